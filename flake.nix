@@ -31,7 +31,11 @@
         crane-stable = (crane.mkLib pkgs).overrideToolchain fenix'.stable.minimalToolchain;
         manifest = (pkgs.lib.importTOML ./Cargo.toml).package;
 
-        src = crane'.cleanCargoSource ./.;
+        # trybuild compares the derive errors against the expected `.stderr` files in `tests/derive`.
+        src = pkgs.lib.cleanSourceWith {
+          src = ./.;
+          filter = path: type: (crane'.filterCargoSources path type) || pkgs.lib.hasSuffix ".stderr" path;
+        };
 
         buildExample =
           {
@@ -58,6 +62,13 @@
             cargoTestExtraArgs = "--no-default-features --all-targets --features ${feature}";
           };
 
+        testStableFeature =
+          feature:
+          crane-stable.buildPackage {
+            inherit src;
+            cargoTestExtraArgs = "--no-default-features --all-targets --features ${feature}";
+          };
+
         refined = crane'.buildPackage {
           inherit src;
           cargoTestExtraArgs = "--all-features";
@@ -136,7 +147,12 @@
           refined-test-implication = testFeature "implication";
           refined-test-arithmetic = testFeature "arithmetic";
           refined-test-regex = testFeature "regex";
+          refined-test-derive = testFeature "derive";
+          refined-test-trusted = testFeature "trusted";
           refined-test-full = testFeature "full";
+          refined-test-stable-implication = testStableFeature "stable-implication";
+          refined-test-stable-arithmetic = testStableFeature "stable-arithmetic";
+          refined-test-stable-arithmetic-alloc = testStableFeature "stable-arithmetic,alloc";
         };
 
         packages.${system} = rec {
@@ -0,0 +1,389 @@
+//! Derive macros for [refined](https://docs.rs/refined).
+//!
+//! This crate should not be used directly; enable the `derive` feature of `refined` instead.
+//! See [Refined](macro@Refined) and [RefineFields](macro@RefineFields) for details.
+use proc_macro::TokenStream;
+use proc_macro2::{Span, TokenStream as TokenStream2};
+use quote::{format_ident, quote};
+use syn::{
+    parse_macro_input, punctuated::Punctuated, Data, DeriveInput, Fields, Ident, LitBool, LitStr,
+    Member, Path, Token, Type,
+};
+
+/// Derives refinement for a newtype wrapper around a single value.
+///
+/// The wrapped value is certified by the predicate given in the `#[refined(predicate = ...)]`
+/// attribute, using the same [Predicate](https://docs.rs/refined/latest/refined/trait.Predicate.html)
+/// machinery as `Refinement`. The derived type implements:
+///
+/// * `RefinementOps` (and therefore `refine`, `modify`, `replace`, etc.)
+/// * `TryFrom<Refined<T>>`
+/// * `Deref<Target = T>`
+/// * `Display`, unless `display = false` is specified
+/// * `Serialize` and `Deserialize` when the `serde` feature of `refined` is enabled, unless
+///   `serde = false` is specified
+///
+/// Refinement errors are [named](https://docs.rs/refined/latest/refined/struct.Named.html) after
+/// the derived type. A different name can be provided using `name = "..."`.
+///
+/// The wrapped field should not be visible outside of the module that defines the type, as
+/// constructing the type directly would bypass refinement.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::ClosedInterval, Refined};
+///
+/// #[derive(Debug, Refined)]
+/// #[refined(predicate = ClosedInterval<1, 10>)]
+/// struct Rating(u8);
+///
+/// assert_eq!(*Rating::refine(5).unwrap(), 5);
+/// assert_eq!(
+///     Rating::refine(11).unwrap_err().to_string(),
+///     "refinement violated: Rating must be less than or equal to 10"
+/// );
+/// ```
+#[proc_macro_derive(Refined, attributes(refined))]
+pub fn derive_refined(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_refined(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct Options {
+    predicate: Type,
+    name: LitStr,
+    display: bool,
+    serde: bool,
+}
+
+fn parse_options(input: &DeriveInput) -> syn::Result<Options> {
+    let mut predicate = None;
+    let mut name = LitStr::new(&input.ident.to_string(), Span::call_site());
+    let mut display = true;
+    let mut serde = true;
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("refined")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("predicate") {
+                predicate = Some(meta.value()?.parse::<Type>()?);
+            } else if meta.path.is_ident("name") {
+                name = meta.value()?.parse::<LitStr>()?;
+            } else if meta.path.is_ident("display") {
+                display = meta.value()?.parse::<LitBool>()?.value;
+            } else if meta.path.is_ident("serde") {
+                serde = meta.value()?.parse::<LitBool>()?.value;
+            } else {
+                return Err(meta.error("unsupported refined attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    let predicate = predicate.ok_or_else(|| {
+        syn::Error::new_spanned(
+            &input.ident,
+            "a predicate must be provided via #[refined(predicate = ...)]",
+        )
+    })?;
+
+    Ok(Options {
+        predicate,
+        name,
+        display,
+        serde,
+    })
+}
+
+fn expand_refined(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "Refined cannot be derived for generic types",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => &data.fields,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "Refined can only be derived for structs",
+            ))
+        }
+    };
+    let field = match fields.iter().collect::<Vec<_>>().as_slice() {
+        [field] => *field,
+        _ => {
+            return Err(syn::Error::new_spanned(
+                fields,
+                "Refined can only be derived for structs with exactly one field",
+            ))
+        }
+    };
+
+    let options = parse_options(&input)?;
+    let ident = &input.ident;
+    let inner = &field.ty;
+    let predicate = &options.predicate;
+    let name = &options.name;
+    let member = match &field.ident {
+        Some(ident) => Member::Named(ident.clone()),
+        None => Member::Unnamed(0.into()),
+    };
+    let construct = match fields {
+        Fields::Named(_) => quote!(#ident { #member: value }),
+        _ => quote!(#ident(value)),
+    };
+
+    let display = options.display.then(|| {
+        quote! {
+            impl ::core::fmt::Display for #ident {
+                fn fmt(&self, f: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::fmt::Display::fmt(&self.#member, f)
+                }
+            }
+        }
+    });
+
+    let serde = options
+        .serde
+        .then(|| quote!(::refined::__refined_derive_serde!(#ident, #inner);));
+
+    Ok(quote! {
+        impl ::core::convert::TryFrom<::refined::Refined<#inner>> for #ident {
+            type Error = ::refined::RefinementError;
+
+            fn try_from(value: ::refined::Refined<#inner>) -> ::core::result::Result<Self, Self::Error> {
+                match ::refined::Refinement::<#inner, #predicate>::try_from(value) {
+                    ::core::result::Result::Ok(value) => {
+                        let value = ::refined::RefinementOps::take(value);
+                        ::core::result::Result::Ok(#construct)
+                    }
+                    ::core::result::Result::Err(err) => ::core::result::Result::Err(err.named(#name)),
+                }
+            }
+        }
+
+        impl ::refined::RefinementOps for #ident {
+            type T = #inner;
+
+            fn try_refine(value: #inner) -> ::core::result::Result<Self, ::refined::RejectedValue<#inner>> {
+                match <::refined::Refinement<#inner, #predicate> as ::refined::RefinementOps>::try_refine(value) {
+                    ::core::result::Result::Ok(value) => {
+                        let value = ::refined::RefinementOps::take(value);
+                        ::core::result::Result::Ok(#construct)
+                    }
+                    ::core::result::Result::Err(rejected) => ::core::result::Result::Err(rejected.named(#name)),
+                }
+            }
+
+            fn take(self) -> #inner {
+                self.#member
+            }
+
+            fn extract(self) -> #inner {
+                self.#member
+            }
+        }
+
+        impl ::core::ops::Deref for #ident {
+            type Target = #inner;
+
+            fn deref(&self) -> &Self::Target {
+                &self.#member
+            }
+        }
+
+        #display
+
+        #serde
+    })
+}
+
+/// Derives field-by-field refinement of a struct from an unrefined "raw" mirror.
+///
+/// A raw struct is generated alongside the derived type, with each field replaced by its
+/// unrefined type (`RefinementOps::T`). The derived type implements `RefineFields` and
+/// `TryFrom<Raw>`, both of which refine every field and report all failures together as
+/// `FieldErrors` keyed by field path.
+///
+/// The following attributes are supported on the struct:
+///
+/// * `raw = Ident` names the generated raw struct (defaults to `Raw` followed by the type name)
+/// * `raw_derive(...)` adds derives to the generated raw struct (e.g. `Debug` or `Deserialize`)
+///
+/// And on individual fields:
+///
+/// * `nested` refines a field whose type itself implements `RefineFields`, reporting its errors
+///   under a nested path (e.g. `address.city`)
+/// * `skip` copies a field through unchanged
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::{LessThanEqual, NonZero}, RefineFields};
+///
+/// #[derive(Debug, RefineFields)]
+/// #[refined(raw = RawFrobnicator)]
+/// struct Frobnicator {
+///     name: Refinement<String, NonZero>,
+///     size: Refinement<u8, LessThanEqual<100>>,
+/// }
+///
+/// let errors = Frobnicator::refine_fields(RawFrobnicator { name: String::new(), size: 101 }).unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// ```
+#[proc_macro_derive(RefineFields, attributes(refined))]
+pub fn derive_refine_fields(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_refine_fields(input)
+        .unwrap_or_else(syn::Error::into_compile_error)
+        .into()
+}
+
+struct FieldsOptions {
+    raw: Ident,
+    raw_derive: Vec<Path>,
+}
+
+fn parse_fields_options(input: &DeriveInput) -> syn::Result<FieldsOptions> {
+    let mut raw = format_ident!("Raw{}", input.ident);
+    let mut raw_derive = Vec::new();
+
+    for attr in input.attrs.iter().filter(|a| a.path().is_ident("refined")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("raw") {
+                raw = meta.value()?.parse::<Ident>()?;
+            } else if meta.path.is_ident("raw_derive") {
+                let content;
+                syn::parenthesized!(content in meta.input);
+                raw_derive.extend(Punctuated::<Path, Token![,]>::parse_terminated(&content)?);
+            } else {
+                return Err(meta.error("unsupported refined attribute"));
+            }
+            Ok(())
+        })?;
+    }
+
+    Ok(FieldsOptions { raw, raw_derive })
+}
+
+enum FieldKind {
+    Refined,
+    Nested,
+    Skip,
+}
+
+fn parse_field_kind(field: &syn::Field) -> syn::Result<FieldKind> {
+    let mut kind = FieldKind::Refined;
+    for attr in field.attrs.iter().filter(|a| a.path().is_ident("refined")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("nested") {
+                kind = FieldKind::Nested;
+            } else if meta.path.is_ident("skip") {
+                kind = FieldKind::Skip;
+            } else {
+                return Err(meta.error("unsupported refined field attribute"));
+            }
+            Ok(())
+        })?;
+    }
+    Ok(kind)
+}
+
+fn expand_refine_fields(input: DeriveInput) -> syn::Result<TokenStream2> {
+    if !input.generics.params.is_empty() {
+        return Err(syn::Error::new_spanned(
+            &input.generics,
+            "RefineFields cannot be derived for generic types",
+        ));
+    }
+
+    let fields = match &input.data {
+        Data::Struct(data) => match &data.fields {
+            Fields::Named(fields) => &fields.named,
+            fields => {
+                return Err(syn::Error::new_spanned(
+                    fields,
+                    "RefineFields can only be derived for structs with named fields",
+                ))
+            }
+        },
+        _ => {
+            return Err(syn::Error::new_spanned(
+                &input.ident,
+                "RefineFields can only be derived for structs",
+            ))
+        }
+    };
+
+    let options = parse_fields_options(&input)?;
+    let ident = &input.ident;
+    let vis = &input.vis;
+    let raw = &options.raw;
+    let raw_derive = (!options.raw_derive.is_empty()).then(|| {
+        let derives = &options.raw_derive;
+        quote!(#[derive(#(#derives),*)])
+    });
+
+    let mut raw_fields = Vec::new();
+    let mut refine_fields = Vec::new();
+    let mut names = Vec::new();
+    for field in fields {
+        let name = field.ident.as_ref().expect("named field");
+        let field_vis = &field.vis;
+        let ty = &field.ty;
+        let path = LitStr::new(&name.to_string(), name.span());
+        let (raw_ty, refine) = match parse_field_kind(field)? {
+            FieldKind::Refined => (
+                quote!(<#ty as ::refined::RefinementOps>::T),
+                quote!(errors.refine::<#ty>(#path, raw.#name)),
+            ),
+            FieldKind::Nested => (
+                quote!(<#ty as ::refined::RefineFields>::Raw),
+                quote!(errors.refine_fields::<#ty>(#path, raw.#name)),
+            ),
+            FieldKind::Skip => (quote!(#ty), quote!(::core::option::Option::Some(raw.#name))),
+        };
+        raw_fields.push(quote!(#field_vis #name: #raw_ty));
+        refine_fields.push(quote!(let #name = #refine;));
+        names.push(name);
+    }
+
+    let raw_doc = LitStr::new(
+        &format!("The unrefined mirror of [{}].", ident),
+        Span::call_site(),
+    );
+
+    Ok(quote! {
+        #[doc = #raw_doc]
+        #raw_derive
+        #vis struct #raw {
+            #(#raw_fields,)*
+        }
+
+        impl ::refined::RefineFields for #ident {
+            type Raw = #raw;
+
+            fn refine_fields(raw: #raw) -> ::core::result::Result<Self, ::refined::FieldErrors> {
+                let mut errors = ::refined::FieldErrors::new();
+                #(#refine_fields)*
+                match (#(#names,)*) {
+                    (#(::core::option::Option::Some(#names),)*) => ::core::result::Result::Ok(#ident { #(#names,)* }),
+                    _ => ::core::result::Result::Err(errors),
+                }
+            }
+        }
+
+        impl ::core::convert::TryFrom<#raw> for #ident {
+            type Error = ::refined::FieldErrors;
+
+            fn try_from(raw: #raw) -> ::core::result::Result<Self, Self::Error> {
+                <Self as ::refined::RefineFields>::refine_fields(raw)
+            }
+        }
+    })
+}
@@ -2,6 +2,8 @@
 //!
 //! This module provides type refinements that act as logical combinators of other refinements.
 //! These combinators allow for the creation of more complex type refinements via type aliases.
+//! [All] and [Any] combine any number of predicates at once, and the [predicate](crate::predicate)
+//! macro provides an infix syntax for composing them.
 //!
 //! # Example
 //!
@@ -16,12 +18,32 @@
 //! let not_ok_string = SizedString::refine("Way too long I'm afraid".to_string());
 //! assert!(not_ok_string.is_err());
 //! ```
+//!
+//! Combinators hold their children, so every combinator over
+//! [stateful predicates](StatefulPredicate) is itself stateful. [Stateless] children (which
+//! include all of the stateless predicates provided by `refined`) simply fall back to their
+//! stateless form, so state can be mixed freely with ordinary predicates:
+//!
+//! ```
+//! use refined::{prelude::*, boolean::*, boundable::unsigned::*};
+//!
+//! type Bounded = Refinement<String, And<DynGreaterThan, And<DynLessThan, Not<Equals<5>>>>>;
+//!
+//! let bounds = And::new(
+//!     DynGreaterThan::new(3),
+//!     And::new(DynLessThan::new(10), Not::default()),
+//! );
+//! assert!(Bounded::refine_with_state(&bounds, "Good size".to_string()).is_ok());
+//! assert!(Bounded::refine_with_state(&bounds, "Bad".to_string()).is_err());
+//! assert!(Bounded::refine_with_state(&bounds, "Fives".to_string()).is_err());
+//! ```
 
 #[cfg(feature = "alloc")]
-use alloc::format;
-use core::marker::PhantomData;
+use alloc::{format, string::String, vec, vec::Vec};
 
-use crate::{ErrorMessage, Predicate};
+use crate::{ConstPredicate, ConstValue, ErrorMessage, Predicate, StatefulPredicate, Stateless};
+#[cfg(feature = "alloc")]
+use crate::{PredicateKind, Violation};
 
 /// Always `true`.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
@@ -42,8 +64,13 @@ impl<T> Predicate<T> for True {
         "true predicate"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(PredicateKind::True, &[], <Self as Predicate<T>>::error())
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
@@ -66,16 +93,33 @@ impl<T> Predicate<T> for False {
         "false predicate"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(PredicateKind::False, &[], <Self as Predicate<T>>::error())
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
 /// Logical conjunction of two [predicates](Predicate).
+///
+/// When refinement fails, only the conjuncts that were violated are reported.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct And<A, B>(pub(crate) PhantomData<A>, pub(crate) PhantomData<B>);
+pub struct And<A, B>(pub(crate) A, pub(crate) B);
+
+impl<A, B> And<A, B> {
+    /// Creates a conjunction of two predicate instances, for use with
+    /// [stateful](StatefulPredicate) refinement.
+    pub const fn new(a: A, b: B) -> Self {
+        Self(a, b)
+    }
+}
 
 impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for And<A, B> {
+    const RUNTIME_STATE: bool = A::RUNTIME_STATE || B::RUNTIME_STATE;
+
     fn test(t: &T) -> bool {
         A::test(t) && B::test(t)
     }
@@ -90,16 +134,78 @@ impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for And<A, B> {
         "conjunction"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::conjunction(A::violation(), B::violation())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(t: &T) -> Violation {
+        match (A::test(t), B::test(t)) {
+            (false, true) => A::explain(t),
+            (true, false) => B::explain(t),
+            _ => Violation::conjunction(A::explain(t), B::explain(t)),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(t: &T) -> Violation {
+        Violation::conjunction(A::explain_satisfied(t), B::explain_satisfied(t))
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        A::optimize(value);
+        B::optimize(value);
+    }
+}
+
+impl<T, A: StatefulPredicate<T>, B: StatefulPredicate<T>> StatefulPredicate<T> for And<A, B> {
+    fn test(&self, t: &T) -> bool {
+        self.0.test(t) && self.1.test(t)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} and {}", self.0.error(), self.1.error())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self) -> Violation {
+        Violation::conjunction(self.0.violation(), self.1.violation())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(&self, t: &T) -> Violation {
+        match (self.0.test(t), self.1.test(t)) {
+            (false, true) => self.0.explain(t),
+            (true, false) => self.1.explain(t),
+            _ => Violation::conjunction(self.0.explain(t), self.1.explain(t)),
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(&self, t: &T) -> Violation {
+        Violation::conjunction(self.0.explain_satisfied(t), self.1.explain_satisfied(t))
     }
 }
 
 /// Logical disjunction of two [predicates](Predicate).
+///
+/// When refinement fails, every disjunct was violated, so all of them are reported.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct Or<A, B>(PhantomData<A>, PhantomData<B>);
+pub struct Or<A, B>(pub(crate) A, pub(crate) B);
+
+impl<A, B> Or<A, B> {
+    /// Creates a disjunction of two predicate instances, for use with
+    /// [stateful](StatefulPredicate) refinement.
+    pub const fn new(a: A, b: B) -> Self {
+        Self(a, b)
+    }
+}
 
 impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for Or<A, B> {
+    const RUNTIME_STATE: bool = A::RUNTIME_STATE || B::RUNTIME_STATE;
+
     fn test(t: &T) -> bool {
         A::test(t) || B::test(t)
     }
@@ -114,16 +220,79 @@ impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for Or<A, B> {
         "disjunction"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::disjunction(A::violation(), B::violation())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(t: &T) -> Violation {
+        Violation::disjunction(A::explain(t), B::explain(t))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(t: &T) -> Violation {
+        match (A::test(t), B::test(t)) {
+            (true, false) => A::explain_satisfied(t),
+            (false, true) => B::explain_satisfied(t),
+            _ => Violation::disjunction(A::explain_satisfied(t), B::explain_satisfied(t)),
+        }
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        if !<Self as Predicate<T>>::RUNTIME_STATE {
+            core::hint::assert_unchecked(Self::test(value));
+        }
+    }
+}
+
+impl<T, A: StatefulPredicate<T>, B: StatefulPredicate<T>> StatefulPredicate<T> for Or<A, B> {
+    fn test(&self, t: &T) -> bool {
+        self.0.test(t) || self.1.test(t)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} or {}", self.0.error(), self.1.error())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self) -> Violation {
+        Violation::disjunction(self.0.violation(), self.1.violation())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(&self, t: &T) -> Violation {
+        Violation::disjunction(self.0.explain(t), self.1.explain(t))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(&self, t: &T) -> Violation {
+        match (self.0.test(t), self.1.test(t)) {
+            (true, false) => self.0.explain_satisfied(t),
+            (false, true) => self.1.explain_satisfied(t),
+            _ => Violation::disjunction(self.0.explain_satisfied(t), self.1.explain_satisfied(t)),
+        }
     }
 }
 
 /// Logical exclusive disjunction of two [predicates](Predicate).
+///
+/// When refinement fails because neither predicate holds, both violations are reported.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct Xor<A, B>(PhantomData<A>, PhantomData<B>);
+pub struct Xor<A, B>(pub(crate) A, pub(crate) B);
+
+impl<A, B> Xor<A, B> {
+    /// Creates an exclusive disjunction of two predicate instances, for use with
+    /// [stateful](StatefulPredicate) refinement.
+    pub const fn new(a: A, b: B) -> Self {
+        Self(a, b)
+    }
+}
 
 impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for Xor<A, B> {
+    const RUNTIME_STATE: bool = A::RUNTIME_STATE || B::RUNTIME_STATE;
+
     fn test(t: &T) -> bool {
         A::test(t) ^ B::test(t)
     }
@@ -138,16 +307,88 @@ impl<T, A: Predicate<T>, B: Predicate<T>> Predicate<T> for Xor<A, B> {
         "exclusive disjunction"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::exclusive_disjunction(A::violation(), B::violation())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(t: &T) -> Violation {
+        if A::test(t) {
+            Self::violation()
+        } else {
+            Violation::exclusive_disjunction(A::explain(t), B::explain(t))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(t: &T) -> Violation {
+        if A::test(t) {
+            A::explain_satisfied(t)
+        } else {
+            B::explain_satisfied(t)
+        }
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        if !<Self as Predicate<T>>::RUNTIME_STATE {
+            core::hint::assert_unchecked(Self::test(value));
+        }
+    }
+}
+
+impl<T, A: StatefulPredicate<T>, B: StatefulPredicate<T>> StatefulPredicate<T> for Xor<A, B> {
+    fn test(&self, t: &T) -> bool {
+        self.0.test(t) ^ self.1.test(t)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("{} xor {}", self.0.error(), self.1.error())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self) -> Violation {
+        Violation::exclusive_disjunction(self.0.violation(), self.1.violation())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(&self, t: &T) -> Violation {
+        if self.0.test(t) {
+            self.violation()
+        } else {
+            Violation::exclusive_disjunction(self.0.explain(t), self.1.explain(t))
+        }
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(&self, t: &T) -> Violation {
+        if self.0.test(t) {
+            self.0.explain_satisfied(t)
+        } else {
+            self.1.explain_satisfied(t)
+        }
     }
 }
 
 /// Logical negation of a [predicate](Predicate).
+///
+/// When refinement fails, the parts of the inner predicate that were satisfied are reported, so
+/// `Not<Or<A, B>>` reports only the disjuncts that held.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct Not<P>(PhantomData<P>);
+pub struct Not<P>(pub(crate) P);
+
+impl<P> Not<P> {
+    /// Creates a negation of a predicate instance, for use with
+    /// [stateful](StatefulPredicate) refinement.
+    pub const fn new(p: P) -> Self {
+        Self(p)
+    }
+}
 
 impl<T, P: Predicate<T>> Predicate<T> for Not<P> {
+    const RUNTIME_STATE: bool = P::RUNTIME_STATE;
+
     fn test(t: &T) -> bool {
         !P::test(t)
     }
@@ -162,8 +403,51 @@ impl<T, P: Predicate<T>> Predicate<T> for Not<P> {
         "negation"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::negation(P::violation())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(t: &T) -> Violation {
+        Violation::negation(P::explain_satisfied(t))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(t: &T) -> Violation {
+        Violation::negation(P::explain(t))
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        if !<Self as Predicate<T>>::RUNTIME_STATE {
+            core::hint::assert_unchecked(Self::test(value));
+        }
+    }
+}
+
+impl<T, P: StatefulPredicate<T>> StatefulPredicate<T> for Not<P> {
+    fn test(&self, t: &T) -> bool {
+        !self.0.test(t)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error(&self) -> ErrorMessage {
+        format!("not {}", self.0.error())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self) -> Violation {
+        Violation::negation(self.0.violation())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(&self, t: &T) -> Violation {
+        Violation::negation(self.0.explain_satisfied(t))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(&self, t: &T) -> Violation {
+        Violation::negation(self.0.explain(t))
     }
 }
 
@@ -173,10 +457,353 @@ pub type Nand<A, B> = Not<And<A, B>>;
 /// Logical negated disjunction of two [predicates](Predicate).
 pub type Nor<A, B> = Not<Or<A, B>>;
 
+/// Logical conjunction of any number of [predicates](Predicate), given as a tuple.
+///
+/// `All<(A, B, C)>` is equivalent to `And<A, And<B, C>>`, but reports the violated predicates as a
+/// flat [list](Violation::All) rather than a nested chain. Tuples of up to eight predicates are supported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct All<Ps>(pub(crate) Ps);
+
+impl<Ps> All<Ps> {
+    /// Creates a conjunction of a tuple of predicate instances, for use with
+    /// [stateful](StatefulPredicate) refinement.
+    pub const fn new(predicates: Ps) -> Self {
+        Self(predicates)
+    }
+}
+
+/// Logical disjunction of any number of [predicates](Predicate), given as a tuple.
+///
+/// `Any<(A, B, C)>` is equivalent to `Or<A, Or<B, C>>`, but reports the violated predicates as a
+/// flat [list](Violation::Any) rather than a nested chain. Tuples of up to eight predicates are supported.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Any<Ps>(pub(crate) Ps);
+
+impl<Ps> Any<Ps> {
+    /// Creates a disjunction of a tuple of predicate instances, for use with
+    /// [stateful](StatefulPredicate) refinement.
+    pub const fn new(predicates: Ps) -> Self {
+        Self(predicates)
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn join(messages: &[ErrorMessage]) -> String {
+    messages.join("; ")
+}
+
+macro_rules! variadic {
+    ($($p:ident $i:tt),+) => {
+        impl<T, $($p: Predicate<T>),+> Predicate<T> for All<($($p,)+)> {
+            const RUNTIME_STATE: bool = false $(|| $p::RUNTIME_STATE)+;
+
+            fn test(t: &T) -> bool {
+                true $(&& $p::test(t))+
+            }
+
+            #[cfg(feature = "alloc")]
+            fn error() -> ErrorMessage {
+                join(&[$($p::error()),+])
+            }
+
+            #[cfg(not(feature = "alloc"))]
+            fn error() -> ErrorMessage {
+                "all of"
+            }
+
+            #[cfg(feature = "alloc")]
+            fn violation() -> Violation {
+                Violation::all(vec![$($p::violation()),+])
+            }
+
+            #[cfg(feature = "alloc")]
+            fn explain(t: &T) -> Violation {
+                let mut violated = Vec::new();
+                $(
+                    if !$p::test(t) {
+                        violated.push($p::explain(t));
+                    }
+                )+
+                Violation::all(violated)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn explain_satisfied(t: &T) -> Violation {
+                Violation::all(vec![$($p::explain_satisfied(t)),+])
+            }
+
+            unsafe fn optimize(value: &T) {
+                $($p::optimize(value);)+
+            }
+        }
+
+        impl<T, $($p: StatefulPredicate<T>),+> StatefulPredicate<T> for All<($($p,)+)> {
+            fn test(&self, t: &T) -> bool {
+                true $(&& self.0.$i.test(t))+
+            }
+
+            #[cfg(feature = "alloc")]
+            fn error(&self) -> ErrorMessage {
+                join(&[$(self.0.$i.error()),+])
+            }
+
+            #[cfg(feature = "alloc")]
+            fn violation(&self) -> Violation {
+                Violation::all(vec![$(self.0.$i.violation()),+])
+            }
+
+            #[cfg(feature = "alloc")]
+            fn explain(&self, t: &T) -> Violation {
+                let mut violated = Vec::new();
+                $(
+                    if !self.0.$i.test(t) {
+                        violated.push(self.0.$i.explain(t));
+                    }
+                )+
+                Violation::all(violated)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn explain_satisfied(&self, t: &T) -> Violation {
+                Violation::all(vec![$(self.0.$i.explain_satisfied(t)),+])
+            }
+        }
+
+        impl<T, V: ConstValue<T>, $($p: ConstPredicate<T, V>),+> ConstPredicate<T, V> for All<($($p,)+)> {
+            const HOLDS: bool = true $(&& $p::HOLDS)+;
+        }
+
+        impl<T, $($p: Predicate<T>),+> Predicate<T> for Any<($($p,)+)> {
+            const RUNTIME_STATE: bool = false $(|| $p::RUNTIME_STATE)+;
+
+            fn test(t: &T) -> bool {
+                false $(|| $p::test(t))+
+            }
+
+            #[cfg(feature = "alloc")]
+            fn error() -> ErrorMessage {
+                format!("any of ({})", join(&[$($p::error()),+]))
+            }
+
+            #[cfg(not(feature = "alloc"))]
+            fn error() -> ErrorMessage {
+                "any of"
+            }
+
+            #[cfg(feature = "alloc")]
+            fn violation() -> Violation {
+                Violation::any(vec![$($p::violation()),+])
+            }
+
+            #[cfg(feature = "alloc")]
+            fn explain(t: &T) -> Violation {
+                Violation::any(vec![$($p::explain(t)),+])
+            }
+
+            #[cfg(feature = "alloc")]
+            fn explain_satisfied(t: &T) -> Violation {
+                let mut satisfied = Vec::new();
+                $(
+                    if $p::test(t) {
+                        satisfied.push($p::explain_satisfied(t));
+                    }
+                )+
+                Violation::any(satisfied)
+            }
+
+            unsafe fn optimize(value: &T) {
+                if !<Self as Predicate<T>>::RUNTIME_STATE {
+                    core::hint::assert_unchecked(Self::test(value));
+                }
+            }
+        }
+
+        impl<T, $($p: StatefulPredicate<T>),+> StatefulPredicate<T> for Any<($($p,)+)> {
+            fn test(&self, t: &T) -> bool {
+                false $(|| self.0.$i.test(t))+
+            }
+
+            #[cfg(feature = "alloc")]
+            fn error(&self) -> ErrorMessage {
+                format!("any of ({})", join(&[$(self.0.$i.error()),+]))
+            }
+
+            #[cfg(feature = "alloc")]
+            fn violation(&self) -> Violation {
+                Violation::any(vec![$(self.0.$i.violation()),+])
+            }
+
+            #[cfg(feature = "alloc")]
+            fn explain(&self, t: &T) -> Violation {
+                Violation::any(vec![$(self.0.$i.explain(t)),+])
+            }
+
+            #[cfg(feature = "alloc")]
+            fn explain_satisfied(&self, t: &T) -> Violation {
+                let mut satisfied = Vec::new();
+                $(
+                    if self.0.$i.test(t) {
+                        satisfied.push(self.0.$i.explain_satisfied(t));
+                    }
+                )+
+                Violation::any(satisfied)
+            }
+        }
+
+        impl<T, V: ConstValue<T>, $($p: ConstPredicate<T, V>),+> ConstPredicate<T, V> for Any<($($p,)+)> {
+            const HOLDS: bool = false $(|| $p::HOLDS)+;
+        }
+    };
+}
+
+variadic!(A 0);
+variadic!(A 0, B 1);
+variadic!(A 0, B 1, C 2);
+variadic!(A 0, B 1, C 2, D 3);
+variadic!(A 0, B 1, C 2, D 3, E 4);
+variadic!(A 0, B 1, C 2, D 3, E 4, F 5);
+variadic!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+variadic!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+
+/// Composes [boolean](crate::boolean) predicates using infix syntax.
+///
+/// `&`, `^` and `|` expand to [And], [Xor] and [Or] respectively, and a prefix `!` expands to [Not].
+/// Operators bind in that order (`!` tightest, `|` loosest) and chains associate to the right;
+/// parentheses may be used for grouping. The result is an ordinary type, so it supports stateful
+/// refinement and implication exactly as the underlying combinators do.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, predicate, boundable::unsigned::*};
+///
+/// type Rating = Refinement<u8, predicate!(GTE<1> & LTE<10> & !Equals<5>)>;
+///
+/// assert!(Rating::refine(4).is_ok());
+/// assert!(Rating::refine(5).is_err());
+/// assert!(Rating::refine(11).is_err());
+///
+/// type Grouped = predicate!((LT<5> | GT<10>) & Even);
+/// assert!(Refinement::<u8, Grouped>::refine(12).is_ok());
+/// assert!(Refinement::<u8, Grouped>::refine(7).is_err());
+/// ```
+#[macro_export]
+macro_rules! predicate {
+    (@split [$($done:tt)*] [$($current:tt)+] | $($rest:tt)+) => {
+        $crate::predicate!(@split [$($done)* ($($current)+)] [] $($rest)+)
+    };
+    (@split [$($done:tt)*] [$($current:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::predicate!(@split [$($done)*] [$($current)* $token] $($rest)*)
+    };
+    (@split [$($done:tt)*] [$($current:tt)+]) => {
+        $crate::__predicate_fold!(Or, __predicate_xor; $($done)* ($($current)+))
+    };
+    ($($tokens:tt)+) => {
+        $crate::predicate!(@split [] [] $($tokens)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __predicate_xor {
+    (@split [$($done:tt)*] [$($current:tt)+] ^ $($rest:tt)+) => {
+        $crate::__predicate_xor!(@split [$($done)* ($($current)+)] [] $($rest)+)
+    };
+    (@split [$($done:tt)*] [$($current:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__predicate_xor!(@split [$($done)*] [$($current)* $token] $($rest)*)
+    };
+    (@split [$($done:tt)*] [$($current:tt)+]) => {
+        $crate::__predicate_fold!(Xor, __predicate_and; $($done)* ($($current)+))
+    };
+    ($($tokens:tt)+) => {
+        $crate::__predicate_xor!(@split [] [] $($tokens)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __predicate_and {
+    (@split [$($done:tt)*] [$($current:tt)+] & $($rest:tt)+) => {
+        $crate::__predicate_and!(@split [$($done)* ($($current)+)] [] $($rest)+)
+    };
+    (@split [$($done:tt)*] [$($current:tt)*] $token:tt $($rest:tt)*) => {
+        $crate::__predicate_and!(@split [$($done)*] [$($current)* $token] $($rest)*)
+    };
+    (@split [$($done:tt)*] [$($current:tt)+]) => {
+        $crate::__predicate_fold!(And, __predicate_unary; $($done)* ($($current)+))
+    };
+    ($($tokens:tt)+) => {
+        $crate::__predicate_and!(@split [] [] $($tokens)+)
+    };
+}
+
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __predicate_unary {
+    (! $($tokens:tt)+) => {
+        $crate::boolean::Not<$crate::__predicate_unary!($($tokens)+)>
+    };
+    (($($tokens:tt)+)) => {
+        $crate::predicate!($($tokens)+)
+    };
+    ($predicate:ty) => {
+        $predicate
+    };
+}
+
+/// Folds the operands split by one of the operator levels of [predicate] into `$combinator`,
+/// expanding each operand with the next (tighter binding) level `$next`.
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __predicate_fold {
+    ($combinator:ident, $next:ident; ($($operand:tt)+)) => {
+        $crate::$next!($($operand)+)
+    };
+    ($combinator:ident, $next:ident; ($($operand:tt)+) $($rest:tt)+) => {
+        $crate::boolean::$combinator<$crate::$next!($($operand)+), $crate::__predicate_fold!($combinator, $next; $($rest)+)>
+    };
+}
+
+impl<T, V: ConstValue<T>> ConstPredicate<T, V> for True {
+    const HOLDS: bool = true;
+}
+
+impl<T, V: ConstValue<T>> ConstPredicate<T, V> for False {
+    const HOLDS: bool = false;
+}
+
+impl<T, V: ConstValue<T>, A: ConstPredicate<T, V>, B: ConstPredicate<T, V>> ConstPredicate<T, V>
+    for And<A, B>
+{
+    const HOLDS: bool = A::HOLDS && B::HOLDS;
+}
+
+impl<T, V: ConstValue<T>, A: ConstPredicate<T, V>, B: ConstPredicate<T, V>> ConstPredicate<T, V>
+    for Or<A, B>
+{
+    const HOLDS: bool = A::HOLDS || B::HOLDS;
+}
+
+impl<T, V: ConstValue<T>, A: ConstPredicate<T, V>, B: ConstPredicate<T, V>> ConstPredicate<T, V>
+    for Xor<A, B>
+{
+    const HOLDS: bool = A::HOLDS ^ B::HOLDS;
+}
+
+impl<T, V: ConstValue<T>, P: ConstPredicate<T, V>> ConstPredicate<T, V> for Not<P> {
+    const HOLDS: bool = !P::HOLDS;
+}
+
+impl Stateless for True {}
+
+impl Stateless for False {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::*;
+    #[cfg(feature = "alloc")]
+    use alloc::format;
 
     #[test]
     fn test_true() {
@@ -244,6 +871,95 @@ mod tests {
         assert!(TestFalseFalse::refine(123).is_err());
     }
 
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_and_reports_violated_conjunct() {
+        type Test = Refinement<u8, boundable::unsigned::ClosedInterval<1, 10>>;
+        assert_eq!(
+            format!("{}", Test::refine(0).unwrap_err()),
+            "refinement violated: must be greater than or equal to 1"
+        );
+        assert_eq!(
+            format!("{}", Test::refine(11).unwrap_err()),
+            "refinement violated: must be less than or equal to 10"
+        );
+
+        type TestFalseFalse = Refinement<u8, And<False, False>>;
+        assert_eq!(
+            format!("{}", TestFalseFalse::refine(0).unwrap_err()),
+            "refinement violated: false predicate and false predicate"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_or_reports_violated_disjuncts() {
+        type Test = Refinement<
+            u8,
+            Or<boundable::unsigned::LessThan<5>, boundable::unsigned::ClosedInterval<10, 20>>,
+        >;
+        assert_eq!(
+            format!("{}", Test::refine(7).unwrap_err()),
+            "refinement violated: must be less than 5 or must be greater than or equal to 10"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_not_reports_accepted_predicate() {
+        type Test = Refinement<u8, Not<boundable::unsigned::ClosedInterval<1, 10>>>;
+        assert_eq!(
+            format!("{}", Test::refine(5).unwrap_err()),
+            "refinement violated: not must be greater than or equal to 1 and must be less than or equal to 10"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_not_reports_satisfied_branches() {
+        use boundable::unsigned::{GreaterThan, LessThan};
+        type TestOr = Refinement<u8, Not<Or<LessThan<5>, GreaterThan<10>>>>;
+        assert_eq!(
+            format!("{}", TestOr::refine(3).unwrap_err()),
+            "refinement violated: not must be less than 5"
+        );
+        assert_eq!(
+            format!("{}", TestOr::refine(12).unwrap_err()),
+            "refinement violated: not must be greater than 10"
+        );
+        type TestAny = Refinement<u8, Not<Any<(LessThan<5>, LessThan<10>, GreaterThan<20>)>>>;
+        assert_eq!(
+            format!("{}", TestAny::refine(7).unwrap_err()),
+            "refinement violated: not must be less than 10"
+        );
+        type TestNotNot = Refinement<u8, Not<Not<And<LessThan<5>, GreaterThan<1>>>>>;
+        assert_eq!(
+            format!("{}", TestNotNot::refine(9).unwrap_err()),
+            "refinement violated: not not must be less than 5"
+        );
+        let stateful = Not::new(Or::new(LessThan::<5>, GreaterThan::<10>));
+        assert_eq!(
+            format!(
+                "{}",
+                Refinement::refine_with_state(&stateful, 12u8).unwrap_err()
+            ),
+            "refinement violated: not must be greater than 10"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_xor_reports_violations() {
+        type Test = Refinement<
+            u8,
+            Xor<boundable::unsigned::LessThan<5>, boundable::unsigned::GreaterThan<3>>,
+        >;
+        assert_eq!(
+            format!("{}", Test::refine(4).unwrap_err()),
+            "refinement violated: must be less than 5 xor must be greater than 3"
+        );
+    }
+
     #[test]
     fn test_nand() {
         type TestTrueFalse = Refinement<u8, Nand<True, False>>;
@@ -273,4 +989,141 @@ mod tests {
         type TestFalseFalse = Refinement<u8, Nor<False, False>>;
         assert!(TestFalseFalse::refine(123).is_ok());
     }
+
+    #[test]
+    fn test_stateful_combinators() {
+        use boundable::unsigned::{DynGreaterThan, DynLessThan, Even, LessThan};
+
+        type TestAnd = Refinement<u8, And<DynLessThan, Even>>;
+        let and = And::new(DynLessThan::new(10), Even::default());
+        assert!(TestAnd::refine_with_state(&and, 4).is_ok());
+        assert!(TestAnd::refine_with_state(&and, 5).is_err());
+        assert!(TestAnd::refine_with_state(&and, 12).is_err());
+
+        type TestOr = Refinement<u8, Or<DynLessThan, LessThan<20>>>;
+        let or = Or::new(DynLessThan::new(10), LessThan);
+        assert!(TestOr::refine_with_state(&or, 15).is_ok());
+        assert!(TestOr::refine_with_state(&or, 25).is_err());
+
+        type TestXor = Refinement<u8, Xor<DynLessThan, DynGreaterThan>>;
+        let xor = Xor::new(DynLessThan::new(10), DynGreaterThan::new(5));
+        assert!(TestXor::refine_with_state(&xor, 3).is_ok());
+        assert!(TestXor::refine_with_state(&xor, 7).is_err());
+        assert!(TestXor::refine_with_state(&xor, 11).is_ok());
+
+        type TestNot = Refinement<u8, Not<Xor<DynLessThan, DynGreaterThan>>>;
+        assert!(TestNot::refine_with_state(&Not::new(xor), 7).is_ok());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_stateful_combinators_report_violations() {
+        use boundable::unsigned::{DynLessThan, GreaterThan};
+
+        type Test = Refinement<u8, And<GreaterThan<2>, DynLessThan>>;
+        let predicate = And::new(GreaterThan, DynLessThan::new(10));
+        assert_eq!(
+            format!("{}", Test::refine_with_state(&predicate, 10).unwrap_err()),
+            "refinement violated: must be less than 10"
+        );
+        assert_eq!(
+            format!("{}", Test::refine_with_state(&predicate, 2).unwrap_err()),
+            "refinement violated: must be greater than 2"
+        );
+    }
+
+    #[test]
+    fn test_all() {
+        use boundable::unsigned::*;
+
+        type Test = Refinement<u8, All<(GTE<1>, LTE<10>, Not<Equals<5>>)>>;
+        assert!(Test::refine(1).is_ok());
+        assert!(Test::refine(0).is_err());
+        assert!(Test::refine(5).is_err());
+        assert!(Test::refine(11).is_err());
+
+        type Single = Refinement<u8, All<(Even,)>>;
+        assert!(Single::refine(2).is_ok());
+        assert!(Single::refine(3).is_err());
+    }
+
+    #[test]
+    fn test_any() {
+        use boundable::unsigned::*;
+
+        type Test = Refinement<u8, Any<(LT<5>, Equals<10>, GT<20>)>>;
+        assert!(Test::refine(4).is_ok());
+        assert!(Test::refine(10).is_ok());
+        assert!(Test::refine(21).is_ok());
+        assert!(Test::refine(7).is_err());
+    }
+
+    #[test]
+    fn test_stateful_all_any() {
+        use boundable::unsigned::*;
+
+        type Test = Refinement<u8, All<(DynGreaterThan, Any<(DynLessThan, Equals<99>)>)>>;
+        let predicate = All::new((
+            DynGreaterThan::new(3),
+            Any::new((DynLessThan::new(10), Equals)),
+        ));
+        assert!(Test::refine_with_state(&predicate, 5).is_ok());
+        assert!(Test::refine_with_state(&predicate, 99).is_ok());
+        assert!(Test::refine_with_state(&predicate, 2).is_err());
+        assert!(Test::refine_with_state(&predicate, 50).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_all_any_report_flat_violations() {
+        use boundable::unsigned::*;
+
+        type TestAll = Refinement<u8, All<(Even, GT<10>, LT<20>)>>;
+        assert_eq!(
+            format!("{}", TestAll::refine(15).unwrap_err()),
+            "refinement violated: must be divisible by 2 with a remainder of 0"
+        );
+        let err = TestAll::refine(5).unwrap_err();
+        assert!(matches!(err.violation(), Violation::All(v) if v.len() == 2));
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must be divisible by 2 with a remainder of 0; must be greater than 10"
+        );
+
+        type TestAny = Refinement<u8, Any<(LT<5>, Equals<10>, GT<20>)>>;
+        assert_eq!(
+            format!("{}", TestAny::refine(7).unwrap_err()),
+            "refinement violated: any of (must be less than 5; must be equal to 10; must be greater than 20)"
+        );
+
+        type TestSingle = Refinement<u8, Any<(LT<5>,)>>;
+        let err = TestSingle::refine(7).unwrap_err();
+        assert!(matches!(err.violation(), Violation::Predicate(_)));
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must be less than 5"
+        );
+    }
+
+    #[test]
+    fn test_predicate_macro() {
+        use boundable::unsigned::*;
+
+        type Test = Refinement<u8, predicate!(GTE<1> & LTE<10> & !Equals<5>)>;
+        let _: core::marker::PhantomData<And<GTE<1>, And<LTE<10>, Not<Equals<5>>>>> =
+            core::marker::PhantomData::<predicate!(GTE<1> & LTE<10> & !Equals<5>)>;
+        assert!(Test::refine(4).is_ok());
+        assert!(Test::refine(5).is_err());
+
+        type Precedence = predicate!(LT<5> | GT<10> & Even ^ Equals<12>);
+        type Expected = Or<LT<5>, Xor<And<GT<10>, Even>, Equals<12>>>;
+        let _: core::marker::PhantomData<Expected> = core::marker::PhantomData::<Precedence>;
+        assert!(Refinement::<u8, Precedence>::refine(3).is_ok());
+        assert!(Refinement::<u8, Precedence>::refine(14).is_ok());
+        assert!(Refinement::<u8, Precedence>::refine(12).is_err());
+
+        type Grouped = predicate!(!(LT<5> | GT<10>));
+        assert!(Refinement::<u8, Grouped>::refine(7).is_ok());
+        assert!(Refinement::<u8, Grouped>::refine(3).is_err());
+    }
 }
@@ -0,0 +1,382 @@
+//! Statically knowable bounds of boundable predicates.
+
+use crate::{boolean::Not, boundable::*, Predicate, SignedBoundable, UnsignedBoundable};
+
+/// A type that has a statically knowable unsigned maximum value.
+pub trait UnsignedMax<T: UnsignedBoundable>: Predicate<T> {
+    /// The maximum value.
+    const UMAX: u128;
+}
+
+impl<T: UnsignedBoundable, const MAX: u128> UnsignedMax<T> for unsigned::LessThan<MAX> {
+    const UMAX: u128 = MAX - 1;
+}
+
+impl<T: UnsignedBoundable, const MAX: u128> UnsignedMax<T> for unsigned::LessThanEqual<MAX> {
+    const UMAX: u128 = MAX;
+}
+
+impl<T: UnsignedBoundable, const VAL: u128> UnsignedMax<T> for unsigned::Equals<VAL> {
+    const UMAX: u128 = VAL;
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMax<T>
+    for unsigned::ClosedInterval<MIN, MAX>
+{
+    const UMAX: u128 = MAX;
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMax<T>
+    for unsigned::OpenClosedInterval<MIN, MAX>
+{
+    const UMAX: u128 = MAX;
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMax<T>
+    for unsigned::OpenInterval<MIN, MAX>
+{
+    const UMAX: u128 = MAX - 1;
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMax<T>
+    for unsigned::ClosedOpenInterval<MIN, MAX>
+{
+    const UMAX: u128 = MAX - 1;
+}
+
+/// A type that has a statically knowable unsigned minimum value.
+pub trait UnsignedMin<T: UnsignedBoundable>: Predicate<T> {
+    /// The minimum value.
+    const UMIN: u128;
+}
+
+impl<T: UnsignedBoundable, const MIN: u128> UnsignedMin<T> for unsigned::GreaterThan<MIN> {
+    const UMIN: u128 = MIN + 1;
+}
+
+impl<T: UnsignedBoundable, const MIN: u128> UnsignedMin<T> for unsigned::GreaterThanEqual<MIN> {
+    const UMIN: u128 = MIN;
+}
+
+impl<T: UnsignedBoundable, const VAL: u128> UnsignedMin<T> for unsigned::Equals<VAL> {
+    const UMIN: u128 = VAL;
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMin<T>
+    for unsigned::ClosedInterval<MIN, MAX>
+{
+    const UMIN: u128 = MIN;
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMin<T>
+    for unsigned::OpenClosedInterval<MIN, MAX>
+{
+    const UMIN: u128 = MIN + 1;
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMin<T>
+    for unsigned::OpenInterval<MIN, MAX>
+{
+    const UMIN: u128 = MIN + 1;
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMin<T>
+    for unsigned::ClosedOpenInterval<MIN, MAX>
+{
+    const UMIN: u128 = MIN;
+}
+
+impl<T: UnsignedBoundable> UnsignedMin<T> for Not<unsigned::Equals<0>> {
+    const UMIN: u128 = 1;
+}
+
+/// A type that has a statically knowable unsigned minimum value and maximum value.
+pub trait UnsignedMinMax<T: UnsignedBoundable>:
+    Predicate<T> + UnsignedMin<T> + UnsignedMax<T>
+{
+}
+
+impl<T: UnsignedBoundable, const VAL: u128> UnsignedMinMax<T> for unsigned::Equals<VAL> {}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMinMax<T>
+    for unsigned::ClosedInterval<MIN, MAX>
+{
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMinMax<T>
+    for unsigned::OpenClosedInterval<MIN, MAX>
+{
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMinMax<T>
+    for unsigned::OpenInterval<MIN, MAX>
+{
+}
+
+impl<T: UnsignedBoundable, const MIN: u128, const MAX: u128> UnsignedMinMax<T>
+    for unsigned::ClosedOpenInterval<MIN, MAX>
+{
+}
+
+/// A type that has a statically knowable signed maximum value.
+pub trait SignedMax<T: SignedBoundable>: Predicate<T> {
+    /// The maximum value.
+    const UMAX: i128;
+}
+
+impl<T: SignedBoundable, const MAX: i128> SignedMax<T> for signed::LessThan<MAX> {
+    const UMAX: i128 = MAX - 1;
+}
+
+impl<T: SignedBoundable, const MAX: i128> SignedMax<T> for signed::LessThanEqual<MAX> {
+    const UMAX: i128 = MAX;
+}
+
+impl<T: SignedBoundable, const VAL: i128> SignedMax<T> for signed::Equals<VAL> {
+    const UMAX: i128 = VAL;
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMax<T>
+    for signed::ClosedInterval<MIN, MAX>
+{
+    const UMAX: i128 = MAX;
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMax<T>
+    for signed::OpenClosedInterval<MIN, MAX>
+{
+    const UMAX: i128 = MAX;
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMax<T>
+    for signed::OpenInterval<MIN, MAX>
+{
+    const UMAX: i128 = MAX - 1;
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMax<T>
+    for signed::ClosedOpenInterval<MIN, MAX>
+{
+    const UMAX: i128 = MAX - 1;
+}
+
+/// A type that has a statically knowable signed minimum value.
+pub trait SignedMin<T: SignedBoundable>: Predicate<T> {
+    /// The minimum value.
+    const UMIN: i128;
+}
+
+impl<T: SignedBoundable, const MIN: i128> SignedMin<T> for signed::GreaterThan<MIN> {
+    const UMIN: i128 = MIN + 1;
+}
+
+impl<T: SignedBoundable, const MIN: i128> SignedMin<T> for signed::GreaterThanEqual<MIN> {
+    const UMIN: i128 = MIN;
+}
+
+impl<T: SignedBoundable, const VAL: i128> SignedMin<T> for signed::Equals<VAL> {
+    const UMIN: i128 = VAL;
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMin<T>
+    for signed::ClosedInterval<MIN, MAX>
+{
+    const UMIN: i128 = MIN;
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMin<T>
+    for signed::OpenClosedInterval<MIN, MAX>
+{
+    const UMIN: i128 = MIN + 1;
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMin<T>
+    for signed::OpenInterval<MIN, MAX>
+{
+    const UMIN: i128 = MIN + 1;
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMin<T>
+    for signed::ClosedOpenInterval<MIN, MAX>
+{
+    const UMIN: i128 = MIN;
+}
+
+/// A type that has a statically knowable signed minimum value and maximum value.
+pub trait SignedMinMax<T: SignedBoundable>: Predicate<T> + SignedMin<T> + SignedMax<T> {}
+
+impl<T: SignedBoundable, const VAL: i128> SignedMinMax<T> for signed::Equals<VAL> {}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMinMax<T>
+    for signed::ClosedInterval<MIN, MAX>
+{
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMinMax<T>
+    for signed::OpenClosedInterval<MIN, MAX>
+{
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMinMax<T>
+    for signed::OpenInterval<MIN, MAX>
+{
+}
+
+impl<T: SignedBoundable, const MIN: i128, const MAX: i128> SignedMinMax<T>
+    for signed::ClosedOpenInterval<MIN, MAX>
+{
+}
+
+pub const fn elem_min(a: i128, b: i128) -> i128 {
+    if a <= b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Calculates the minimum bounds for an interval over multiplication.
+pub const fn min_mul(xmin: i128, xmax: i128, ymin: i128, ymax: i128) -> i128 {
+    elem_min(
+        xmin * ymin,
+        elem_min(xmin * ymax, elem_min(xmax * ymin, xmax * ymax)),
+    )
+}
+
+/// Calculates the minimum bounds for an interval over division.
+pub const fn min_div(xmin: i128, xmax: i128, ymin: i128, ymax: i128) -> i128 {
+    elem_min(
+        xmin / ymin,
+        elem_min(xmin / ymax, elem_min(xmax / ymin, xmax / ymax)),
+    )
+}
+
+pub const fn elem_max(a: i128, b: i128) -> i128 {
+    if a >= b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Calculates the maximum bounds for an interval over multiplication.
+pub const fn max_mul(xmin: i128, xmax: i128, ymin: i128, ymax: i128) -> i128 {
+    elem_max(
+        xmin * ymin,
+        elem_max(xmin * ymax, elem_max(xmax * ymin, xmax * ymax)),
+    )
+}
+
+/// Calculates the maximum bounds for an interval over division.
+pub const fn max_div(xmin: i128, xmax: i128, ymin: i128, ymax: i128) -> i128 {
+    elem_max(
+        xmin / ymin,
+        elem_max(xmin / ymax, elem_max(xmax / ymin, xmax / ymax)),
+    )
+}
+
+pub const fn unsigned_min(a: u128, b: u128) -> u128 {
+    if a <= b {
+        a
+    } else {
+        b
+    }
+}
+
+/// Shifts an unsigned bound left, failing if any bits would be lost.
+pub const fn unsigned_shl(x: u128, s: u128) -> u128 {
+    assert!(
+        s < 128 && (x << s) >> s == x,
+        "left shift overflows the bound"
+    );
+    x << s
+}
+
+/// Shifts a signed bound left, failing if any bits would be lost.
+pub const fn signed_shl(x: i128, s: u128) -> i128 {
+    assert!(
+        s < 128 && (x << s) >> s == x,
+        "left shift overflows the bound"
+    );
+    x << s
+}
+
+/// Calculates the minimum bounds for an interval over remainder.
+pub const fn min_rem(xmin: i128, _xmax: i128, ymin: i128, ymax: i128) -> i128 {
+    if xmin < 0 {
+        elem_max(xmin, 1 - elem_max(ymin.abs(), ymax.abs()))
+    } else {
+        0
+    }
+}
+
+/// Calculates the maximum bounds for an interval over remainder.
+pub const fn max_rem(_xmin: i128, xmax: i128, ymin: i128, ymax: i128) -> i128 {
+    if xmax > 0 {
+        elem_min(xmax, elem_max(ymin.abs(), ymax.abs()) - 1)
+    } else {
+        0
+    }
+}
+
+/// Calculates the minimum bounds for an interval over left shift.
+pub const fn min_shl(xmin: i128, xmax: i128, smin: u128, smax: u128) -> i128 {
+    elem_min(
+        signed_shl(xmin, smin),
+        elem_min(
+            signed_shl(xmin, smax),
+            elem_min(signed_shl(xmax, smin), signed_shl(xmax, smax)),
+        ),
+    )
+}
+
+/// Calculates the maximum bounds for an interval over left shift.
+pub const fn max_shl(xmin: i128, xmax: i128, smin: u128, smax: u128) -> i128 {
+    elem_max(
+        signed_shl(xmin, smin),
+        elem_max(
+            signed_shl(xmin, smax),
+            elem_max(signed_shl(xmax, smin), signed_shl(xmax, smax)),
+        ),
+    )
+}
+
+/// Calculates the minimum bounds for an interval over right shift.
+pub const fn min_shr(xmin: i128, xmax: i128, smin: u128, smax: u128) -> i128 {
+    elem_min(
+        xmin >> smin,
+        elem_min(xmin >> smax, elem_min(xmax >> smin, xmax >> smax)),
+    )
+}
+
+/// Calculates the maximum bounds for an interval over right shift.
+pub const fn max_shr(xmin: i128, xmax: i128, smin: u128, smax: u128) -> i128 {
+    elem_max(
+        xmin >> smin,
+        elem_max(xmin >> smax, elem_max(xmax >> smin, xmax >> smax)),
+    )
+}
+
+/// Calculates the minimum bounds for an interval raised to the power of `exp`.
+pub const fn min_pow(xmin: i128, xmax: i128, exp: u32) -> i128 {
+    if exp % 2 == 1 {
+        xmin.pow(exp)
+    } else if xmin <= 0 && xmax >= 0 {
+        if exp == 0 {
+            1
+        } else {
+            0
+        }
+    } else {
+        elem_min(xmin.pow(exp), xmax.pow(exp))
+    }
+}
+
+/// Calculates the maximum bounds for an interval raised to the power of `exp`.
+pub const fn max_pow(xmin: i128, xmax: i128, exp: u32) -> i128 {
+    if exp % 2 == 1 {
+        xmax.pow(exp)
+    } else {
+        elem_max(xmin.pow(exp), xmax.pow(exp))
+    }
+}
@@ -8,5 +8,77 @@
 //! don't support any form of polymorphism), signed and unsigned bounding must be
 //! implemented independently. The signed numerics are implemented using signed bounds,
 //! while all other types are implemented using unsigned bounds.
+//!
+//! Each module also provides `Dyn` variants of its bounds, such as `DynLessThan`. These are
+//! [StatefulPredicate](crate::StatefulPredicate) implementations whose bounds are runtime values
+//! (for example, limits read from configuration) rather than const generics.
+
+macro_rules! dynamic_bound {
+    ($name:ident, $field:ident, $bound:ty, $boundable:ident, $variant:ident, $kind:ident, $op:tt, $message:literal, $static_message:literal) => {
+        #[doc = concat!("[", stringify!($kind), "] with a bound that is only known at runtime.")]
+        ///
+        /// The bound is carried by the predicate instance, so this predicate can only be
+        /// certified statefully (e.g. via [refine_with_state](crate::StatefulRefinementOps::refine_with_state)
+        /// or [Certified](crate::Certified)). Stateless refinement always fails, including through
+        /// combinators such as [Not](crate::boolean::Not).
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name {
+            pub $field: $bound,
+        }
+
+        impl $name {
+            pub const fn new($field: $bound) -> Self {
+                Self { $field }
+            }
+        }
+
+        impl<T: $boundable> Predicate<T> for $name {
+            const RUNTIME_STATE: bool = true;
+
+            fn test(_: &T) -> bool {
+                false
+            }
+
+            #[cfg(feature = "alloc")]
+            fn error() -> ErrorMessage {
+                ErrorMessage::from(concat!($message, " a bound provided at runtime"))
+            }
+
+            #[cfg(not(feature = "alloc"))]
+            fn error() -> ErrorMessage {
+                $static_message
+            }
+
+            #[cfg(feature = "alloc")]
+            fn violation() -> Violation {
+                Violation::predicate(PredicateKind::$kind, &[], <Self as Predicate<T>>::error())
+            }
+        }
+
+        impl<T: $boundable> StatefulPredicate<T> for $name {
+            fn test(&self, value: &T) -> bool {
+                value.bounding_value() $op self.$field
+            }
+
+            #[cfg(feature = "alloc")]
+            fn error(&self) -> ErrorMessage {
+                format!(concat!($message, " {}"), self.$field)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn violation(&self) -> Violation {
+                Violation::predicate(
+                    PredicateKind::$kind,
+                    &[Bound::$variant(self.$field)],
+                    StatefulPredicate::<T>::error(self),
+                )
+            }
+        }
+    };
+}
+
 pub mod signed;
 pub mod unsigned;
+
+mod bounds;
+pub use bounds::*;
@@ -13,115 +13,107 @@
 //! let not_ok = RangedI64::refine(11);
 //! assert!(not_ok.is_err());
 //! ```
-use crate::{boolean::*, ErrorMessage, Predicate};
+use core::marker::PhantomData;
+
+use crate::{
+    boolean::*, ConstPredicate, ConstValue, ErrorMessage, Predicate, StatefulPredicate, Stateless,
+};
+#[cfg(feature = "alloc")]
+use crate::{Bound, PredicateKind, Violation};
 #[cfg(feature = "alloc")]
 use alloc::format;
 
 /// Types that can be reduced to a signed size so that they can be bounded.
+///
+/// Sizes are represented as [i128] so that every primitive signed integer can be bounded
+/// across its full range, regardless of the target's pointer width.
+///
+/// Unsigned primitives up to 64 bits wide are also signed boundable, allowing them to be bounded by ranges
+/// that include negative values. When both boundable traits are in scope, call `bounding_value` on these
+/// types through the trait, as in `SignedBoundable::bounding_value(&value)`.
+///
+/// Bounds outside of [BOUND_MIN](SignedBoundable::BOUND_MIN) and [BOUND_MAX](SignedBoundable::BOUND_MAX)
+/// cannot be represented by the type and are therefore rejected at compile time:
+///
+/// ```compile_fail
+/// use refined::{Refinement, RefinementOps, boundable::signed::GreaterThan};
+///
+/// let _ = Refinement::<i8, GreaterThan<-200>>::refine(5);
+/// ```
 pub trait SignedBoundable {
-    fn bounding_value(&self) -> isize;
-}
-
-impl SignedBoundable for i8 {
-    fn bounding_value(&self) -> isize {
-        *self as isize
-    }
-}
-
-impl SignedBoundable for core::num::Saturating<i8> {
-    fn bounding_value(&self) -> isize {
-        self.0 as isize
-    }
-}
-
-impl SignedBoundable for core::num::NonZeroI8 {
-    fn bounding_value(&self) -> isize {
-        self.get() as isize
-    }
-}
-
-impl SignedBoundable for i16 {
-    fn bounding_value(&self) -> isize {
-        *self as isize
-    }
-}
-
-impl SignedBoundable for core::num::Saturating<i16> {
-    fn bounding_value(&self) -> isize {
-        self.0 as isize
-    }
-}
-
-impl SignedBoundable for core::num::NonZeroI16 {
-    fn bounding_value(&self) -> isize {
-        self.get() as isize
-    }
-}
+    /// The smallest bound that can be meaningfully applied to the type.
+    const BOUND_MIN: i128 = i128::MIN;
 
-impl SignedBoundable for i32 {
-    fn bounding_value(&self) -> isize {
-        *self as isize
-    }
-}
+    /// The largest bound that can be meaningfully applied to the type.
+    const BOUND_MAX: i128 = i128::MAX;
 
-impl SignedBoundable for core::num::Saturating<i32> {
-    fn bounding_value(&self) -> isize {
-        self.0 as isize
-    }
+    fn bounding_value(&self) -> i128;
 }
 
-impl SignedBoundable for core::num::NonZeroI32 {
-    fn bounding_value(&self) -> isize {
-        self.get() as isize
-    }
-}
+pub(crate) struct InDomain<T: ?Sized, const BOUND: i128>(PhantomData<T>);
 
-impl SignedBoundable for isize {
-    fn bounding_value(&self) -> isize {
-        *self
-    }
+impl<T: SignedBoundable + ?Sized, const BOUND: i128> InDomain<T, BOUND> {
+    pub(crate) const CHECK: () = assert!(
+        T::BOUND_MIN <= BOUND && BOUND <= T::BOUND_MAX,
+        "bound does not fit in the domain of the bounded type"
+    );
 }
 
-impl SignedBoundable for core::num::Saturating<isize> {
-    fn bounding_value(&self) -> isize {
-        self.0
-    }
+macro_rules! signed_boundable_primitive {
+    ($($t:ty, $nz:ty);+) => {
+        $(
+            impl SignedBoundable for $t {
+                const BOUND_MIN: i128 = <$t>::MIN as i128;
+                const BOUND_MAX: i128 = <$t>::MAX as i128;
+
+                fn bounding_value(&self) -> i128 {
+                    *self as i128
+                }
+            }
+
+            impl SignedBoundable for core::num::Saturating<$t> {
+                const BOUND_MIN: i128 = <$t>::MIN as i128;
+                const BOUND_MAX: i128 = <$t>::MAX as i128;
+
+                fn bounding_value(&self) -> i128 {
+                    self.0 as i128
+                }
+            }
+
+            impl SignedBoundable for $nz {
+                const BOUND_MIN: i128 = <$t>::MIN as i128;
+                const BOUND_MAX: i128 = <$t>::MAX as i128;
+
+                fn bounding_value(&self) -> i128 {
+                    self.get() as i128
+                }
+            }
+        )+
+    };
 }
 
-impl SignedBoundable for core::num::NonZeroIsize {
-    fn bounding_value(&self) -> isize {
-        self.get()
-    }
-}
-
-#[cfg(target_pointer_width = "64")]
-impl SignedBoundable for i64 {
-    fn bounding_value(&self) -> isize {
-        *self as isize
-    }
-}
-
-#[cfg(target_pointer_width = "64")]
-impl SignedBoundable for core::num::Saturating<i64> {
-    fn bounding_value(&self) -> isize {
-        self.0 as isize
-    }
-}
-
-#[cfg(target_pointer_width = "64")]
-impl SignedBoundable for core::num::NonZeroI64 {
-    fn bounding_value(&self) -> isize {
-        self.get() as isize
-    }
-}
+signed_boundable_primitive!(
+    i8, core::num::NonZeroI8;
+    i16, core::num::NonZeroI16;
+    i32, core::num::NonZeroI32;
+    i64, core::num::NonZeroI64;
+    i128, core::num::NonZeroI128;
+    isize, core::num::NonZeroIsize;
+    u8, core::num::NonZeroU8;
+    u16, core::num::NonZeroU16;
+    u32, core::num::NonZeroU32;
+    u64, core::num::NonZeroU64;
+    usize, core::num::NonZeroUsize
+);
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct GreaterThan<const MIN: isize>;
+pub struct GreaterThan<const MIN: i128>;
 
-pub type GT<const MIN: isize> = GreaterThan<MIN>;
+pub type GT<const MIN: i128> = GreaterThan<MIN>;
 
-impl<T: SignedBoundable, const MIN: isize> Predicate<T> for GreaterThan<MIN> {
+impl<T: SignedBoundable, const MIN: i128> Predicate<T> for GreaterThan<MIN> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, MIN>::CHECK;
         value.bounding_value() > MIN
     }
 
@@ -135,18 +127,28 @@ impl<T: SignedBoundable, const MIN: isize> Predicate<T> for GreaterThan<MIN> {
         "greater than"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::GreaterThan,
+            &[Bound::Signed(MIN)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct GreaterThanEqual<const MIN: isize>;
+pub struct GreaterThanEqual<const MIN: i128>;
 
-pub type GTE<const MIN: isize> = GreaterThanEqual<MIN>;
+pub type GTE<const MIN: i128> = GreaterThanEqual<MIN>;
 
-impl<T: SignedBoundable, const MIN: isize> Predicate<T> for GreaterThanEqual<MIN> {
+impl<T: SignedBoundable, const MIN: i128> Predicate<T> for GreaterThanEqual<MIN> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, MIN>::CHECK;
         value.bounding_value() >= MIN
     }
 
@@ -160,18 +162,28 @@ impl<T: SignedBoundable, const MIN: isize> Predicate<T> for GreaterThanEqual<MIN
         "greater than equal"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::GreaterThanEqual,
+            &[Bound::Signed(MIN)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct LessThan<const MAX: isize>;
+pub struct LessThan<const MAX: i128>;
 
-pub type LT<const MAX: isize> = LessThan<MAX>;
+pub type LT<const MAX: i128> = LessThan<MAX>;
 
-impl<T: SignedBoundable, const MAX: isize> Predicate<T> for LessThan<MAX> {
+impl<T: SignedBoundable, const MAX: i128> Predicate<T> for LessThan<MAX> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, MAX>::CHECK;
         value.bounding_value() < MAX
     }
 
@@ -185,18 +197,28 @@ impl<T: SignedBoundable, const MAX: isize> Predicate<T> for LessThan<MAX> {
         "less than"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::LessThan,
+            &[Bound::Signed(MAX)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct LessThanEqual<const MAX: isize>;
+pub struct LessThanEqual<const MAX: i128>;
 
-pub type LTE<const MAX: isize> = LessThanEqual<MAX>;
+pub type LTE<const MAX: i128> = LessThanEqual<MAX>;
 
-impl<T: SignedBoundable, const MAX: isize> Predicate<T> for LessThanEqual<MAX> {
+impl<T: SignedBoundable, const MAX: i128> Predicate<T> for LessThanEqual<MAX> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, MAX>::CHECK;
         value.bounding_value() <= MAX
     }
 
@@ -210,24 +232,48 @@ impl<T: SignedBoundable, const MAX: isize> Predicate<T> for LessThanEqual<MAX> {
         "less than equal"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::LessThanEqual,
+            &[Bound::Signed(MAX)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
-pub type OpenInterval<const MIN: isize, const MAX: isize> = And<GT<MIN>, LT<MAX>>;
+pub type OpenInterval<const MIN: i128, const MAX: i128> = And<GT<MIN>, LT<MAX>>;
+
+pub type OpenClosedInterval<const MIN: i128, const MAX: i128> = And<GT<MIN>, LTE<MAX>>;
+
+pub type ClosedOpenInterval<const MIN: i128, const MAX: i128> = And<GTE<MIN>, LT<MAX>>;
 
-pub type OpenClosedInterval<const MIN: isize, const MAX: isize> = And<GT<MIN>, LTE<MAX>>;
+pub type ClosedInterval<const MIN: i128, const MAX: i128> = And<GTE<MIN>, LTE<MAX>>;
 
-pub type ClosedOpenInterval<const MIN: isize, const MAX: isize> = And<GTE<MIN>, LT<MAX>>;
+dynamic_bound!(DynGreaterThan, min, i128, SignedBoundable, Signed, GreaterThan, >, "must be greater than", "greater than");
+dynamic_bound!(DynGreaterThanEqual, min, i128, SignedBoundable, Signed, GreaterThanEqual, >=, "must be greater than or equal to", "greater than equal");
+dynamic_bound!(DynLessThan, max, i128, SignedBoundable, Signed, LessThan, <, "must be less than", "less than");
+dynamic_bound!(DynLessThanEqual, max, i128, SignedBoundable, Signed, LessThanEqual, <=, "must be less than or equal to", "less than equal");
 
-pub type ClosedInterval<const MIN: isize, const MAX: isize> = And<GTE<MIN>, LTE<MAX>>;
+pub type DynOpenInterval = And<DynGreaterThan, DynLessThan>;
+
+pub type DynOpenClosedInterval = And<DynGreaterThan, DynLessThanEqual>;
+
+pub type DynClosedOpenInterval = And<DynGreaterThanEqual, DynLessThan>;
+
+pub type DynClosedInterval = And<DynGreaterThanEqual, DynLessThanEqual>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct Modulo<const DIV: isize, const MOD: isize>;
+pub struct Modulo<const DIV: i128, const MOD: i128>;
 
-impl<T: SignedBoundable, const DIV: isize, const MOD: isize> Predicate<T> for Modulo<DIV, MOD> {
+impl<T: SignedBoundable, const DIV: i128, const MOD: i128> Predicate<T> for Modulo<DIV, MOD> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, DIV>::CHECK;
+        let () = InDomain::<T, MOD>::CHECK;
         value.bounding_value() % DIV == MOD
     }
 
@@ -241,22 +287,32 @@ impl<T: SignedBoundable, const DIV: isize, const MOD: isize> Predicate<T> for Mo
         "modulo"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::Modulo,
+            &[Bound::Signed(DIV), Bound::Signed(MOD)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
-pub type Divisible<const DIV: isize> = Modulo<DIV, 0>;
+pub type Divisible<const DIV: i128> = Modulo<DIV, 0>;
 
 pub type Even = Modulo<2, 0>;
 
 pub type Odd = Not<Even>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct Equals<const VAL: isize>;
+pub struct Equals<const VAL: i128>;
 
-impl<T: SignedBoundable, const VAL: isize> Predicate<T> for Equals<VAL> {
+impl<T: SignedBoundable, const VAL: i128> Predicate<T> for Equals<VAL> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, VAL>::CHECK;
         value.bounding_value() == VAL
     }
 
@@ -270,8 +326,17 @@ impl<T: SignedBoundable, const VAL: isize> Predicate<T> for Equals<VAL> {
         "equals"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::Equals,
+            &[Bound::Signed(VAL)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
@@ -287,11 +352,85 @@ pub type Negative = LT<0>;
 
 pub type NonNegative = Not<Negative>;
 
+macro_rules! signed_const_predicate {
+    ($($t:ty),+) => {
+        $(
+            impl<V: ConstValue<$t>, const MIN: i128> ConstPredicate<$t, V> for GreaterThan<MIN> {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, MIN>::CHECK;
+                    V::VALUE as i128 > MIN
+                };
+            }
+
+            impl<V: ConstValue<$t>, const MIN: i128> ConstPredicate<$t, V> for GreaterThanEqual<MIN> {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, MIN>::CHECK;
+                    V::VALUE as i128 >= MIN
+                };
+            }
+
+            impl<V: ConstValue<$t>, const MAX: i128> ConstPredicate<$t, V> for LessThan<MAX> {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, MAX>::CHECK;
+                    (V::VALUE as i128) < MAX
+                };
+            }
+
+            impl<V: ConstValue<$t>, const MAX: i128> ConstPredicate<$t, V> for LessThanEqual<MAX> {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, MAX>::CHECK;
+                    V::VALUE as i128 <= MAX
+                };
+            }
+
+            impl<V: ConstValue<$t>, const DIV: i128, const MOD: i128> ConstPredicate<$t, V>
+                for Modulo<DIV, MOD>
+            {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, DIV>::CHECK;
+                    let () = InDomain::<$t, MOD>::CHECK;
+                    V::VALUE as i128 % DIV == MOD
+                };
+            }
+
+            impl<V: ConstValue<$t>, const VAL: i128> ConstPredicate<$t, V> for Equals<VAL> {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, VAL>::CHECK;
+                    V::VALUE as i128 == VAL
+                };
+            }
+        )+
+    };
+}
+
+signed_const_predicate!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, usize);
+
+impl<const MIN: i128> Stateless for GreaterThan<MIN> {}
+
+impl<const MIN: i128> Stateless for GreaterThanEqual<MIN> {}
+
+impl<const MAX: i128> Stateless for LessThan<MAX> {}
+
+impl<const MAX: i128> Stateless for LessThanEqual<MAX> {}
+
+impl<const DIV: i128, const MOD: i128> Stateless for Modulo<DIV, MOD> {}
+
+impl<const VAL: i128> Stateless for Equals<VAL> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::*;
 
+    #[test]
+    fn test_refine_const() {
+        type Offset = Refinement<i32, OpenInterval<-10, 10>>;
+        const OFFSET: Offset = refine_const!(Offset, -9);
+        const ODD: Refinement<u64, Odd> = refine_const!(Refinement<u64, Odd>, u64::MAX);
+        assert_eq!(*OFFSET, -9);
+        assert_eq!(*ODD, u64::MAX);
+    }
+
     #[test]
     fn test_greater_than() {
         type Test = Refinement<i64, GreaterThan<5>>;
@@ -300,6 +439,23 @@ mod tests {
         assert!(Test::refine(4).is_err());
     }
 
+    #[test]
+    fn test_full_range() {
+        type Big = Refinement<i128, LessThan<{ i64::MIN as i128 }>>;
+        assert!(Big::refine(i128::MIN).is_ok());
+        assert!(Big::refine(i64::MIN as i128).is_err());
+        type Wide = Refinement<i64, GreaterThan<{ i32::MAX as i128 }>>;
+        assert!(Wide::refine(i64::MAX).is_ok());
+        assert!(Wide::refine(i32::MAX as i64).is_err());
+    }
+
+    #[test]
+    fn test_unsigned_in_signed_context() {
+        type Test = Refinement<u64, ClosedInterval<{ i64::MAX as i128 }, { u64::MAX as i128 }>>;
+        assert!(Test::refine(u64::MAX).is_ok());
+        assert!(Test::refine(i64::MAX as u64 - 1).is_err());
+    }
+
     #[test]
     fn test_greater_than_equal() {
         type Test = Refinement<i32, GreaterThanEqual<5>>;
@@ -457,4 +613,14 @@ mod tests {
         assert!(Test::refine(0).is_err());
         assert!(Test::refine(-2).is_err());
     }
+
+    #[test]
+    fn test_dyn_bounds() {
+        type Test = Refinement<i32, Or<DynLessThan, DynGreaterThanEqual>>;
+        let outside = Or::new(DynLessThan::new(-10), DynGreaterThanEqual::new(10));
+        assert!(Test::refine_with_state(&outside, -11).is_ok());
+        assert!(Test::refine_with_state(&outside, 10).is_ok());
+        assert!(Test::refine_with_state(&outside, 0).is_err());
+        assert!(Test::refine(-11).is_err());
+    }
 }
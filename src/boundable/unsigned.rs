@@ -14,7 +14,13 @@
 //! assert!(not_ok_string.is_err());
 //! ```
 
-use crate::{boolean::*, ErrorMessage, Predicate};
+use core::marker::PhantomData;
+
+use crate::{
+    boolean::*, ConstPredicate, ConstValue, ErrorMessage, Predicate, StatefulPredicate, Stateless,
+};
+#[cfg(feature = "alloc")]
+use crate::{Bound, PredicateKind, Violation};
 #[cfg(feature = "alloc")]
 use alloc::{
     collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
@@ -26,102 +32,72 @@ use alloc::{
 use std::collections::{HashMap, HashSet};
 
 /// Types that can be reduced to an unsigned size so that they can be bounded.
+///
+/// Sizes are represented as [u128] so that every primitive unsigned integer can be bounded
+/// across its full range, regardless of the target's pointer width.
+///
+/// Bounds that exceed [BOUND_MAX](UnsignedBoundable::BOUND_MAX) cannot be represented by the type and are
+/// therefore rejected at compile time:
+///
+/// ```compile_fail
+/// use refined::{Refinement, RefinementOps, boundable::unsigned::LessThan};
+///
+/// let _ = Refinement::<u8, LessThan<300>>::refine(5);
+/// ```
 pub trait UnsignedBoundable {
-    fn bounding_value(&self) -> usize;
-}
-
-impl UnsignedBoundable for u8 {
-    fn bounding_value(&self) -> usize {
-        *self as usize
-    }
-}
-
-impl UnsignedBoundable for core::num::Saturating<u8> {
-    fn bounding_value(&self) -> usize {
-        self.0 as usize
-    }
-}
+    /// The largest bound that can be meaningfully applied to the type.
+    const BOUND_MAX: u128 = u128::MAX;
 
-impl UnsignedBoundable for core::num::NonZeroU8 {
-    fn bounding_value(&self) -> usize {
-        self.get() as usize
-    }
-}
-
-impl UnsignedBoundable for u16 {
-    fn bounding_value(&self) -> usize {
-        *self as usize
-    }
+    fn bounding_value(&self) -> u128;
 }
 
-impl UnsignedBoundable for core::num::Saturating<u16> {
-    fn bounding_value(&self) -> usize {
-        self.0 as usize
-    }
-}
+pub(crate) struct InDomain<T: ?Sized, const BOUND: u128>(PhantomData<T>);
 
-impl UnsignedBoundable for core::num::NonZeroU16 {
-    fn bounding_value(&self) -> usize {
-        self.get() as usize
-    }
+impl<T: UnsignedBoundable + ?Sized, const BOUND: u128> InDomain<T, BOUND> {
+    pub(crate) const CHECK: () = assert!(
+        BOUND <= T::BOUND_MAX,
+        "bound does not fit in the domain of the bounded type"
+    );
 }
 
-impl UnsignedBoundable for u32 {
-    fn bounding_value(&self) -> usize {
-        *self as usize
-    }
-}
-
-impl UnsignedBoundable for core::num::Saturating<u32> {
-    fn bounding_value(&self) -> usize {
-        self.0 as usize
-    }
-}
-
-impl UnsignedBoundable for core::num::NonZeroU32 {
-    fn bounding_value(&self) -> usize {
-        self.get() as usize
-    }
-}
+macro_rules! unsigned_boundable_primitive {
+    ($($t:ty, $nz:ty);+) => {
+        $(
+            impl UnsignedBoundable for $t {
+                const BOUND_MAX: u128 = <$t>::MAX as u128;
 
-impl UnsignedBoundable for usize {
-    fn bounding_value(&self) -> usize {
-        *self
-    }
-}
+                fn bounding_value(&self) -> u128 {
+                    *self as u128
+                }
+            }
 
-impl UnsignedBoundable for core::num::Saturating<usize> {
-    fn bounding_value(&self) -> usize {
-        self.0
-    }
-}
+            impl UnsignedBoundable for core::num::Saturating<$t> {
+                const BOUND_MAX: u128 = <$t>::MAX as u128;
 
-impl UnsignedBoundable for core::num::NonZeroUsize {
-    fn bounding_value(&self) -> usize {
-        self.get()
-    }
-}
+                fn bounding_value(&self) -> u128 {
+                    self.0 as u128
+                }
+            }
 
-#[cfg(target_pointer_width = "64")]
-impl UnsignedBoundable for u64 {
-    fn bounding_value(&self) -> usize {
-        *self as usize
-    }
-}
+            impl UnsignedBoundable for $nz {
+                const BOUND_MAX: u128 = <$t>::MAX as u128;
 
-#[cfg(target_pointer_width = "64")]
-impl UnsignedBoundable for core::num::Saturating<u64> {
-    fn bounding_value(&self) -> usize {
-        self.0 as usize
-    }
+                fn bounding_value(&self) -> u128 {
+                    self.get() as u128
+                }
+            }
+        )+
+    };
 }
 
-#[cfg(target_pointer_width = "64")]
-impl UnsignedBoundable for core::num::NonZeroU64 {
-    fn bounding_value(&self) -> usize {
-        self.get() as usize
-    }
-}
+unsigned_boundable_primitive!(
+    u8, core::num::NonZeroU8;
+    u16, core::num::NonZeroU16;
+    u32, core::num::NonZeroU32;
+    u64, core::num::NonZeroU64;
+    u128, core::num::NonZeroU128;
+    usize, core::num::NonZeroUsize
+);
 
 /// Creates an [UnsignedBoundable] implementation for a struct that has a `len` method.
 ///
@@ -144,8 +120,10 @@ impl UnsignedBoundable for core::num::NonZeroU64 {
 macro_rules! unsigned_boundable_via_len {
     ($t:ident $(<$($ts:ident),+>)?) => {
         impl $(<$($ts),+>)? UnsignedBoundable for $t $(<$($ts),+>)? {
-            fn bounding_value(&self) -> usize {
-                self.len()
+            const BOUND_MAX: u128 = usize::MAX as u128;
+
+            fn bounding_value(&self) -> u128 {
+                self.len() as u128
             }
         }
     };
@@ -173,17 +151,20 @@ mod needs_std {
 }
 
 impl<T> UnsignedBoundable for [T] {
-    fn bounding_value(&self) -> usize {
-        self.len()
+    const BOUND_MAX: u128 = usize::MAX as u128;
+
+    fn bounding_value(&self) -> u128 {
+        self.len() as u128
     }
 }
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct GreaterThan<const MIN: usize>;
+pub struct GreaterThan<const MIN: u128>;
 
-pub type GT<const MIN: usize> = GreaterThan<MIN>;
+pub type GT<const MIN: u128> = GreaterThan<MIN>;
 
-impl<T: UnsignedBoundable, const MIN: usize> Predicate<T> for GreaterThan<MIN> {
+impl<T: UnsignedBoundable, const MIN: u128> Predicate<T> for GreaterThan<MIN> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, MIN>::CHECK;
         value.bounding_value() > MIN
     }
 
@@ -197,18 +178,28 @@ impl<T: UnsignedBoundable, const MIN: usize> Predicate<T> for GreaterThan<MIN> {
         "greater than"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::GreaterThan,
+            &[Bound::Unsigned(MIN)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct GreaterThanEqual<const MIN: usize>;
+pub struct GreaterThanEqual<const MIN: u128>;
 
-pub type GTE<const MIN: usize> = GreaterThanEqual<MIN>;
+pub type GTE<const MIN: u128> = GreaterThanEqual<MIN>;
 
-impl<T: UnsignedBoundable, const MIN: usize> Predicate<T> for GreaterThanEqual<MIN> {
+impl<T: UnsignedBoundable, const MIN: u128> Predicate<T> for GreaterThanEqual<MIN> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, MIN>::CHECK;
         value.bounding_value() >= MIN
     }
 
@@ -222,18 +213,28 @@ impl<T: UnsignedBoundable, const MIN: usize> Predicate<T> for GreaterThanEqual<M
         "greater than equal"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::GreaterThanEqual,
+            &[Bound::Unsigned(MIN)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct LessThan<const MAX: usize>;
+pub struct LessThan<const MAX: u128>;
 
-pub type LT<const MAX: usize> = LessThan<MAX>;
+pub type LT<const MAX: u128> = LessThan<MAX>;
 
-impl<T: UnsignedBoundable, const MAX: usize> Predicate<T> for LessThan<MAX> {
+impl<T: UnsignedBoundable, const MAX: u128> Predicate<T> for LessThan<MAX> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, MAX>::CHECK;
         value.bounding_value() < MAX
     }
 
@@ -247,18 +248,28 @@ impl<T: UnsignedBoundable, const MAX: usize> Predicate<T> for LessThan<MAX> {
         "less than"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::LessThan,
+            &[Bound::Unsigned(MAX)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct LessThanEqual<const MAX: usize>;
+pub struct LessThanEqual<const MAX: u128>;
 
-pub type LTE<const MAX: usize> = LessThanEqual<MAX>;
+pub type LTE<const MAX: u128> = LessThanEqual<MAX>;
 
-impl<T: UnsignedBoundable, const MAX: usize> Predicate<T> for LessThanEqual<MAX> {
+impl<T: UnsignedBoundable, const MAX: u128> Predicate<T> for LessThanEqual<MAX> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, MAX>::CHECK;
         value.bounding_value() <= MAX
     }
 
@@ -272,24 +283,48 @@ impl<T: UnsignedBoundable, const MAX: usize> Predicate<T> for LessThanEqual<MAX>
         "less than equal"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::LessThanEqual,
+            &[Bound::Unsigned(MAX)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
-pub type OpenInterval<const MIN: usize, const MAX: usize> = And<GT<MIN>, LT<MAX>>;
+pub type OpenInterval<const MIN: u128, const MAX: u128> = And<GT<MIN>, LT<MAX>>;
+
+pub type OpenClosedInterval<const MIN: u128, const MAX: u128> = And<GT<MIN>, LTE<MAX>>;
 
-pub type OpenClosedInterval<const MIN: usize, const MAX: usize> = And<GT<MIN>, LTE<MAX>>;
+pub type ClosedOpenInterval<const MIN: u128, const MAX: u128> = And<GTE<MIN>, LT<MAX>>;
 
-pub type ClosedOpenInterval<const MIN: usize, const MAX: usize> = And<GTE<MIN>, LT<MAX>>;
+pub type ClosedInterval<const MIN: u128, const MAX: u128> = And<GTE<MIN>, LTE<MAX>>;
 
-pub type ClosedInterval<const MIN: usize, const MAX: usize> = And<GTE<MIN>, LTE<MAX>>;
+dynamic_bound!(DynGreaterThan, min, u128, UnsignedBoundable, Unsigned, GreaterThan, >, "must be greater than", "greater than");
+dynamic_bound!(DynGreaterThanEqual, min, u128, UnsignedBoundable, Unsigned, GreaterThanEqual, >=, "must be greater than or equal to", "greater than equal");
+dynamic_bound!(DynLessThan, max, u128, UnsignedBoundable, Unsigned, LessThan, <, "must be less than", "less than");
+dynamic_bound!(DynLessThanEqual, max, u128, UnsignedBoundable, Unsigned, LessThanEqual, <=, "must be less than or equal to", "less than equal");
+
+pub type DynOpenInterval = And<DynGreaterThan, DynLessThan>;
+
+pub type DynOpenClosedInterval = And<DynGreaterThan, DynLessThanEqual>;
+
+pub type DynClosedOpenInterval = And<DynGreaterThanEqual, DynLessThan>;
+
+pub type DynClosedInterval = And<DynGreaterThanEqual, DynLessThanEqual>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct Modulo<const DIV: usize, const MOD: usize>;
+pub struct Modulo<const DIV: u128, const MOD: u128>;
 
-impl<T: UnsignedBoundable, const DIV: usize, const MOD: usize> Predicate<T> for Modulo<DIV, MOD> {
+impl<T: UnsignedBoundable, const DIV: u128, const MOD: u128> Predicate<T> for Modulo<DIV, MOD> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, DIV>::CHECK;
+        let () = InDomain::<T, MOD>::CHECK;
         value.bounding_value() % DIV == MOD
     }
 
@@ -303,22 +338,32 @@ impl<T: UnsignedBoundable, const DIV: usize, const MOD: usize> Predicate<T> for
         "modulo"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::Modulo,
+            &[Bound::Unsigned(DIV), Bound::Unsigned(MOD)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
-pub type Divisible<const DIV: usize> = Modulo<DIV, 0>;
+pub type Divisible<const DIV: u128> = Modulo<DIV, 0>;
 
 pub type Even = Modulo<2, 0>;
 
 pub type Odd = Not<Even>;
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
-pub struct Equals<const VAL: usize>;
+pub struct Equals<const VAL: u128>;
 
-impl<T: UnsignedBoundable, const VAL: usize> Predicate<T> for Equals<VAL> {
+impl<T: UnsignedBoundable, const VAL: u128> Predicate<T> for Equals<VAL> {
     fn test(value: &T) -> bool {
+        let () = InDomain::<T, VAL>::CHECK;
         value.bounding_value() == VAL
     }
 
@@ -332,8 +377,17 @@ impl<T: UnsignedBoundable, const VAL: usize> Predicate<T> for Equals<VAL> {
         "equals"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::Equals,
+            &[Bound::Unsigned(VAL)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
@@ -341,10 +395,87 @@ pub type Zero = Equals<0>;
 
 pub type NonZero = Not<Zero>;
 
+macro_rules! unsigned_const_predicate {
+    ($($t:ty),+) => {
+        $(
+            impl<V: ConstValue<$t>, const MIN: u128> ConstPredicate<$t, V> for GreaterThan<MIN> {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, MIN>::CHECK;
+                    V::VALUE as u128 > MIN
+                };
+            }
+
+            impl<V: ConstValue<$t>, const MIN: u128> ConstPredicate<$t, V> for GreaterThanEqual<MIN> {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, MIN>::CHECK;
+                    V::VALUE as u128 >= MIN
+                };
+            }
+
+            impl<V: ConstValue<$t>, const MAX: u128> ConstPredicate<$t, V> for LessThan<MAX> {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, MAX>::CHECK;
+                    (V::VALUE as u128) < MAX
+                };
+            }
+
+            impl<V: ConstValue<$t>, const MAX: u128> ConstPredicate<$t, V> for LessThanEqual<MAX> {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, MAX>::CHECK;
+                    V::VALUE as u128 <= MAX
+                };
+            }
+
+            impl<V: ConstValue<$t>, const DIV: u128, const MOD: u128> ConstPredicate<$t, V>
+                for Modulo<DIV, MOD>
+            {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, DIV>::CHECK;
+                    let () = InDomain::<$t, MOD>::CHECK;
+                    V::VALUE as u128 % DIV == MOD
+                };
+            }
+
+            impl<V: ConstValue<$t>, const VAL: u128> ConstPredicate<$t, V> for Equals<VAL> {
+                const HOLDS: bool = {
+                    let () = InDomain::<$t, VAL>::CHECK;
+                    V::VALUE as u128 == VAL
+                };
+            }
+        )+
+    };
+}
+
+unsigned_const_predicate!(u8, u16, u32, u64, u128, usize);
+
+impl<const MIN: u128> Stateless for GreaterThan<MIN> {}
+
+impl<const MIN: u128> Stateless for GreaterThanEqual<MIN> {}
+
+impl<const MAX: u128> Stateless for LessThan<MAX> {}
+
+impl<const MAX: u128> Stateless for LessThanEqual<MAX> {}
+
+impl<const DIV: u128, const MOD: u128> Stateless for Modulo<DIV, MOD> {}
+
+impl<const VAL: u128> Stateless for Equals<VAL> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::*;
+    #[cfg(feature = "alloc")]
+    use alloc::string::ToString;
+
+    #[test]
+    fn test_refine_const() {
+        const PORT: Refinement<u16, ClosedInterval<1, 65535>> =
+            refine_const!(Refinement<u16, ClosedInterval<1, 65535>>, 8080);
+        const EVEN: Refinement<u128, And<Even, NonZero>> =
+            refine_const!(Refinement<u128, And<Even, NonZero>>, u128::MAX - 1);
+        assert_eq!(*PORT, 8080);
+        assert_eq!(*EVEN, u128::MAX - 1);
+    }
 
     #[test]
     fn test_greater_than() {
@@ -354,6 +485,16 @@ mod tests {
         assert!(Test::refine(4).is_err());
     }
 
+    #[test]
+    fn test_full_range() {
+        type Big = Refinement<u128, GreaterThan<{ u64::MAX as u128 }>>;
+        assert!(Big::refine(u128::MAX).is_ok());
+        assert!(Big::refine(u64::MAX as u128).is_err());
+        type Wide = Refinement<u64, LessThanEqual<{ u32::MAX as u128 + 1 }>>;
+        assert!(Wide::refine(u32::MAX as u64 + 1).is_ok());
+        assert!(Wide::refine(u64::MAX).is_err());
+    }
+
     #[test]
     fn test_greater_than_equal() {
         type Test = Refinement<u32, GreaterThanEqual<5>>;
@@ -472,4 +613,78 @@ mod tests {
         assert!(Test::refine(4).is_err());
         assert!(Test::refine(0).is_err());
     }
+
+    #[test]
+    fn test_dyn_bounds() {
+        type Upload = Refinement<u64, DynLessThanEqual>;
+        let max_upload = DynLessThanEqual::new(1024);
+        assert!(Upload::refine_with_state(&max_upload, 1024).is_ok());
+        assert!(Upload::refine_with_state(&max_upload, 1025).is_err());
+        assert!(Upload::refine(0).is_err());
+    }
+
+    #[test]
+    fn test_dyn_bounds_reject_stateless_combinators() {
+        assert!(Refinement::<u8, Not<DynGreaterThan>>::refine(200).is_err());
+        assert!(Refinement::<u8, Or<DynGreaterThan, LessThan<5>>>::refine(2).is_err());
+        assert!(Refinement::<u8, Xor<DynGreaterThan, LessThan<5>>>::refine(2).is_err());
+        assert!(Refinement::<u8, boolean::Any<(DynLessThan, LessThan<5>)>>::refine(2).is_err());
+        assert!(Refinement::<u8, Not<DynGreaterThan>>::try_from(Refined(200)).is_err());
+        let mut value = Refinement::<u8, LessThan<5>>::refine(2).unwrap();
+        assert!(value.try_mutate(|v| *v += 1).is_ok());
+        assert!(Refinement::<u8, Not<DynGreaterThan>>::refine_with_state(
+            &Not::new(DynGreaterThan::new(3)),
+            2
+        )
+        .is_ok());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dyn_bounds_reject_stateless_collections() {
+        use crate::collection::{Count, NoneOf};
+        use alloc::vec;
+
+        assert!(Refinement::<Vec<u8>, NoneOf<DynGreaterThan>>::refine(vec![1, 2]).is_err());
+        assert!(
+            Refinement::<Vec<u8>, Count<DynGreaterThan, Equals<0>>>::refine(vec![1, 2]).is_err()
+        );
+    }
+
+    #[cfg(all(feature = "serde", feature = "alloc"))]
+    #[test]
+    fn test_dyn_bounds_reject_deserialize() {
+        assert!(serde_json::from_str::<Refinement<u8, Not<DynGreaterThan>>>("200").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dyn_interval() {
+        type Name = Refinement<String, DynClosedOpenInterval>;
+        let bounds = DynClosedOpenInterval::new(DynGreaterThanEqual::new(1), DynLessThan::new(4));
+        let name = Name::refine_with_state(&bounds, "abc".to_string()).unwrap();
+        assert!(name
+            .clone()
+            .replace_with_state(&bounds, "".to_string())
+            .is_err());
+        assert!(name.modify_with_state(&bounds, |n| n + "d").is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_dyn_bounds_error() {
+        type Test = Refinement<u8, Not<DynGreaterThan>>;
+        let err = Test::refine_with_state(&Not::new(DynGreaterThan::new(3)), 4).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: not must be greater than 3"
+        );
+        assert_eq!(
+            format!(
+                "{}",
+                Refinement::<u8, DynGreaterThan>::refine(4).unwrap_err()
+            ),
+            "refinement violated: must be greater than a bound provided at runtime"
+        );
+    }
 }
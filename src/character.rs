@@ -10,7 +10,9 @@
 //! assert!(Test::refine('0').is_ok());
 //! assert!(Test::refine('a').is_err());
 //! ```
-use crate::{ErrorMessage, Predicate};
+use crate::{ConstPredicate, ConstValue, ErrorMessage, Predicate, Stateless};
+#[cfg(feature = "alloc")]
+use crate::{PredicateKind, Violation};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct IsControl;
@@ -30,8 +32,17 @@ impl Predicate<char> for IsControl {
         "must be a control character"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::IsControl,
+            &[],
+            <Self as Predicate<char>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &char) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<char>>::test(value));
     }
 }
 
@@ -53,8 +64,17 @@ impl Predicate<char> for IsDigit {
         "must be a digit"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::IsDigit,
+            &[],
+            <Self as Predicate<char>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &char) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<char>>::test(value));
     }
 }
 
@@ -76,8 +96,17 @@ impl Predicate<char> for IsLowercase {
         "must be a lowercase character"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::IsLowercase,
+            &[],
+            <Self as Predicate<char>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &char) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<char>>::test(value));
     }
 }
 
@@ -99,8 +128,17 @@ impl Predicate<char> for IsUppercase {
         "must be an uppercase character"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::IsUppercase,
+            &[],
+            <Self as Predicate<char>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &char) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<char>>::test(value));
     }
 }
 
@@ -122,8 +160,17 @@ impl Predicate<char> for IsNumeric {
         "must be a numeric character"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::IsNumeric,
+            &[],
+            <Self as Predicate<char>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &char) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<char>>::test(value));
     }
 }
 
@@ -145,8 +192,17 @@ impl Predicate<char> for IsWhitespace {
         "must be a whitespace character"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::IsWhitespace,
+            &[],
+            <Self as Predicate<char>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &char) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<char>>::test(value));
     }
 }
 
@@ -168,16 +224,73 @@ impl Predicate<char> for IsHexDigit {
         "must be a valid hex character"
     }
 
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::IsHexDigit,
+            &[],
+            <Self as Predicate<char>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &char) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<char>>::test(value));
     }
 }
 
+impl<V: ConstValue<char>> ConstPredicate<char, V> for IsControl {
+    const HOLDS: bool = matches!(V::VALUE, '\u{0}'..='\u{1f}' | '\u{7f}'..='\u{9f}');
+}
+
+impl<V: ConstValue<char>> ConstPredicate<char, V> for IsDigit {
+    const HOLDS: bool = V::VALUE.is_ascii_digit();
+}
+
+impl<V: ConstValue<char>> ConstPredicate<char, V> for IsLowercase {
+    const HOLDS: bool = V::VALUE.is_lowercase();
+}
+
+impl<V: ConstValue<char>> ConstPredicate<char, V> for IsUppercase {
+    const HOLDS: bool = V::VALUE.is_uppercase();
+}
+
+impl<V: ConstValue<char>> ConstPredicate<char, V> for IsWhitespace {
+    const HOLDS: bool = V::VALUE.is_whitespace();
+}
+
+impl<V: ConstValue<char>> ConstPredicate<char, V> for IsHexDigit {
+    const HOLDS: bool = V::VALUE.is_ascii_hexdigit();
+}
+
+impl Stateless for IsControl {}
+
+impl Stateless for IsDigit {}
+
+impl Stateless for IsLowercase {}
+
+impl Stateless for IsUppercase {}
+
+impl Stateless for IsNumeric {}
+
+impl Stateless for IsWhitespace {}
+
+impl Stateless for IsHexDigit {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::*;
 
+    #[test]
+    fn test_refine_const() {
+        const LOWER: Refinement<char, IsLowercase> =
+            refine_const!(Refinement<char, IsLowercase>, 'é');
+        const CONTROL: Refinement<char, IsControl> =
+            refine_const!(Refinement<char, IsControl>, '\u{009C}');
+        assert_eq!(*LOWER, 'é');
+        assert_eq!(*CONTROL, '\u{009C}');
+    }
+
     #[test]
     fn test_is_control() {
         type Test = Refinement<char, IsControl>;
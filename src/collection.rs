@@ -0,0 +1,589 @@
+//! Element-wise refinement of collections.
+//!
+//! While [boundable::unsigned](crate::boundable::unsigned) can bound the length of a collection,
+//! the predicates in this module look at its elements. They apply to any type that implements
+//! [Elements], which includes arrays, slices, and the `alloc` and `std` collections. Maps are
+//! refined by their values.
+//!
+//! When refinement fails, the index (or, for maps, the key) of the first offending element is
+//! reported.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boolean::And, boundable::unsigned::ClosedInterval, collection::*, string::Contains};
+//!
+//! type_string!(At, "@");
+//!
+//! type Recipients = Refinement<Vec<String>, And<ClosedInterval<1, 10>, And<ForAll<Contains<At>>, Unique>>>;
+//!
+//! assert!(Recipients::refine(vec!["a@example.com".to_string(), "b@example.com".to_string()]).is_ok());
+//! assert_eq!(
+//!     Recipients::refine(vec!["a@example.com".to_string(), "b".to_string()]).unwrap_err().to_string(),
+//!     "refinement violated: element 1 must contain '@'"
+//! );
+//! assert_eq!(
+//!     Recipients::refine(vec!["a@example.com".to_string(), "a@example.com".to_string()]).unwrap_err().to_string(),
+//!     "refinement violated: element 1 must not duplicate a preceding element"
+//! );
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{
+    collections::{BTreeMap, BTreeSet, BinaryHeap, LinkedList, VecDeque},
+    format,
+    vec::Vec,
+};
+#[cfg(feature = "alloc")]
+use core::fmt::Debug;
+use core::marker::PhantomData;
+#[cfg(feature = "std")]
+use std::collections::{HashMap, HashSet};
+
+use crate::{ErrorMessage, Predicate, Stateless};
+#[cfg(feature = "alloc")]
+use crate::{Position, PredicateKind, Violation};
+
+/// A collection whose elements can be refined by the predicates in this module.
+pub trait Elements {
+    /// The type of the elements.
+    type Element;
+
+    /// Iterates over the elements of the collection.
+    fn elements(&self) -> impl Iterator<Item = &Self::Element>;
+
+    /// The position of the element at `index` in the iteration order of [Elements::elements],
+    /// used in error messages.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn position(&self, index: usize) -> Position {
+        Position::Index(index)
+    }
+}
+
+impl<T> Elements for [T] {
+    type Element = T;
+
+    fn elements(&self) -> impl Iterator<Item = &T> {
+        self.iter()
+    }
+}
+
+impl<T, const N: usize> Elements for [T; N] {
+    type Element = T;
+
+    fn elements(&self) -> impl Iterator<Item = &T> {
+        self.iter()
+    }
+}
+
+impl<C: Elements + ?Sized> Elements for &C {
+    type Element = C::Element;
+
+    fn elements(&self) -> impl Iterator<Item = &C::Element> {
+        (**self).elements()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn position(&self, index: usize) -> Position {
+        (**self).position(index)
+    }
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! sequence_elements {
+    ($($t:ident),+) => {
+        $(
+            impl<T> Elements for $t<T> {
+                type Element = T;
+
+                fn elements(&self) -> impl Iterator<Item = &T> {
+                    self.iter()
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "alloc")]
+macro_rules! map_elements {
+    ($($t:ident),+) => {
+        $(
+            impl<K: Debug, V> Elements for $t<K, V> {
+                type Element = V;
+
+                fn elements(&self) -> impl Iterator<Item = &V> {
+                    self.values()
+                }
+
+                fn position(&self, index: usize) -> Position {
+                    match self.keys().nth(index) {
+                        Some(key) => Position::Key(format!("{:?}", key)),
+                        None => Position::Index(index),
+                    }
+                }
+            }
+        )+
+    };
+}
+
+#[cfg(feature = "alloc")]
+sequence_elements!(Vec, VecDeque, LinkedList, BTreeSet, BinaryHeap);
+#[cfg(feature = "alloc")]
+map_elements!(BTreeMap);
+
+#[cfg(feature = "std")]
+sequence_elements!(HashSet);
+#[cfg(feature = "std")]
+map_elements!(HashMap);
+
+/// Every element satisfies the predicate `P`.
+///
+/// Errors name the first element that does not.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct ForAll<P>(PhantomData<P>);
+
+impl<C: Elements, P: Predicate<C::Element>> Predicate<C> for ForAll<P> {
+    const RUNTIME_STATE: bool = P::RUNTIME_STATE;
+
+    fn test(value: &C) -> bool {
+        value.elements().all(|element| P::test(element))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        format!("every element {}", P::error())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        "every element must satisfy the predicate"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(PredicateKind::ForAll, &[], <Self as Predicate<C>>::error())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &C) -> Violation {
+        match value
+            .elements()
+            .enumerate()
+            .find(|(_, element)| !P::test(element))
+        {
+            Some((index, element)) => {
+                Violation::element(value.position(index), P::explain(element))
+            }
+            None => <Self as Predicate<C>>::violation(),
+        }
+    }
+
+    unsafe fn optimize(value: &C) {
+        for element in value.elements() {
+            P::optimize(element);
+        }
+    }
+}
+
+/// At least one element satisfies the predicate `P`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Exists<P>(PhantomData<P>);
+
+impl<C: Elements, P: Predicate<C::Element>> Predicate<C> for Exists<P> {
+    const RUNTIME_STATE: bool = P::RUNTIME_STATE;
+
+    fn test(value: &C) -> bool {
+        value.elements().any(|element| P::test(element))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        format!("at least one element {}", P::error())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        "at least one element must satisfy the predicate"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(PredicateKind::Exists, &[], <Self as Predicate<C>>::error())
+    }
+
+    unsafe fn optimize(value: &C) {
+        if !<Self as Predicate<C>>::RUNTIME_STATE {
+            core::hint::assert_unchecked(<Self as Predicate<C>>::test(value));
+        }
+    }
+}
+
+/// Negates a predicate's error message, turning "must be ..." into "must not be ...".
+#[cfg(feature = "alloc")]
+fn negated(message: &str) -> ErrorMessage {
+    if let Some(rest) = message.strip_prefix("must not ") {
+        format!("must {}", rest)
+    } else if let Some(rest) = message.strip_prefix("must ") {
+        format!("must not {}", rest)
+    } else {
+        format!("must not satisfy \"{}\"", message)
+    }
+}
+
+/// No element satisfies the predicate `P`.
+///
+/// Errors name the first element that does.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct NoneOf<P>(PhantomData<P>);
+
+impl<C: Elements, P: Predicate<C::Element>> Predicate<C> for NoneOf<P> {
+    const RUNTIME_STATE: bool = P::RUNTIME_STATE;
+
+    fn test(value: &C) -> bool {
+        !value.elements().any(|element| P::test(element))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        format!("every element {}", negated(&P::error()))
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        "no element may satisfy the predicate"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(PredicateKind::NoneOf, &[], <Self as Predicate<C>>::error())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &C) -> Violation {
+        match value.elements().position(|element| P::test(element)) {
+            Some(index) => Violation::element(
+                value.position(index),
+                Violation::predicate(PredicateKind::NoneOf, &[], negated(&P::error())),
+            ),
+            None => <Self as Predicate<C>>::violation(),
+        }
+    }
+
+    unsafe fn optimize(value: &C) {
+        if !<Self as Predicate<C>>::RUNTIME_STATE {
+            core::hint::assert_unchecked(<Self as Predicate<C>>::test(value));
+        }
+    }
+}
+
+/// The number of elements that satisfy the predicate `P` satisfies the predicate `B`.
+///
+/// `B` is usually one of the [unsigned](crate::boundable::unsigned) bounds, such as
+/// `Count<IsUppercase, ClosedInterval<1, 3>>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Count<P, B>(PhantomData<P>, PhantomData<B>);
+
+impl<C: Elements, P: Predicate<C::Element>, B: Predicate<usize>> Predicate<C> for Count<P, B> {
+    const RUNTIME_STATE: bool = P::RUNTIME_STATE || B::RUNTIME_STATE;
+
+    fn test(value: &C) -> bool {
+        B::test(&value.elements().filter(|element| P::test(element)).count())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        format!(
+            "number of elements satisfying \"{}\" {}",
+            P::error(),
+            B::error()
+        )
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        "number of elements satisfying the predicate is out of bounds"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(PredicateKind::Count, &[], <Self as Predicate<C>>::error())
+    }
+
+    unsafe fn optimize(value: &C) {
+        if !<Self as Predicate<C>>::RUNTIME_STATE {
+            core::hint::assert_unchecked(<Self as Predicate<C>>::test(value));
+        }
+    }
+}
+
+/// The index of the first element that fails to relate to its predecessor by `related`.
+fn first_unrelated<C: Elements>(
+    value: &C,
+    related: impl Fn(&C::Element, &C::Element) -> bool,
+) -> Option<usize> {
+    value
+        .elements()
+        .zip(value.elements().skip(1))
+        .position(|(previous, element)| !related(previous, element))
+        .map(|index| index + 1)
+}
+
+/// The index of the first element that is equal to a preceding element.
+fn first_duplicate<C: Elements>(value: &C) -> Option<usize>
+where
+    C::Element: PartialEq,
+{
+    value.elements().enumerate().position(|(index, element)| {
+        value
+            .elements()
+            .take(index)
+            .any(|previous| previous == element)
+    })
+}
+
+macro_rules! sorted_predicate {
+    ($name:ident, $op:tt, $message:literal, $element_message:literal) => {
+        impl<C: Elements> Predicate<C> for $name
+        where
+            C::Element: PartialOrd,
+        {
+            fn test(value: &C) -> bool {
+                first_unrelated(value, |previous, element| previous $op element).is_none()
+            }
+
+            #[cfg(feature = "alloc")]
+            fn error() -> ErrorMessage {
+                ErrorMessage::from($message)
+            }
+
+            #[cfg(not(feature = "alloc"))]
+            fn error() -> ErrorMessage {
+                $message
+            }
+
+            #[cfg(feature = "alloc")]
+            fn violation() -> Violation {
+                Violation::predicate(PredicateKind::$name, &[], <Self as Predicate<C>>::error())
+            }
+
+            #[cfg(feature = "alloc")]
+            fn explain(value: &C) -> Violation {
+                match first_unrelated(value, |previous, element| previous $op element) {
+                    Some(index) => Violation::element(
+                        value.position(index),
+                        Violation::predicate(
+                            PredicateKind::$name,
+                            &[],
+                            ErrorMessage::from($element_message),
+                        ),
+                    ),
+                    None => <Self as Predicate<C>>::violation(),
+                }
+            }
+
+            unsafe fn optimize(value: &C) {
+                core::hint::assert_unchecked(<Self as Predicate<C>>::test(value));
+            }
+        }
+    };
+}
+
+/// The elements are in non-decreasing order.
+///
+/// Errors name the first element that is less than its predecessor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Sorted;
+
+sorted_predicate!(
+    Sorted,
+    <=,
+    "must be sorted in non-decreasing order",
+    "must be greater than or equal to the preceding element"
+);
+
+/// The elements are in strictly increasing order.
+///
+/// Errors name the first element that is not greater than its predecessor.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct StrictlySorted;
+
+sorted_predicate!(
+    StrictlySorted,
+    <,
+    "must be sorted in increasing order",
+    "must be greater than the preceding element"
+);
+
+/// No two elements are equal.
+///
+/// Elements are compared pairwise, so testing takes time quadratic in the number of elements.
+/// Errors name the first element that is equal to a preceding element.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Unique;
+
+impl<C: Elements> Predicate<C> for Unique
+where
+    C::Element: PartialEq,
+{
+    fn test(value: &C) -> bool {
+        first_duplicate(value).is_none()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        ErrorMessage::from("must not contain duplicate elements")
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        "must not contain duplicate elements"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(PredicateKind::Unique, &[], <Self as Predicate<C>>::error())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &C) -> Violation {
+        match first_duplicate(value) {
+            Some(index) => Violation::element(
+                value.position(index),
+                Violation::predicate(
+                    PredicateKind::Unique,
+                    &[],
+                    ErrorMessage::from("must not duplicate a preceding element"),
+                ),
+            ),
+            None => <Self as Predicate<C>>::violation(),
+        }
+    }
+
+    unsafe fn optimize(value: &C) {
+        core::hint::assert_unchecked(<Self as Predicate<C>>::test(value));
+    }
+}
+
+impl<P: Stateless> Stateless for ForAll<P> {}
+
+impl<P: Stateless> Stateless for Exists<P> {}
+
+impl<P: Stateless> Stateless for NoneOf<P> {}
+
+impl<P: Stateless, B: Stateless> Stateless for Count<P, B> {}
+
+impl Stateless for Sorted {}
+
+impl Stateless for StrictlySorted {}
+
+impl Stateless for Unique {}
+
+#[cfg(test)]
+mod tests {
+    #[cfg(feature = "alloc")]
+    use crate::boolean::And;
+    use crate::{boundable::unsigned::*, character::IsLowercase, collection::*, *};
+    #[cfg(feature = "alloc")]
+    use alloc::{collections::BTreeMap, format, vec, vec::Vec};
+
+    #[test]
+    fn test_quantifiers() {
+        type Small = Refinement<[u8; 3], ForAll<LessThan<10>>>;
+        assert!(Small::refine([1, 2, 9]).is_ok());
+        assert!(Small::refine([1, 10, 2]).is_err());
+
+        type AnyLower = Refinement<&'static [char], Exists<IsLowercase>>;
+        assert!(AnyLower::refine(&['A', 'b']).is_ok());
+        assert!(AnyLower::refine(&['A', 'B']).is_err());
+        assert!(AnyLower::refine(&[]).is_err());
+
+        type NoLower = Refinement<[char; 2], NoneOf<IsLowercase>>;
+        assert!(NoLower::refine(['A', 'B']).is_ok());
+        assert!(NoLower::refine(['A', 'b']).is_err());
+
+        type FewLower = Refinement<[char; 3], Count<IsLowercase, ClosedInterval<1, 2>>>;
+        assert!(FewLower::refine(['a', 'B', 'c']).is_ok());
+        assert!(FewLower::refine(['A', 'B', 'C']).is_err());
+        assert!(FewLower::refine(['a', 'b', 'c']).is_err());
+    }
+
+    #[test]
+    fn test_ordering() {
+        assert!(Refinement::<[u8; 3], Sorted>::refine([1, 1, 2]).is_ok());
+        assert!(Refinement::<[u8; 3], Sorted>::refine([1, 2, 1]).is_err());
+        assert!(Refinement::<[f64; 2], StrictlySorted>::refine([1.0, 1.5]).is_ok());
+        assert!(Refinement::<[u8; 3], StrictlySorted>::refine([1, 1, 2]).is_err());
+        assert!(Refinement::<[u8; 0], StrictlySorted>::refine([]).is_ok());
+
+        assert!(Refinement::<[u8; 3], Unique>::refine([3, 1, 2]).is_ok());
+        assert!(Refinement::<[u8; 3], Unique>::refine([3, 1, 3]).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_element_error() {
+        let err = Refinement::<Vec<u8>, ForAll<LessThan<10>>>::refine(vec![1, 10, 20]).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: element 1 must be less than 10"
+        );
+        let Violation::Element(position, _) = err.violation() else {
+            panic!("expected element violation");
+        };
+        assert_eq!(position, &Position::Index(1));
+        assert_eq!(err.violation().leaves()[0].kind(), PredicateKind::LessThan);
+
+        let err = Refinement::<Vec<u8>, Sorted>::refine(vec![1, 2, 0]).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: element 2 must be greater than or equal to the preceding element"
+        );
+
+        let err = Refinement::<Vec<char>, NoneOf<IsLowercase>>::refine(vec!['A', 'b']).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: element 1 must not be a lowercase character"
+        );
+        assert_eq!(
+            <NoneOf<IsLowercase> as Predicate<Vec<char>>>::error(),
+            "every element must not be a lowercase character"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_map_values() {
+        type Scores = Refinement<BTreeMap<&'static str, u8>, And<ForAll<LessThan<100>>, Unique>>;
+        assert!(Scores::refine([("alice", 90), ("bob", 80)].into()).is_ok());
+
+        let err = Scores::refine([("alice", 90), ("bob", 100)].into()).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: value at \"bob\" must be less than 100"
+        );
+
+        let err = Scores::refine([("alice", 90), ("bob", 90)].into()).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: value at \"bob\" must not duplicate a preceding element"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_hash_collections() {
+        use std::collections::{HashMap, HashSet};
+
+        type Lower = Refinement<HashSet<char>, ForAll<IsLowercase>>;
+        assert!(Lower::refine(['a', 'b'].into()).is_ok());
+        assert!(Lower::refine(['a', 'B'].into()).is_err());
+
+        let err = Refinement::<HashMap<u8, u8>, ForAll<LessThan<10>>>::refine([(7, 10)].into())
+            .unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: value at 7 must be less than 10"
+        );
+    }
+}
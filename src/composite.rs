@@ -0,0 +1,384 @@
+//! Refinement of composite types.
+//!
+//! This module provides predicates over tuples as well as projection combinators that allow
+//! existing predicates to be applied to individual fields or tuple elements. Together, these
+//! allow for invariants that span multiple fields (such as "start <= end") to be expressed
+//! at the type level.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boolean::And, boundable::unsigned::LessThan, composite::*, projection};
+//!
+//! type Range = Refinement<(u32, u32), Ordered>;
+//!
+//! assert!(Range::refine((1, 2)).is_ok());
+//! assert!(Range::refine((2, 1)).is_err());
+//!
+//! #[derive(Debug)]
+//! struct Config {
+//!   min_len: usize,
+//!   max_len: usize,
+//! }
+//!
+//! projection!(MinLen, Config => min_len: usize);
+//! projection!(MaxLen, Config => max_len: usize);
+//!
+//! type ValidConfig = Refinement<Config, And<Zip<MinLen, MaxLen, Ordered>, Project<MaxLen, LessThan<100>>>>;
+//!
+//! assert!(ValidConfig::refine(Config { min_len: 1, max_len: 10 }).is_ok());
+//! assert_eq!(
+//!     ValidConfig::refine(Config { min_len: 10, max_len: 1 }).unwrap_err().to_string(),
+//!     "refinement violated: (min_len, max_len) must be in non-decreasing order"
+//! );
+//! assert_eq!(
+//!     ValidConfig::refine(Config { min_len: 1, max_len: 100 }).unwrap_err().to_string(),
+//!     "refinement violated: max_len must be less than 100"
+//! );
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{format, vec};
+use core::marker::PhantomData;
+
+use crate::{ErrorMessage, Predicate, Stateless};
+#[cfg(feature = "alloc")]
+use crate::{PredicateKind, Violation};
+
+/// A component of a composite type `T`, such as a struct field or tuple element.
+///
+/// Projections are most easily created for struct fields using the [projection](crate::projection)
+/// macro; [Element] projects the elements of tuples.
+pub trait Projection<T> {
+    /// The type of the projected component.
+    type Target;
+
+    /// The name of the projected component, used in error messages.
+    const NAME: &'static str;
+
+    /// Retrieves the projected component from a value.
+    fn project(value: &T) -> &Self::Target;
+}
+
+/// Creates a [Projection] of a struct field.
+///
+/// `$name` is the name of a type to create to represent the projection.
+/// `$type` is the struct containing the field, `$field` is the name of the field, and `$target` is its type.
+///
+/// # Example
+///
+/// ```
+/// use refined::{projection, composite::Projection};
+///
+/// struct Point {
+///   x: i32,
+///   y: i32,
+/// }
+///
+/// projection!(X, Point => x: i32);
+/// assert_eq!(<X as Projection<Point>>::NAME, "x");
+/// assert_eq!(*X::project(&Point { x: 1, y: 2 }), 1);
+/// ```
+#[macro_export]
+macro_rules! projection {
+    ($name:ident, $type:ty => $field:ident : $target:ty) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name;
+
+        impl $crate::composite::Projection<$type> for $name {
+            type Target = $target;
+
+            const NAME: &'static str = stringify!($field);
+
+            fn project(value: &$type) -> &$target {
+                &value.$field
+            }
+        }
+    };
+}
+
+/// Projects the `N`th element of a tuple.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Element<const N: usize>;
+
+macro_rules! element_projection {
+    ($idx:tt => $target:ident; $($ty:ident),+) => {
+        impl<$($ty),+> Projection<($($ty,)+)> for Element<$idx> {
+            type Target = $target;
+
+            const NAME: &'static str = concat!("element ", stringify!($idx));
+
+            fn project(value: &($($ty,)+)) -> &$target {
+                &value.$idx
+            }
+        }
+    };
+}
+
+element_projection!(0 => A; A);
+element_projection!(0 => A; A, B);
+element_projection!(1 => B; A, B);
+element_projection!(0 => A; A, B, C);
+element_projection!(1 => B; A, B, C);
+element_projection!(2 => C; A, B, C);
+element_projection!(0 => A; A, B, C, D);
+element_projection!(1 => B; A, B, C, D);
+element_projection!(2 => C; A, B, C, D);
+element_projection!(3 => D; A, B, C, D);
+
+/// Applies the predicate `P` to the component of a value projected by `F`.
+///
+/// Errors are prefixed with the [name](Projection::NAME) of the projected component, and reported
+/// as a [component](Violation::Component) violation.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Project<F, P>(PhantomData<F>, PhantomData<P>);
+
+impl<T, F: Projection<T>, P: Predicate<F::Target>> Predicate<T> for Project<F, P> {
+    const RUNTIME_STATE: bool = P::RUNTIME_STATE;
+
+    fn test(value: &T) -> bool {
+        P::test(F::project(value))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        format!("{} {}", F::NAME, P::error())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        P::error()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::component(vec![F::NAME], P::violation())
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Violation {
+        Violation::component(vec![F::NAME], P::explain(F::project(value)))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(value: &T) -> Violation {
+        Violation::component(vec![F::NAME], P::explain_satisfied(F::project(value)))
+    }
+
+    unsafe fn optimize(value: &T) {
+        P::optimize(F::project(value));
+    }
+}
+
+/// Applies the predicate `P` to the pair of components of a value projected by `F` and `G`.
+///
+/// This allows for predicates that relate multiple fields of a struct to one another,
+/// such as [Ordered]. Errors are reported as a [component](Violation::Component) violation of
+/// both projected components.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Zip<F, G, P>(PhantomData<F>, PhantomData<G>, PhantomData<P>);
+
+impl<T, F: Projection<T>, G: Projection<T>, P> Predicate<T> for Zip<F, G, P>
+where
+    P: for<'a> Predicate<(&'a F::Target, &'a G::Target)>,
+{
+    const RUNTIME_STATE: bool = pair_runtime_state::<F::Target, G::Target, P>();
+
+    fn test(value: &T) -> bool {
+        P::test(&(F::project(value), G::project(value)))
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        format!("({}, {}) {}", F::NAME, G::NAME, P::error())
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        P::error()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::component(
+            vec![F::NAME, G::NAME],
+            pair_violation::<F::Target, G::Target, P>(),
+        )
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(value: &T) -> Violation {
+        Violation::component(
+            vec![F::NAME, G::NAME],
+            P::explain(&(F::project(value), G::project(value))),
+        )
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(value: &T) -> Violation {
+        Violation::component(
+            vec![F::NAME, G::NAME],
+            P::explain_satisfied(&(F::project(value), G::project(value))),
+        )
+    }
+
+    unsafe fn optimize(value: &T) {
+        if !<Self as Predicate<T>>::RUNTIME_STATE {
+            core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
+        }
+    }
+}
+
+/// Whether a predicate over pairs of references has runtime state, which [Zip] can only name with an
+/// arbitrary lifetime.
+const fn pair_runtime_state<'a, A: 'a + ?Sized, B: 'a + ?Sized, P: Predicate<(&'a A, &'a B)>>(
+) -> bool {
+    P::RUNTIME_STATE
+}
+
+/// The violation of a predicate over pairs of references, which [Zip] can only name with an
+/// arbitrary lifetime.
+#[cfg(feature = "alloc")]
+fn pair_violation<'a, A: 'a + ?Sized, B: 'a + ?Sized, P: Predicate<(&'a A, &'a B)>>() -> Violation {
+    P::violation()
+}
+
+macro_rules! ordered_predicate {
+    ($name:ident, $op:tt, $message:literal) => {
+        impl<T: PartialOrd> Predicate<(T, T)> for $name {
+            fn test(value: &(T, T)) -> bool {
+                value.0 $op value.1
+            }
+
+            #[cfg(feature = "alloc")]
+            fn error() -> ErrorMessage {
+                ErrorMessage::from($message)
+            }
+
+            #[cfg(not(feature = "alloc"))]
+            fn error() -> ErrorMessage {
+                $message
+            }
+
+            #[cfg(feature = "alloc")]
+            fn violation() -> Violation {
+                Violation::predicate(PredicateKind::$name, &[], <Self as Predicate<(T, T)>>::error())
+            }
+
+            unsafe fn optimize(value: &(T, T)) {
+                core::hint::assert_unchecked(<Self as Predicate<(T, T)>>::test(value));
+            }
+        }
+    };
+}
+
+/// The elements of a pair are in non-decreasing order (`a <= b`).
+///
+/// Pairs of references are also supported, allowing use with [Zip].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Ordered;
+
+ordered_predicate!(Ordered, <=, "must be in non-decreasing order");
+
+/// The elements of a pair are in strictly increasing order (`a < b`).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct StrictlyOrdered;
+
+ordered_predicate!(StrictlyOrdered, <, "must be in increasing order");
+
+impl<F: Default, P: Stateless> Stateless for Project<F, P> {}
+
+impl<F: Default, G: Default, P: Stateless> Stateless for Zip<F, G, P> {}
+
+impl Stateless for Ordered {}
+
+impl Stateless for StrictlyOrdered {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boolean::*, boundable::unsigned::*, composite::*, *};
+    #[cfg(feature = "alloc")]
+    use alloc::{format, vec};
+
+    #[derive(Debug)]
+    struct Window {
+        start: u32,
+        end: u32,
+    }
+
+    projection!(Start, Window => start: u32);
+    projection!(End, Window => end: u32);
+
+    type ValidWindow =
+        Refinement<Window, And<Zip<Start, End, StrictlyOrdered>, Project<End, LessThan<10>>>>;
+
+    #[test]
+    fn test_ordered() {
+        assert!(Refinement::<(u32, u32), Ordered>::refine((1, 1)).is_ok());
+        assert!(Refinement::<(u32, u32), Ordered>::refine((2, 1)).is_err());
+        assert!(Refinement::<(f64, f64), StrictlyOrdered>::refine((1.0, 1.5)).is_ok());
+        assert!(Refinement::<(u32, u32), StrictlyOrdered>::refine((1, 1)).is_err());
+    }
+
+    #[test]
+    fn test_project_element() {
+        type Test = Refinement<(u8, u8, u8), Project<Element<2>, LessThan<5>>>;
+        assert!(Test::refine((10, 10, 4)).is_ok());
+        assert!(Test::refine((0, 0, 5)).is_err());
+    }
+
+    #[test]
+    fn test_zip_fields() {
+        assert!(ValidWindow::refine(Window { start: 1, end: 5 }).is_ok());
+        assert!(ValidWindow::refine(Window { start: 5, end: 5 }).is_err());
+        assert!(ValidWindow::refine(Window { start: 1, end: 10 }).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_project_error() {
+        let err = ValidWindow::refine(Window { start: 1, end: 10 }).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: end must be less than 10"
+        );
+        assert_eq!(err.violation().name(), None);
+        assert_eq!(
+            err.violation(),
+            &Violation::component(vec!["end"], <LessThan<10> as Predicate<u32>>::violation())
+        );
+
+        let err =
+            Refinement::<(u8, u8), Project<Element<0>, LessThan<5>>>::refine((5, 0)).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: element 0 must be less than 5"
+        );
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_zip_error() {
+        let err = ValidWindow::refine(Window { start: 5, end: 5 }).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: (start, end) must be in increasing order"
+        );
+        let Violation::Component(names, inner) = err.violation() else {
+            panic!("expected a component violation");
+        };
+        assert_eq!(names, &vec!["start", "end"]);
+        assert_eq!(inner.leaves()[0].kind(), PredicateKind::StrictlyOrdered);
+        assert_eq!(
+            <Zip<Start, End, StrictlyOrdered> as Predicate<Window>>::violation(),
+            Violation::component(
+                vec!["start", "end"],
+                Violation::predicate(
+                    PredicateKind::StrictlyOrdered,
+                    &[],
+                    "must be in increasing order".into()
+                )
+            )
+        );
+    }
+}
@@ -0,0 +1,169 @@
+//! Support machinery for the [Refined](macro@crate::Refined) and [RefineFields](macro@crate::RefineFields)
+//! derive macros.
+//!
+//! Nothing in this module is part of the public API; it exists only so that code generated by
+//! `refined-derive` can respect the features enabled for `refined` itself.
+
+#[doc(hidden)]
+pub mod __private {
+    #[cfg(feature = "serde")]
+    pub use serde;
+}
+
+#[cfg(feature = "serde")]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __refined_derive_serde {
+    ($name:ident, $inner:ty) => {
+        impl $crate::__private::serde::Serialize for $name {
+            fn serialize<S>(&self, serializer: S) -> ::core::result::Result<S::Ok, S::Error>
+            where
+                S: $crate::__private::serde::Serializer,
+            {
+                $crate::__private::serde::Serialize::serialize(
+                    ::core::ops::Deref::deref(self),
+                    serializer,
+                )
+            }
+        }
+
+        impl<'de> $crate::__private::serde::Deserialize<'de> for $name {
+            fn deserialize<D>(deserializer: D) -> ::core::result::Result<Self, D::Error>
+            where
+                D: $crate::__private::serde::Deserializer<'de>,
+            {
+                let refined = <$crate::Refined<$inner> as $crate::__private::serde::Deserialize>::deserialize(deserializer)?;
+                <Self as ::core::convert::TryFrom<$crate::Refined<$inner>>>::try_from(refined)
+                    .map_err(<D::Error as $crate::__private::serde::de::Error>::custom)
+            }
+        }
+    };
+}
+
+#[cfg(not(feature = "serde"))]
+#[doc(hidden)]
+#[macro_export]
+macro_rules! __refined_derive_serde {
+    ($name:ident, $inner:ty) => {};
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::unsigned::ClosedInterval, string::Trimmed, *};
+    use alloc::{
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+
+    #[derive(Debug, Refined)]
+    #[refined(predicate = ClosedInterval<1, 10>)]
+    struct Rating(u8);
+
+    #[derive(Debug, Refined)]
+    #[refined(predicate = Trimmed, name = "user name", display = false)]
+    struct UserName {
+        value: String,
+    }
+
+    #[derive(Debug, RefineFields)]
+    #[refined(raw_derive(Debug, Clone))]
+    struct Address {
+        city: Refinement<String, Trimmed>,
+    }
+
+    #[derive(Debug, RefineFields)]
+    #[refined(raw = UserInput)]
+    struct User {
+        name: UserName,
+        rating: Rating,
+        #[refined(nested)]
+        address: Address,
+        #[refined(skip)]
+        note: String,
+    }
+
+    #[test]
+    fn test_derive_refine() {
+        let rating = Rating::refine(5).unwrap();
+        assert_eq!(*rating, 5);
+        assert_eq!(format!("{}", rating), "5");
+        assert_eq!(
+            format!("{}", Rating::refine(11).unwrap_err()),
+            "refinement violated: Rating must be less than or equal to 10"
+        );
+    }
+
+    #[test]
+    fn test_derive_modify() {
+        let rating = Rating::refine(5).unwrap();
+        let rating = rating.modify(|r| r + 5).unwrap();
+        assert_eq!(rating.take(), 10);
+        let rejected = Rating::refine(10)
+            .unwrap()
+            .try_modify(|r| r + 1)
+            .unwrap_err();
+        assert_eq!(rejected.into_value(), 11);
+    }
+
+    #[test]
+    fn test_derive_named_field() {
+        let name = UserName::refine("jordan".to_string()).unwrap();
+        assert_eq!(name.as_str(), "jordan");
+        let err = UserName::refine(" jordan".to_string()).unwrap_err();
+        assert_eq!(err.name(), Some("user name"));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn test_derive_serde() {
+        let rating: Rating = serde_json::from_str("7").unwrap();
+        assert_eq!(serde_json::to_string(&rating).unwrap(), "7");
+        let err = serde_json::from_str::<Rating>("0").unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: Rating must be greater than or equal to 1"
+        );
+    }
+
+    #[test]
+    fn test_derive_refine_fields_success() {
+        let user = User::refine_fields(UserInput {
+            name: "jordan".to_string(),
+            rating: 5,
+            address: RawAddress {
+                city: "Boston".to_string(),
+            },
+            note: "note".to_string(),
+        })
+        .unwrap();
+        assert_eq!(user.name.as_str(), "jordan");
+        assert_eq!(*user.rating, 5);
+        assert_eq!(*user.address.city, "Boston");
+        assert_eq!(user.note, "note");
+    }
+
+    #[test]
+    fn test_derive_refine_fields_failure() {
+        let errors = User::try_from(UserInput {
+            name: " jordan".to_string(),
+            rating: 5,
+            address: RawAddress {
+                city: "Boston ".to_string(),
+            },
+            note: String::new(),
+        })
+        .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert_eq!(errors.get("name").unwrap().name(), Some("user name"));
+        assert!(errors.get("rating").is_none());
+        assert_eq!(
+            errors.iter().map(|(path, _)| path).collect::<Vec<_>>(),
+            ["name", "address.city"]
+        );
+        assert_eq!(
+            errors.to_string(),
+            "refinement violated: name must not start or end with whitespace; address.city must not start or end with whitespace"
+        );
+    }
+}
@@ -0,0 +1,634 @@
+#[cfg(feature = "alloc")]
+use alloc::{boxed::Box, vec::Vec};
+#[cfg(feature = "alloc")]
+use core::fmt::Display;
+
+#[cfg(feature = "alloc")]
+use thiserror::Error;
+
+use crate::{ErrorMessage, Predicate, StatefulPredicate};
+
+/// An [Error] that can result from failed refinement.
+///
+/// When the `alloc` feature is enabled, the error carries a structured [Violation] describing
+/// exactly which predicate (or combination of predicates) was not satisfied. The [Display]
+/// implementation is a rendering of this structure.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::{ClosedInterval, Equals}, boolean::Or, Bound, PredicateKind, Violation};
+///
+/// type Rating = Refinement<u8, Or<Equals<0>, ClosedInterval<5, 10>>>;
+///
+/// let err = Rating::refine(11).unwrap_err();
+/// assert!(matches!(err.violation(), Violation::Or(_, _)));
+///
+/// let leaves = err.violation().leaves();
+/// assert_eq!(leaves[0].kind(), PredicateKind::Equals);
+/// assert_eq!(leaves[0].bounds(), &[Bound::Unsigned(0)]);
+/// assert_eq!(leaves[1].kind(), PredicateKind::LessThanEqual);
+/// assert_eq!(leaves[1].bounds(), &[Bound::Unsigned(10)]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "alloc", derive(Error))]
+pub struct RefinementError(
+    #[cfg(feature = "alloc")] Violation,
+    #[cfg(not(feature = "alloc"))] ErrorMessage,
+);
+
+impl RefinementError {
+    /// Creates the error that results from `value` failing to satisfy the predicate `P`.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub(crate) fn of<T, P: Predicate<T>>(value: &T) -> Self {
+        #[cfg(feature = "alloc")]
+        {
+            Self(P::explain(value))
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            Self(P::error())
+        }
+    }
+
+    /// Creates the error that results from `value` failing to satisfy the stateful `predicate`.
+    #[cfg_attr(not(feature = "alloc"), allow(unused_variables))]
+    pub(crate) fn of_stateful<T, P: StatefulPredicate<T>>(predicate: &P, value: &T) -> Self {
+        #[cfg(feature = "alloc")]
+        {
+            Self(predicate.explain(value))
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            Self(predicate.error())
+        }
+    }
+
+    /// Creates the error that results from an arithmetic operation whose result cannot be represented.
+    pub(crate) fn arithmetic(message: &'static str) -> Self {
+        #[cfg(feature = "alloc")]
+        {
+            Self(Violation::custom(ErrorMessage::from(message)))
+        }
+        #[cfg(not(feature = "alloc"))]
+        {
+            Self(message)
+        }
+    }
+
+    /// The structured description of the violated predicate.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn violation(&self) -> &Violation {
+        &self.0
+    }
+
+    /// Consumes the error, returning the structured description of the violated predicate.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn into_violation(self) -> Violation {
+        self.0
+    }
+
+    /// The name of the refinement that was violated, if it was [named](crate::Named).
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn name(&self) -> Option<&'static str> {
+        self.0.name()
+    }
+
+    /// Attaches a name to the error, as is done by [Named](crate::Named) refinements.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn named(self, name: &'static str) -> Self {
+        Self(Violation::named(name, self.0))
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl From<Violation> for RefinementError {
+    fn from(value: Violation) -> Self {
+        Self(value)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl Display for RefinementError {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "refinement violated: {}", self.0)
+    }
+}
+
+/// A [RefinementError] that also carries the value that was rejected.
+///
+/// This error is returned by the `try_` family of functions on [RefinementOps](crate::RefinementOps),
+/// allowing the rejected value to be recovered without cloning it beforehand. When the rejected value
+/// implements [Debug], it is included in the [Display] rendering of the error.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan};
+///
+/// type Small = Refinement<u8, LessThan<100>>;
+///
+/// let err = Small::try_refine(123).unwrap_err();
+/// assert_eq!(err.to_string(), "refinement violated: must be less than 100 (got 123)");
+/// assert_eq!(err.into_value(), 123);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[cfg_attr(feature = "alloc", derive(Error))]
+pub struct RejectedValue<T> {
+    value: T,
+    error: RefinementError,
+}
+
+impl<T> RejectedValue<T> {
+    /// Pairs a rejected value with the error that describes why it was rejected.
+    ///
+    /// This is primarily useful when implementing [RefinementOps::try_refine](crate::RefinementOps::try_refine)
+    /// for your own refinement types.
+    pub fn new(value: T, error: RefinementError) -> Self {
+        Self { value, error }
+    }
+
+    /// The value that was rejected.
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    /// Consumes the error, returning the value that was rejected.
+    pub fn into_value(self) -> T {
+        self.value
+    }
+
+    /// The error describing why the value was rejected.
+    pub fn error(&self) -> &RefinementError {
+        &self.error
+    }
+
+    /// Consumes the error, returning the error describing why the value was rejected.
+    pub fn into_error(self) -> RefinementError {
+        self.error
+    }
+
+    /// Consumes the error, returning both the rejected value and the reason for its rejection.
+    pub fn into_parts(self) -> (T, RefinementError) {
+        (self.value, self.error)
+    }
+
+    /// Attaches a name to the error, as is done by [Named](crate::Named) refinements.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    pub fn named(self, name: &'static str) -> Self {
+        Self {
+            value: self.value,
+            error: self.error.named(name),
+        }
+    }
+}
+
+impl<T> From<RejectedValue<T>> for RefinementError {
+    fn from(value: RejectedValue<T>) -> Self {
+        value.error
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+impl<T: core::fmt::Debug> Display for RejectedValue<T> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{} (got {:?})", self.error, self.value)
+    }
+}
+
+/// The kind of [Predicate](crate::Predicate) that was violated.
+///
+/// Predicates that are not provided by `refined` are reported as [PredicateKind::Custom].
+#[non_exhaustive]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PredicateKind {
+    /// [boolean::True](crate::boolean::True)
+    True,
+    /// [boolean::False](crate::boolean::False)
+    False,
+    /// `GreaterThan` (or `DynGreaterThan`) from [boundable::signed](crate::boundable::signed), [boundable::unsigned](crate::boundable::unsigned), or [float](crate::float)
+    GreaterThan,
+    /// `GreaterThanEqual` (or `DynGreaterThanEqual`) from [boundable::signed](crate::boundable::signed), [boundable::unsigned](crate::boundable::unsigned), or [float](crate::float)
+    GreaterThanEqual,
+    /// `LessThan` (or `DynLessThan`) from [boundable::signed](crate::boundable::signed), [boundable::unsigned](crate::boundable::unsigned), or [float](crate::float)
+    LessThan,
+    /// `LessThanEqual` (or `DynLessThanEqual`) from [boundable::signed](crate::boundable::signed), [boundable::unsigned](crate::boundable::unsigned), or [float](crate::float)
+    LessThanEqual,
+    /// `Modulo` from [boundable::signed](crate::boundable::signed) or [boundable::unsigned](crate::boundable::unsigned)
+    Modulo,
+    /// `Equals` from [boundable::signed](crate::boundable::signed) or [boundable::unsigned](crate::boundable::unsigned)
+    Equals,
+    /// [character::IsControl](crate::character::IsControl)
+    IsControl,
+    /// [character::IsDigit](crate::character::IsDigit)
+    IsDigit,
+    /// [character::IsLowercase](crate::character::IsLowercase)
+    IsLowercase,
+    /// [character::IsUppercase](crate::character::IsUppercase)
+    IsUppercase,
+    /// [character::IsNumeric](crate::character::IsNumeric)
+    IsNumeric,
+    /// [character::IsWhitespace](crate::character::IsWhitespace)
+    IsWhitespace,
+    /// [character::IsHexDigit](crate::character::IsHexDigit)
+    IsHexDigit,
+    /// `string::StartsWith`
+    StartsWith,
+    /// `string::EndsWith`
+    EndsWith,
+    /// `string::Contains`
+    Contains,
+    /// `string::Trimmed`
+    Trimmed,
+    /// `string::Regex` or `string::DynRegex`
+    Regex,
+    /// [composite::Ordered](crate::composite::Ordered)
+    Ordered,
+    /// [composite::StrictlyOrdered](crate::composite::StrictlyOrdered)
+    StrictlyOrdered,
+    /// [collection::ForAll](crate::collection::ForAll)
+    ForAll,
+    /// [collection::Exists](crate::collection::Exists)
+    Exists,
+    /// [collection::NoneOf](crate::collection::NoneOf)
+    NoneOf,
+    /// [collection::Sorted](crate::collection::Sorted)
+    Sorted,
+    /// [collection::StrictlySorted](crate::collection::StrictlySorted)
+    StrictlySorted,
+    /// [collection::Unique](crate::collection::Unique)
+    Unique,
+    /// [collection::Count](crate::collection::Count)
+    Count,
+    /// [float::NotNan](crate::float::NotNan)
+    NotNan,
+    /// [float::Finite](crate::float::Finite)
+    Finite,
+    /// Any predicate not provided by `refined`.
+    Custom,
+}
+
+/// A value that parameterizes a violated predicate, such as the `MAX` of a `LessThan<MAX>`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Bound {
+    /// An unsigned bound, as used by [boundable::unsigned](crate::boundable::unsigned).
+    Unsigned(u128),
+    /// A signed bound, as used by [boundable::signed](crate::boundable::signed).
+    Signed(i128),
+    /// A string bound, as used by the [TypeString](crate::TypeString)-based string predicates.
+    Str(&'static str),
+    /// A rational bound `numerator / denominator`, as used by [float](crate::float).
+    Ratio(isize, usize),
+}
+
+/// A single violated predicate; the leaves of a [Violation] tree.
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct PredicateViolation {
+    kind: PredicateKind,
+    bounds: Vec<Bound>,
+    message: ErrorMessage,
+}
+
+#[cfg(feature = "alloc")]
+impl PredicateViolation {
+    /// The kind of predicate that was violated.
+    pub fn kind(&self) -> PredicateKind {
+        self.kind
+    }
+
+    /// The bounds of the violated predicate, in the order that they appear in its type.
+    pub fn bounds(&self) -> &[Bound] {
+        &self.bounds
+    }
+
+    /// The human-readable message describing the violated predicate.
+    pub fn message(&self) -> &str {
+        &self.message
+    }
+}
+
+/// The position of an element within a collection, as reported by [Violation::Element].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Position {
+    /// The index of an element in iteration order.
+    Index(usize),
+    /// The [Debug](core::fmt::Debug) rendering of the key of a map entry.
+    Key(ErrorMessage),
+}
+
+#[cfg(feature = "alloc")]
+impl Display for Position {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Index(index) => write!(f, "element {}", index),
+            Self::Key(key) => write!(f, "value at {}", key),
+        }
+    }
+}
+
+/// A structured description of a predicate that did not hold.
+///
+/// Violations mirror the structure of the predicates that produce them: leaf predicates
+/// produce [Violation::Predicate], the [boolean](crate::boolean) combinators produce
+/// the corresponding combinator variants, and [Named](crate::Named) refinements attach
+/// their name via [Violation::Named]. The [collection](crate::collection) predicates report the
+/// first offending element of a collection via [Violation::Element], and the
+/// [composite](crate::composite) combinators report the projected components via
+/// [Violation::Component].
+#[cfg(feature = "alloc")]
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[derive(Clone, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Violation {
+    /// A single predicate.
+    Predicate(PredicateViolation),
+    /// Logical conjunction of two violations.
+    And(Box<Violation>, Box<Violation>),
+    /// Logical disjunction of two violations.
+    Or(Box<Violation>, Box<Violation>),
+    /// Logical exclusive disjunction of two violations.
+    Xor(Box<Violation>, Box<Violation>),
+    /// Logical negation of a violation.
+    Not(Box<Violation>),
+    /// Conjunction of any number of violations, as reported by [All](crate::boolean::All).
+    All(Vec<Violation>),
+    /// Disjunction of any number of violations, as reported by [Any](crate::boolean::Any).
+    Any(Vec<Violation>),
+    /// A violation of a named refinement.
+    Named(&'static str, Box<Violation>),
+    /// A violation by a single element of a collection.
+    Element(Position, Box<Violation>),
+    /// A violation by the [projected](crate::composite::Projection) components of a composite
+    /// value, such as struct fields or tuple elements.
+    Component(Vec<&'static str>, Box<Violation>),
+}
+
+#[cfg(feature = "alloc")]
+impl Violation {
+    /// Creates a violation of a single predicate.
+    pub fn predicate(kind: PredicateKind, bounds: &[Bound], message: ErrorMessage) -> Self {
+        Self::Predicate(PredicateViolation {
+            kind,
+            bounds: bounds.to_vec(),
+            message,
+        })
+    }
+
+    /// Creates a violation of a [custom](PredicateKind::Custom) predicate with no bounds.
+    pub fn custom(message: ErrorMessage) -> Self {
+        Self::predicate(PredicateKind::Custom, &[], message)
+    }
+
+    /// Creates a conjunction of two violations.
+    pub fn conjunction(a: Violation, b: Violation) -> Self {
+        Self::And(Box::new(a), Box::new(b))
+    }
+
+    /// Creates a disjunction of two violations.
+    pub fn disjunction(a: Violation, b: Violation) -> Self {
+        Self::Or(Box::new(a), Box::new(b))
+    }
+
+    /// Creates an exclusive disjunction of two violations.
+    pub fn exclusive_disjunction(a: Violation, b: Violation) -> Self {
+        Self::Xor(Box::new(a), Box::new(b))
+    }
+
+    /// Creates a negation of a violation.
+    pub fn negation(inner: Violation) -> Self {
+        Self::Not(Box::new(inner))
+    }
+
+    /// Creates a conjunction of any number of violations.
+    ///
+    /// A single violation is returned as-is rather than wrapped in [Violation::All].
+    pub fn all(mut violations: Vec<Violation>) -> Self {
+        if violations.len() == 1 {
+            violations.remove(0)
+        } else {
+            Self::All(violations)
+        }
+    }
+
+    /// Creates a disjunction of any number of violations.
+    ///
+    /// A single violation is returned as-is rather than wrapped in [Violation::Any].
+    pub fn any(mut violations: Vec<Violation>) -> Self {
+        if violations.len() == 1 {
+            violations.remove(0)
+        } else {
+            Self::Any(violations)
+        }
+    }
+
+    /// Attaches a name to a violation.
+    pub fn named(name: &'static str, inner: Violation) -> Self {
+        Self::Named(name, Box::new(inner))
+    }
+
+    /// Attaches the position of an offending element of a collection to a violation.
+    pub fn element(position: Position, inner: Violation) -> Self {
+        Self::Element(position, Box::new(inner))
+    }
+
+    /// Attaches the names of the projected components of a composite value to a violation.
+    pub fn component(names: Vec<&'static str>, inner: Violation) -> Self {
+        Self::Component(names, Box::new(inner))
+    }
+
+    /// The outermost name attached to the violation, if any.
+    pub fn name(&self) -> Option<&'static str> {
+        match self {
+            Self::Named(name, _) => Some(name),
+            _ => None,
+        }
+    }
+
+    /// All leaf predicates of the violation, from left to right.
+    pub fn leaves(&self) -> Vec<&PredicateViolation> {
+        let mut leaves = Vec::new();
+        self.collect_leaves(&mut leaves);
+        leaves
+    }
+
+    fn collect_leaves<'a>(&'a self, leaves: &mut Vec<&'a PredicateViolation>) {
+        match self {
+            Self::Predicate(leaf) => leaves.push(leaf),
+            Self::And(a, b) | Self::Or(a, b) | Self::Xor(a, b) => {
+                a.collect_leaves(leaves);
+                b.collect_leaves(leaves);
+            }
+            Self::Not(inner)
+            | Self::Named(_, inner)
+            | Self::Element(_, inner)
+            | Self::Component(_, inner) => inner.collect_leaves(leaves),
+            Self::All(violations) | Self::Any(violations) => {
+                for violation in violations {
+                    violation.collect_leaves(leaves);
+                }
+            }
+        }
+    }
+}
+
+#[cfg(feature = "alloc")]
+fn write_list(f: &mut core::fmt::Formatter<'_>, violations: &[Violation]) -> core::fmt::Result {
+    for (i, violation) in violations.iter().enumerate() {
+        if i > 0 {
+            write!(f, "; ")?;
+        }
+        write!(f, "{}", violation)?;
+    }
+    Ok(())
+}
+
+#[cfg(feature = "alloc")]
+impl Display for Violation {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        match self {
+            Self::Predicate(leaf) => write!(f, "{}", leaf.message),
+            Self::And(a, b) => write!(f, "{} and {}", a, b),
+            Self::Or(a, b) => write!(f, "{} or {}", a, b),
+            Self::Xor(a, b) => write!(f, "{} xor {}", a, b),
+            Self::Not(inner) => write!(f, "not {}", inner),
+            Self::All(violations) => write_list(f, violations),
+            Self::Any(violations) => {
+                write!(f, "any of (")?;
+                write_list(f, violations)?;
+                write!(f, ")")
+            }
+            Self::Named(name, inner) => write!(f, "{} {}", name, inner),
+            Self::Element(position, inner) => write!(f, "{} {}", position, inner),
+            Self::Component(names, inner) => match names.as_slice() {
+                [name] => write!(f, "{} {}", name, inner),
+                names => write!(f, "({}) {}", names.join(", "), inner),
+            },
+        }
+    }
+}
+
+#[cfg(all(test, feature = "alloc"))]
+mod tests {
+    use super::*;
+    use crate::*;
+    use alloc::{format, string::String};
+
+    type_string!(TestName, "test");
+
+    #[test]
+    fn test_leaf_violation() {
+        type Test = Refinement<u8, boundable::unsigned::LessThan<5>>;
+        let err = Test::refine(5).unwrap_err();
+        let leaves = err.violation().leaves();
+        assert_eq!(leaves.len(), 1);
+        assert_eq!(leaves[0].kind(), PredicateKind::LessThan);
+        assert_eq!(leaves[0].bounds(), &[Bound::Unsigned(5)]);
+        assert_eq!(leaves[0].message(), "must be less than 5");
+        assert_eq!(err.name(), None);
+    }
+
+    #[test]
+    fn test_combinator_violation() {
+        type Test = Refinement<
+            i8,
+            boolean::Or<boundable::signed::Negative, boundable::signed::Modulo<4, 1>>,
+        >;
+        let err = Test::refine(2).unwrap_err();
+        let Violation::Or(a, b) = err.violation() else {
+            panic!("expected disjunction");
+        };
+        assert_eq!(a.leaves()[0].kind(), PredicateKind::LessThan);
+        assert_eq!(
+            b.leaves()[0].bounds(),
+            &[Bound::Signed(4), Bound::Signed(1)]
+        );
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must be less than 0 or must be divisible by 4 with a remainder of 1"
+        );
+    }
+
+    #[test]
+    fn test_named_violation() {
+        type Test = Named<TestName, Refinement<String, string::Trimmed>>;
+        let err = Test::refine(String::from(" a ")).unwrap_err();
+        assert_eq!(err.name(), Some("test"));
+        assert_eq!(err.violation().leaves()[0].kind(), PredicateKind::Trimmed);
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: test must not start or end with whitespace"
+        );
+    }
+
+    #[test]
+    fn test_rejected_value_refine() {
+        type Test = Refinement<String, string::Trimmed>;
+        let err = Test::try_refine(String::from(" a ")).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must not start or end with whitespace (got \" a \")"
+        );
+        assert_eq!(err.into_value(), " a ");
+    }
+
+    #[test]
+    fn test_rejected_value_modify() {
+        type Test = Refinement<u8, boundable::unsigned::LessThan<5>>;
+        let value = Test::refine(4).unwrap();
+        let err = value.try_modify(|x| x + 1).unwrap_err();
+        assert_eq!(err.value(), &5);
+        assert_eq!(
+            format!("{}", err.error()),
+            "refinement violated: must be less than 5"
+        );
+    }
+
+    #[test]
+    fn test_rejected_value_replace() {
+        type Test = Named<TestName, Refinement<u8, boundable::unsigned::LessThan<5>>>;
+        let value = Test::refine(4).unwrap();
+        let (rejected, err) = value.try_replace(7).unwrap_err().into_parts();
+        assert_eq!(rejected, 7);
+        assert_eq!(err.name(), Some("test"));
+        assert_eq!(
+            format!(
+                "{}",
+                RefinementError::from(Test::try_refine(9).unwrap_err())
+            ),
+            "refinement violated: test must be less than 5"
+        );
+    }
+
+    #[test]
+    fn test_custom_violation() {
+        #[derive(Debug)]
+        struct Custom;
+
+        impl Predicate<u8> for Custom {
+            fn test(_: &u8) -> bool {
+                false
+            }
+
+            fn error() -> ErrorMessage {
+                ErrorMessage::from("custom")
+            }
+        }
+
+        let err = Refinement::<u8, Custom>::refine(1).unwrap_err();
+        assert_eq!(
+            err.violation(),
+            &Violation::custom(ErrorMessage::from("custom"))
+        );
+    }
+}
@@ -0,0 +1,254 @@
+use alloc::{format, string::String, vec::Vec};
+use core::fmt::Display;
+
+use thiserror::Error;
+
+use crate::{RefinementError, RefinementOps, Violation};
+
+/// Structs whose fields can all be refined at once from a "raw" mirror of the struct.
+///
+/// Refining every field before reporting any failure allows all errors to be reported together,
+/// which is usually what is desired when validating user input such as forms or API requests.
+///
+/// This trait is most easily implemented using the [RefineFields](macro@crate::RefineFields)
+/// derive macro (requires the `derive` feature), though it can also be implemented by hand using
+/// [FieldErrors]:
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::{LessThanEqual, ClosedInterval}, FieldErrors, RefineFields};
+///
+/// type FrobnicatorName = Refinement<String, ClosedInterval<1, 10>>;
+/// type FrobnicatorSize = Refinement<u8, LessThanEqual<100>>;
+///
+/// #[derive(Debug)]
+/// struct Frobnicator {
+///   name: FrobnicatorName,
+///   size: FrobnicatorSize
+/// }
+///
+/// struct RawFrobnicator {
+///   name: String,
+///   size: u8
+/// }
+///
+/// impl RefineFields for Frobnicator {
+///   type Raw = RawFrobnicator;
+///
+///   fn refine_fields(raw: RawFrobnicator) -> Result<Self, FieldErrors> {
+///     let mut errors = FieldErrors::new();
+///     let name = errors.refine("name", raw.name);
+///     let size = errors.refine("size", raw.size);
+///     match (name, size) {
+///       (Some(name), Some(size)) => Ok(Self { name, size }),
+///       _ => Err(errors)
+///     }
+///   }
+/// }
+///
+/// let errors = Frobnicator::refine_fields(RawFrobnicator { name: "".to_string(), size: 123 }).unwrap_err();
+/// assert_eq!(errors.len(), 2);
+/// assert_eq!(errors.to_string(),
+///            "refinement violated: name must be greater than or equal to 1; size must be less than or equal to 100");
+/// ```
+pub trait RefineFields: Sized {
+    /// The unrefined mirror of the struct.
+    type Raw;
+
+    /// Attempts to refine every field of the raw struct, reporting all failures.
+    fn refine_fields(raw: Self::Raw) -> Result<Self, FieldErrors>;
+}
+
+/// A collection of [RefinementError]s keyed by the path of the field that failed refinement.
+///
+/// Nested fields are keyed by their full path, with each component separated by a `.`. When
+/// displayed, each error is prefixed by its path, which takes the place of the name of a
+/// [Named](crate::Named) refinement.
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Default, Error)]
+pub struct FieldErrors(Vec<(String, RefinementError)>);
+
+impl FieldErrors {
+    /// Creates an empty collection of errors.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Records an error for the field at `path`.
+    pub fn push(&mut self, path: impl Into<String>, error: RefinementError) {
+        self.0.push((path.into(), error));
+    }
+
+    /// Attempts to refine the field at `path`, recording the error if refinement fails.
+    pub fn refine<R: RefinementOps>(&mut self, path: &str, value: R::T) -> Option<R> {
+        match R::refine(value) {
+            Ok(refined) => Some(refined),
+            Err(err) => {
+                self.push(path, err);
+                None
+            }
+        }
+    }
+
+    /// Attempts to refine all fields of the nested struct at `path`, recording every error
+    /// under the nested path if refinement fails.
+    pub fn refine_fields<S: RefineFields>(&mut self, path: &str, raw: S::Raw) -> Option<S> {
+        match S::refine_fields(raw) {
+            Ok(refined) => Some(refined),
+            Err(errors) => {
+                for (field, err) in errors {
+                    self.push(format!("{}.{}", path, field), err);
+                }
+                None
+            }
+        }
+    }
+
+    /// The error recorded for the field at `path`, if any.
+    pub fn get(&self, path: &str) -> Option<&RefinementError> {
+        self.0.iter().find(|(p, _)| p == path).map(|(_, err)| err)
+    }
+
+    /// Iterates over all recorded errors in the order that they were recorded.
+    pub fn iter(&self) -> impl Iterator<Item = (&str, &RefinementError)> {
+        self.0.iter().map(|(p, err)| (p.as_str(), err))
+    }
+
+    /// The number of recorded errors.
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    /// Whether no errors have been recorded.
+    pub fn is_empty(&self) -> bool {
+        self.0.is_empty()
+    }
+}
+
+impl IntoIterator for FieldErrors {
+    type Item = (String, RefinementError);
+    type IntoIter = alloc::vec::IntoIter<(String, RefinementError)>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.0.into_iter()
+    }
+}
+
+impl Display for FieldErrors {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "refinement violated: ")?;
+        for (i, (path, err)) in self.0.iter().enumerate() {
+            if i > 0 {
+                write!(f, "; ")?;
+            }
+            let violation = match err.violation() {
+                Violation::Named(_, inner) => inner,
+                violation => violation,
+            };
+            write!(f, "{} {}", path, violation)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{boundable::unsigned::*, *};
+    use alloc::string::ToString;
+
+    type Size = Refinement<u8, LessThan<10>>;
+    type Name = Refinement<String, NonZero>;
+
+    #[derive(Debug)]
+    struct Inner {
+        size: Size,
+    }
+
+    struct RawInner {
+        size: u8,
+    }
+
+    impl RefineFields for Inner {
+        type Raw = RawInner;
+
+        fn refine_fields(raw: RawInner) -> Result<Self, FieldErrors> {
+            let mut errors = FieldErrors::new();
+            match errors.refine("size", raw.size) {
+                Some(size) => Ok(Self { size }),
+                None => Err(errors),
+            }
+        }
+    }
+
+    #[derive(Debug)]
+    struct Outer {
+        name: Name,
+        inner: Inner,
+    }
+
+    struct RawOuter {
+        name: String,
+        inner: RawInner,
+    }
+
+    impl RefineFields for Outer {
+        type Raw = RawOuter;
+
+        fn refine_fields(raw: RawOuter) -> Result<Self, FieldErrors> {
+            let mut errors = FieldErrors::new();
+            let name = errors.refine("name", raw.name);
+            let inner = errors.refine_fields("inner", raw.inner);
+            match (name, inner) {
+                (Some(name), Some(inner)) => Ok(Self { name, inner }),
+                _ => Err(errors),
+            }
+        }
+    }
+
+    #[test]
+    fn test_refine_fields_success() {
+        let outer = Outer::refine_fields(RawOuter {
+            name: "name".to_string(),
+            inner: RawInner { size: 5 },
+        })
+        .unwrap();
+        assert_eq!(*outer.name, "name");
+        assert_eq!(*outer.inner.size, 5);
+    }
+
+    #[test]
+    fn test_refine_fields_aggregates_errors() {
+        let errors = Outer::refine_fields(RawOuter {
+            name: "".to_string(),
+            inner: RawInner { size: 10 },
+        })
+        .unwrap_err();
+        assert_eq!(errors.len(), 2);
+        assert!(errors.get("name").is_some());
+        assert_eq!(
+            format!("{}", errors.get("inner.size").unwrap()),
+            "refinement violated: must be less than 10"
+        );
+        assert_eq!(
+            format!("{}", errors),
+            "refinement violated: name not must be equal to 0; inner.size must be less than 10"
+        );
+    }
+
+    #[test]
+    fn test_refine_fields_named() {
+        type_string!(UserName, "user name");
+
+        let mut errors = FieldErrors::new();
+        assert!(errors
+            .refine::<Named<UserName, Name>>("name", "".to_string())
+            .is_none());
+        assert_eq!(
+            format!("{}", errors.get("name").unwrap()),
+            "refinement violated: user name not must be equal to 0"
+        );
+        assert_eq!(
+            format!("{}", errors),
+            "refinement violated: name not must be equal to 0"
+        );
+    }
+}
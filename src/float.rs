@@ -0,0 +1,461 @@
+//! Refinement of floating point values.
+//!
+//! Bounds are expressed as rationals `NUM / DEN` because floating point values cannot be used as
+//! const generics; `DEN` defaults to `1`, so integral bounds can be written directly. All bounded
+//! predicates reject `NaN`.
+//!
+//! Because [Refinement] derives its comparison traits from the refined type, refined floats only
+//! implement [PartialEq] and [PartialOrd]. Wrapping a refinement whose predicate [excludes
+//! NaN](ExcludesNan) in a [TotalFloat] additionally provides [Eq], [Ord], and [Hash], allowing
+//! refined floats to be used as map keys.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, float::*};
+//!
+//! type Probability = Refinement<f64, UnitInterval>;
+//! type Half = Refinement<f32, LessThanEqual<1, 2>>;
+//!
+//! assert!(Probability::refine(0.5).is_ok());
+//! assert!(Probability::refine(1.5).is_err());
+//! assert!(Probability::refine(f64::NAN).is_err());
+//! assert_eq!(Half::refine(0.75).unwrap_err().to_string(), "refinement violated: must be less than or equal to 1/2");
+//!
+//! let mut weights = std::collections::BTreeMap::new();
+//! weights.insert(TotalFloat::<f64, UnitInterval>::refine(0.25).unwrap(), "low");
+//! ```
+
+#[cfg(feature = "alloc")]
+use alloc::{format, string::String};
+use core::{
+    cmp::Ordering,
+    fmt::Display,
+    hash::{Hash, Hasher},
+    ops::Deref,
+};
+
+use crate::{
+    boolean::*, ErrorMessage, Predicate, Refined, Refinement, RefinementError, RefinementOps,
+    RejectedValue, Stateless,
+};
+#[cfg(feature = "alloc")]
+use crate::{Bound, PredicateKind, Violation};
+
+mod private {
+    pub trait Sealed {}
+
+    impl Sealed for f32 {}
+    impl Sealed for f64 {}
+
+    pub trait SealedNan {}
+}
+
+/// Floating point types that can be refined by the predicates in this module.
+///
+/// This trait is sealed and implemented only for [f32] and [f64].
+pub trait Float: Copy + PartialOrd + private::Sealed {
+    /// Whether the value is `NaN`.
+    fn is_nan(self) -> bool;
+
+    /// Whether the value is neither infinite nor `NaN`.
+    fn is_finite(self) -> bool;
+
+    /// The nearest value to the ratio `num / den`, computed in this type so that bounds compare
+    /// exactly with literals of the same type.
+    fn from_ratio(num: isize, den: usize) -> Self;
+
+    /// The bits of the value, with negative zero normalized to positive zero.
+    fn canonical_bits(self) -> u64;
+}
+
+impl Float for f32 {
+    fn is_nan(self) -> bool {
+        f32::is_nan(self)
+    }
+
+    fn is_finite(self) -> bool {
+        f32::is_finite(self)
+    }
+
+    fn from_ratio(num: isize, den: usize) -> Self {
+        num as f32 / den as f32
+    }
+
+    fn canonical_bits(self) -> u64 {
+        (self + 0.0).to_bits() as u64
+    }
+}
+
+impl Float for f64 {
+    fn is_nan(self) -> bool {
+        f64::is_nan(self)
+    }
+
+    fn is_finite(self) -> bool {
+        f64::is_finite(self)
+    }
+
+    fn from_ratio(num: isize, den: usize) -> Self {
+        num as f64 / den as f64
+    }
+
+    fn canonical_bits(self) -> u64 {
+        (self + 0.0).to_bits()
+    }
+}
+
+/// Predicates that never hold for `NaN`.
+///
+/// This trait is sealed, as [TotalFloat] relies upon it for its total ordering. It is implemented by
+/// every predicate in this module.
+///
+/// Conjunctions exclude `NaN` if their first predicate does, and disjunctions exclude `NaN` if both
+/// of their predicates do. Conjunctions in which only the second predicate excludes `NaN` are not
+/// recognized, so place the `NaN`-excluding predicate first, as in `And<NotNan, P>`.
+pub trait ExcludesNan: private::SealedNan {}
+
+impl<A: ExcludesNan, B> private::SealedNan for And<A, B> {}
+impl<A: ExcludesNan, B> ExcludesNan for And<A, B> {}
+
+impl<A: ExcludesNan, B: ExcludesNan> private::SealedNan for Or<A, B> {}
+impl<A: ExcludesNan, B: ExcludesNan> ExcludesNan for Or<A, B> {}
+
+struct Ratio<const NUM: isize, const DEN: usize>;
+
+impl<const NUM: isize, const DEN: usize> Ratio<NUM, DEN> {
+    const CHECK: () = assert!(DEN > 0, "float bound denominators must be non-zero");
+}
+
+#[cfg(feature = "alloc")]
+fn display_ratio(num: isize, den: usize) -> String {
+    if den == 1 {
+        format!("{}", num)
+    } else {
+        format!("{}/{}", num, den)
+    }
+}
+
+/// The value is not `NaN`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct NotNan;
+
+impl private::SealedNan for NotNan {}
+impl ExcludesNan for NotNan {}
+
+impl<T: Float> Predicate<T> for NotNan {
+    fn test(value: &T) -> bool {
+        !value.is_nan()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        ErrorMessage::from("must not be NaN")
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        "must not be NaN"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(PredicateKind::NotNan, &[], <Self as Predicate<T>>::error())
+    }
+
+    unsafe fn optimize(value: &T) {
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
+    }
+}
+
+/// The value is neither infinite nor `NaN`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+pub struct Finite;
+
+impl private::SealedNan for Finite {}
+impl ExcludesNan for Finite {}
+
+impl<T: Float> Predicate<T> for Finite {
+    fn test(value: &T) -> bool {
+        value.is_finite()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn error() -> ErrorMessage {
+        ErrorMessage::from("must be finite")
+    }
+
+    #[cfg(not(feature = "alloc"))]
+    fn error() -> ErrorMessage {
+        "must be finite"
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation() -> Violation {
+        Violation::predicate(PredicateKind::Finite, &[], <Self as Predicate<T>>::error())
+    }
+
+    unsafe fn optimize(value: &T) {
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
+    }
+}
+
+macro_rules! float_bound {
+    ($name:ident, $alias:ident, $bound:ident, $op:tt, $message:literal, $static_message:literal) => {
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name<const $bound: isize, const DEN: usize = 1>;
+
+        pub type $alias<const $bound: isize, const DEN: usize = 1> = $name<$bound, DEN>;
+
+        impl<const $bound: isize, const DEN: usize> private::SealedNan for $name<$bound, DEN> {}
+        impl<const $bound: isize, const DEN: usize> ExcludesNan for $name<$bound, DEN> {}
+
+        impl<const $bound: isize, const DEN: usize> Stateless for $name<$bound, DEN> {}
+
+        impl<T: Float, const $bound: isize, const DEN: usize> Predicate<T> for $name<$bound, DEN> {
+            fn test(value: &T) -> bool {
+                let () = Ratio::<$bound, DEN>::CHECK;
+                *value $op T::from_ratio($bound, DEN)
+            }
+
+            #[cfg(feature = "alloc")]
+            fn error() -> ErrorMessage {
+                format!(concat!($message, " {}"), display_ratio($bound, DEN))
+            }
+
+            #[cfg(not(feature = "alloc"))]
+            fn error() -> ErrorMessage {
+                $static_message
+            }
+
+            #[cfg(feature = "alloc")]
+            fn violation() -> Violation {
+                Violation::predicate(
+                    PredicateKind::$name,
+                    &[Bound::Ratio($bound, DEN)],
+                    <Self as Predicate<T>>::error(),
+                )
+            }
+
+            unsafe fn optimize(value: &T) {
+                core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
+            }
+        }
+    };
+}
+
+float_bound!(GreaterThan, GT, MIN, >, "must be greater than", "greater than");
+float_bound!(GreaterThanEqual, GTE, MIN, >=, "must be greater than or equal to", "greater than or equal to");
+float_bound!(LessThan, LT, MAX, <, "must be less than", "less than");
+float_bound!(LessThanEqual, LTE, MAX, <=, "must be less than or equal to", "less than or equal to");
+
+pub type OpenInterval<const MIN: isize, const MAX: isize, const DEN: usize = 1> =
+    And<GT<MIN, DEN>, LT<MAX, DEN>>;
+
+pub type OpenClosedInterval<const MIN: isize, const MAX: isize, const DEN: usize = 1> =
+    And<GT<MIN, DEN>, LTE<MAX, DEN>>;
+
+pub type ClosedOpenInterval<const MIN: isize, const MAX: isize, const DEN: usize = 1> =
+    And<GTE<MIN, DEN>, LT<MAX, DEN>>;
+
+pub type ClosedInterval<const MIN: isize, const MAX: isize, const DEN: usize = 1> =
+    And<GTE<MIN, DEN>, LTE<MAX, DEN>>;
+
+pub type Positive = GreaterThan<0>;
+
+pub type Negative = LessThan<0>;
+
+pub type NonNegative = GreaterThanEqual<0>;
+
+pub type NonPositive = LessThanEqual<0>;
+
+/// The closed interval `[0, 1]`.
+pub type UnitInterval = ClosedInterval<0, 1>;
+
+/// A refined float whose predicate excludes `NaN`, providing [Eq], [Ord], and [Hash].
+///
+/// Negative and positive zero compare (and hash) as equal, consistent with [PartialEq] for floats.
+#[derive(Clone, Copy, Debug)]
+pub struct TotalFloat<T: Float, P: Predicate<T> + ExcludesNan>(Refinement<T, P>);
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> PartialEq for TotalFloat<T, P> {
+    fn eq(&self, other: &Self) -> bool {
+        **self == **other
+    }
+}
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> Eq for TotalFloat<T, P> {}
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> PartialOrd for TotalFloat<T, P> {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> Ord for TotalFloat<T, P> {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (**self)
+            .partial_cmp(&**other)
+            .expect("refined floats excluding NaN are totally ordered")
+    }
+}
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> Hash for TotalFloat<T, P> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        (**self).canonical_bits().hash(state);
+    }
+}
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> Deref for TotalFloat<T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        self.0.deref()
+    }
+}
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> AsRef<Refinement<T, P>> for TotalFloat<T, P> {
+    fn as_ref(&self) -> &Refinement<T, P> {
+        &self.0
+    }
+}
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> From<Refinement<T, P>> for TotalFloat<T, P> {
+    fn from(value: Refinement<T, P>) -> Self {
+        Self(value)
+    }
+}
+
+impl<T: Float + Display, P: Predicate<T> + ExcludesNan> Display for TotalFloat<T, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        self.0.fmt(f)
+    }
+}
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> TryFrom<Refined<T>> for TotalFloat<T, P> {
+    type Error = RefinementError;
+
+    fn try_from(value: Refined<T>) -> Result<Self, Self::Error> {
+        Self::try_refine(value.0).map_err(RejectedValue::into_error)
+    }
+}
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> From<TotalFloat<T, P>> for Refined<T> {
+    fn from(value: TotalFloat<T, P>) -> Self {
+        Refined(value.take())
+    }
+}
+
+impl<T: Float, P: Predicate<T> + ExcludesNan> RefinementOps for TotalFloat<T, P> {
+    type T = T;
+
+    fn try_refine(value: T) -> Result<Self, RejectedValue<T>> {
+        Refinement::try_refine(value).map(Self)
+    }
+
+    fn take(self) -> T {
+        self.0.take()
+    }
+
+    fn extract(self) -> T {
+        self.0.take()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<T: Float + serde::Serialize, P: Predicate<T> + ExcludesNan> serde::Serialize
+    for TotalFloat<T, P>
+{
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: serde::Serializer,
+    {
+        self.0.serialize(serializer)
+    }
+}
+
+#[cfg(all(feature = "serde", feature = "alloc"))]
+impl<'de, T: Float + serde::Deserialize<'de>, P: Predicate<T> + ExcludesNan> serde::Deserialize<'de>
+    for TotalFloat<T, P>
+{
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: serde::Deserializer<'de>,
+    {
+        Refinement::deserialize(deserializer).map(Self)
+    }
+}
+
+impl Stateless for NotNan {}
+
+impl Stateless for Finite {}
+
+#[cfg(test)]
+mod tests {
+    use crate::{float::*, *};
+    #[cfg(feature = "alloc")]
+    use alloc::format;
+
+    #[test]
+    fn test_not_nan() {
+        assert!(Refinement::<f64, NotNan>::refine(f64::INFINITY).is_ok());
+        assert!(Refinement::<f64, NotNan>::refine(f64::NAN).is_err());
+    }
+
+    #[test]
+    fn test_finite() {
+        assert!(Refinement::<f32, Finite>::refine(1.0).is_ok());
+        assert!(Refinement::<f32, Finite>::refine(f32::NEG_INFINITY).is_err());
+        assert!(Refinement::<f32, Finite>::refine(f32::NAN).is_err());
+    }
+
+    #[test]
+    fn test_bounds() {
+        assert!(Refinement::<f64, Positive>::refine(0.1).is_ok());
+        assert!(Refinement::<f64, Positive>::refine(0.0).is_err());
+        assert!(Refinement::<f64, NonNegative>::refine(-0.0).is_ok());
+        assert!(Refinement::<f64, Negative>::refine(f64::NAN).is_err());
+        assert!(Refinement::<f32, OpenInterval<-1, 3, 2>>::refine(1.4).is_ok());
+        assert!(Refinement::<f32, OpenInterval<-1, 3, 2>>::refine(-0.5).is_err());
+        assert!(Refinement::<f64, UnitInterval>::refine(1.0).is_ok());
+        assert!(Refinement::<f64, UnitInterval>::refine(1.0 + f64::EPSILON).is_err());
+        assert!(Refinement::<f32, LessThanEqual<1, 10>>::refine(0.1).is_ok());
+        assert!(Refinement::<f32, GreaterThanEqual<1, 10>>::refine(0.1).is_ok());
+        assert!(Refinement::<f32, LessThan<1, 10>>::refine(0.1).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_bound_errors() {
+        let err = Refinement::<f64, GreaterThan<-3, 4>>::refine(-1.0).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must be greater than -3/4"
+        );
+        assert_eq!(err.violation().leaves()[0].bounds(), &[Bound::Ratio(-3, 4)]);
+        let err = Refinement::<f64, NotNan>::refine(f64::NAN).unwrap_err();
+        assert_eq!(format!("{}", err), "refinement violated: must not be NaN");
+    }
+
+    #[test]
+    fn test_total_float_ord() {
+        type Total = TotalFloat<f64, Finite>;
+        let a = Total::refine(-0.0).unwrap();
+        let b = Total::refine(0.0).unwrap();
+        let c = Total::refine(1.5).unwrap();
+        assert_eq!(a, b);
+        assert_eq!(a.cmp(&b), core::cmp::Ordering::Equal);
+        assert!(b < c);
+        assert!(Total::refine(f64::NAN).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_total_float_hash() {
+        type Total = TotalFloat<f32, NotNan>;
+        let mut set = std::collections::HashSet::new();
+        set.insert(Total::refine(0.0).unwrap());
+        assert!(set.contains(&Total::refine(-0.0).unwrap()));
+        assert!(!set.contains(&Total::refine(1.0).unwrap()));
+    }
+}
@@ -1,11 +1,11 @@
-use core::{marker::PhantomData, ops::Add};
+use core::ops::Add;
 
 use crate::{boundable::*, Predicate, Refinement};
 
-use super::*;
+use super::{signed_result, unsigned_result};
 
 impl<
-        const A: usize,
+        const A: u128,
         Type: unsigned::UnsignedBoundable + Add<Output = Type>,
         B: UnsignedMax<Type> + Predicate<Type>,
     > Add<Refinement<Type, B>> for Refinement<Type, unsigned::LessThan<A>>
@@ -15,12 +15,12 @@ where
     type Output = Refinement<Type, unsigned::LessThan<{ A + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        unsigned_result(self.0 + rhs.0)
     }
 }
 
 impl<
-        const A: usize,
+        const A: u128,
         Type: unsigned::UnsignedBoundable + Add<Output = Type>,
         B: UnsignedMax<Type> + Predicate<Type>,
     > Add<Refinement<Type, B>> for Refinement<Type, unsigned::LessThanEqual<A>>
@@ -30,43 +30,13 @@ where
     type Output = Refinement<Type, unsigned::LessThanEqual<{ A + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        unsigned_result(self.0 + rhs.0)
     }
 }
 
 impl<
-        const A: usize,
-        Type: unsigned::UnsignedBoundable + Add<Output = Type>,
-        B: UnsignedMin<Type> + Predicate<Type>,
-    > Add<Refinement<Type, B>> for Refinement<Type, unsigned::GreaterThan<A>>
-where
-    Refinement<Type, unsigned::GreaterThan<{ A + B::UMIN }>>: Sized,
-{
-    type Output = Refinement<Type, unsigned::GreaterThan<{ A + B::UMIN }>>;
-
-    fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
-    }
-}
-
-impl<
-        const A: usize,
-        Type: unsigned::UnsignedBoundable + Add<Output = Type>,
-        B: UnsignedMin<Type> + Predicate<Type>,
-    > Add<Refinement<Type, B>> for Refinement<Type, unsigned::GreaterThanEqual<A>>
-where
-    Refinement<Type, unsigned::GreaterThanEqual<{ A + B::UMIN }>>: Sized,
-{
-    type Output = Refinement<Type, unsigned::GreaterThanEqual<{ A + B::UMIN }>>;
-
-    fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
-    }
-}
-
-impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Add<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Add<Refinement<Type, B>> for Refinement<Type, unsigned::OpenInterval<MIN, MAX>>
@@ -76,13 +46,13 @@ where
     type Output = Refinement<Type, unsigned::OpenInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        unsigned_result(self.0 + rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Add<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Add<Refinement<Type, B>> for Refinement<Type, unsigned::ClosedInterval<MIN, MAX>>
@@ -92,13 +62,13 @@ where
     type Output = Refinement<Type, unsigned::ClosedInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        unsigned_result(self.0 + rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Add<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Add<Refinement<Type, B>> for Refinement<Type, unsigned::OpenClosedInterval<MIN, MAX>>
@@ -109,13 +79,13 @@ where
         Refinement<Type, unsigned::OpenClosedInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        unsigned_result(self.0 + rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Add<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Add<Refinement<Type, B>> for Refinement<Type, unsigned::ClosedOpenInterval<MIN, MAX>>
@@ -126,7 +96,7 @@ where
         Refinement<Type, unsigned::ClosedOpenInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        unsigned_result(self.0 + rhs.0)
     }
 }
 
@@ -143,6 +113,28 @@ mod unsigned_tests {
         assert_eq!(*c, 18);
     }
 
+    #[test]
+    fn test_u64_add_beyond_u32() {
+        let a = Refinement::<u64, unsigned::LessThanEqual<{ u32::MAX as u128 }>>::refine(
+            u32::MAX as u64,
+        )
+        .unwrap();
+        let b =
+            Refinement::<u64, unsigned::LessThanEqual<{ u32::MAX as u128 }>>::refine(1).unwrap();
+        let c: Refinement<u64, unsigned::LessThanEqual<{ 2 * u32::MAX as u128 }>> = a + b;
+        assert_eq!(*c, u32::MAX as u64 + 1);
+    }
+
+    #[test]
+    fn test_add_within_domain() {
+        let a = Refinement::<u8, unsigned::LessThanEqual<199>>::refine(199).unwrap();
+        let b = Refinement::<u8, unsigned::LessThan<57>>::refine(56).unwrap();
+        let c: Refinement<u8, unsigned::LessThanEqual<255>> = a + b;
+        assert_eq!(*c, 255);
+        // let _ = Refinement::<u8, unsigned::LessThan<200>>::refine(100).unwrap()
+        //     + Refinement::<u8, unsigned::LessThan<200>>::refine(100).unwrap();
+    }
+
     #[test]
     fn test_lt_add_lte() {
         let a = Refinement::<u8, unsigned::LessThan<10>>::refine(9).unwrap();
@@ -159,22 +151,6 @@ mod unsigned_tests {
         assert_eq!(*c, 19);
     }
 
-    #[test]
-    fn test_gt_add_gt() {
-        let a = Refinement::<u8, unsigned::GreaterThan<10>>::refine(11).unwrap();
-        let b = Refinement::<u8, unsigned::GreaterThan<10>>::refine(12).unwrap();
-        let c: Refinement<u8, unsigned::GreaterThan<21>> = a + b;
-        assert_eq!(*c, 23);
-    }
-
-    #[test]
-    fn test_gt_add_gte() {
-        let a = Refinement::<u8, unsigned::GreaterThan<10>>::refine(11).unwrap();
-        let b = Refinement::<u8, unsigned::GreaterThanEqual<10>>::refine(10).unwrap();
-        let c: Refinement<u8, unsigned::GreaterThan<20>> = a + b;
-        assert_eq!(*c, 21);
-    }
-
     #[test]
     fn test_lte_add_lt() {
         let a = Refinement::<u8, unsigned::LessThanEqual<10>>::refine(10).unwrap();
@@ -191,22 +167,6 @@ mod unsigned_tests {
         assert_eq!(*c, 19);
     }
 
-    #[test]
-    fn test_gte_add_gte() {
-        let a = Refinement::<u8, unsigned::GreaterThanEqual<10>>::refine(10).unwrap();
-        let b = Refinement::<u8, unsigned::GreaterThanEqual<10>>::refine(11).unwrap();
-        let c: Refinement<u8, unsigned::GreaterThanEqual<20>> = a + b;
-        assert_eq!(*c, 21);
-    }
-
-    #[test]
-    fn test_gte_add_gt() {
-        let a = Refinement::<u8, unsigned::GreaterThanEqual<10>>::refine(10).unwrap();
-        let b = Refinement::<u8, unsigned::GreaterThan<10>>::refine(11).unwrap();
-        let c: Refinement<u8, unsigned::GreaterThanEqual<21>> = a + b;
-        assert_eq!(*c, 21);
-    }
-
     #[test]
     fn test_open_closed_interval_add() {
         let a = Refinement::<u8, unsigned::OpenClosedInterval<5, 10>>::refine(7).unwrap();
@@ -241,68 +201,8 @@ mod unsigned_tests {
 }
 
 impl<
-        const A: isize,
-        Type: signed::SignedBoundable + Add<Output = Type>,
-        B: SignedMax<Type> + Predicate<Type>,
-    > Add<Refinement<Type, B>> for Refinement<Type, signed::LessThan<A>>
-where
-    Refinement<Type, signed::LessThan<{ A + B::UMAX }>>: Sized,
-{
-    type Output = Refinement<Type, signed::LessThan<{ A + B::UMAX }>>;
-
-    fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
-    }
-}
-
-impl<
-        const A: isize,
-        Type: signed::SignedBoundable + Add<Output = Type>,
-        B: SignedMax<Type> + Predicate<Type>,
-    > Add<Refinement<Type, B>> for Refinement<Type, signed::LessThanEqual<A>>
-where
-    Refinement<Type, signed::LessThanEqual<{ A + B::UMAX }>>: Sized,
-{
-    type Output = Refinement<Type, signed::LessThanEqual<{ A + B::UMAX }>>;
-
-    fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
-    }
-}
-
-impl<
-        const A: isize,
-        Type: signed::SignedBoundable + Add<Output = Type>,
-        B: SignedMin<Type> + Predicate<Type>,
-    > Add<Refinement<Type, B>> for Refinement<Type, signed::GreaterThan<A>>
-where
-    Refinement<Type, signed::GreaterThan<{ A + B::UMIN }>>: Sized,
-{
-    type Output = Refinement<Type, signed::GreaterThan<{ A + B::UMIN }>>;
-
-    fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
-    }
-}
-
-impl<
-        const A: isize,
-        Type: signed::SignedBoundable + Add<Output = Type>,
-        B: SignedMin<Type> + Predicate<Type>,
-    > Add<Refinement<Type, B>> for Refinement<Type, signed::GreaterThanEqual<A>>
-where
-    Refinement<Type, signed::GreaterThanEqual<{ A + B::UMIN }>>: Sized,
-{
-    type Output = Refinement<Type, signed::GreaterThanEqual<{ A + B::UMIN }>>;
-
-    fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
-    }
-}
-
-impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Add<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Add<Refinement<Type, B>> for Refinement<Type, signed::OpenInterval<MIN, MAX>>
@@ -312,13 +212,13 @@ where
     type Output = Refinement<Type, signed::OpenInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        signed_result(self.0 + rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Add<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Add<Refinement<Type, B>> for Refinement<Type, signed::ClosedInterval<MIN, MAX>>
@@ -328,13 +228,13 @@ where
     type Output = Refinement<Type, signed::ClosedInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        signed_result(self.0 + rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Add<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Add<Refinement<Type, B>> for Refinement<Type, signed::OpenClosedInterval<MIN, MAX>>
@@ -345,13 +245,13 @@ where
         Refinement<Type, signed::OpenClosedInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        signed_result(self.0 + rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Add<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Add<Refinement<Type, B>> for Refinement<Type, signed::ClosedOpenInterval<MIN, MAX>>
@@ -362,7 +262,7 @@ where
         Refinement<Type, signed::ClosedOpenInterval<{ MIN + B::UMIN }, { MAX + B::UMAX }>>;
 
     fn add(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 + rhs.0, PhantomData)
+        signed_result(self.0 + rhs.0)
     }
 }
 
@@ -371,78 +271,6 @@ mod signed_tests {
     use super::*;
     use crate::prelude::*;
 
-    #[test]
-    fn test_lt_add_lt() {
-        let a = Refinement::<i8, signed::LessThan<10>>::refine(9).unwrap();
-        let b = Refinement::<i8, signed::LessThan<10>>::refine(9).unwrap();
-        let c: Refinement<i8, signed::LessThan<19>> = a + b;
-        assert_eq!(*c, 18);
-    }
-
-    #[test]
-    fn test_lt_add_lte() {
-        let a = Refinement::<i8, signed::LessThan<10>>::refine(9).unwrap();
-        let b = Refinement::<i8, signed::LessThanEqual<10>>::refine(10).unwrap();
-        let c: Refinement<i8, signed::LessThan<20>> = a + b;
-        assert_eq!(*c, 19);
-    }
-
-    #[test]
-    fn test_lt_add_eq() {
-        let a = Refinement::<i8, signed::LessThan<10>>::refine(9).unwrap();
-        let b = Refinement::<i8, signed::Equals<10>>::refine(10).unwrap();
-        let c: Refinement<i8, signed::LessThan<20>> = a + b;
-        assert_eq!(*c, 19);
-    }
-
-    #[test]
-    fn test_gt_add_gt() {
-        let a = Refinement::<i8, signed::GreaterThan<10>>::refine(11).unwrap();
-        let b = Refinement::<i8, signed::GreaterThan<10>>::refine(12).unwrap();
-        let c: Refinement<i8, signed::GreaterThan<21>> = a + b;
-        assert_eq!(*c, 23);
-    }
-
-    #[test]
-    fn test_gt_add_gte() {
-        let a = Refinement::<i8, signed::GreaterThan<10>>::refine(11).unwrap();
-        let b = Refinement::<i8, signed::GreaterThanEqual<10>>::refine(10).unwrap();
-        let c: Refinement<i8, signed::GreaterThan<20>> = a + b;
-        assert_eq!(*c, 21);
-    }
-
-    #[test]
-    fn test_lte_add_lt() {
-        let a = Refinement::<i8, signed::LessThanEqual<10>>::refine(10).unwrap();
-        let b = Refinement::<i8, signed::LessThan<10>>::refine(9).unwrap();
-        let c: Refinement<i8, signed::LessThanEqual<19>> = a + b;
-        assert_eq!(*c, 19);
-    }
-
-    #[test]
-    fn test_lte_add_lte() {
-        let a = Refinement::<i8, signed::LessThanEqual<10>>::refine(10).unwrap();
-        let b = Refinement::<i8, signed::LessThanEqual<10>>::refine(9).unwrap();
-        let c: Refinement<i8, signed::LessThanEqual<20>> = a + b;
-        assert_eq!(*c, 19);
-    }
-
-    #[test]
-    fn test_gte_add_gte() {
-        let a = Refinement::<i8, signed::GreaterThanEqual<10>>::refine(10).unwrap();
-        let b = Refinement::<i8, signed::GreaterThanEqual<10>>::refine(11).unwrap();
-        let c: Refinement<i8, signed::GreaterThanEqual<20>> = a + b;
-        assert_eq!(*c, 21);
-    }
-
-    #[test]
-    fn test_gte_add_gt() {
-        let a = Refinement::<i8, signed::GreaterThanEqual<10>>::refine(10).unwrap();
-        let b = Refinement::<i8, signed::GreaterThan<10>>::refine(11).unwrap();
-        let c: Refinement<i8, signed::GreaterThanEqual<21>> = a + b;
-        assert_eq!(*c, 21);
-    }
-
     #[test]
     fn test_open_closed_interval_add() {
         let a = Refinement::<i8, signed::OpenClosedInterval<5, 10>>::refine(7).unwrap();
@@ -0,0 +1,74 @@
+use core::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Rem, Sub},
+};
+
+use crate::{boundable::*, Predicate, Refinement};
+
+fn unsigned_constant<Type: unsigned::UnsignedBoundable + TryFrom<u128>, const N: u128>(
+) -> Refinement<Type, unsigned::Equals<N>> {
+    let () = unsigned::InDomain::<Type, N>::CHECK;
+    let value = Type::try_from(N).unwrap_or_else(|_| unreachable!("constant is within the domain"));
+    Refinement(value, PhantomData)
+}
+
+fn signed_constant<Type: signed::SignedBoundable + TryFrom<i128>, const N: i128>(
+) -> Refinement<Type, signed::Equals<N>> {
+    let () = signed::InDomain::<Type, N>::CHECK;
+    let value = Type::try_from(N).unwrap_or_else(|_| unreachable!("constant is within the domain"));
+    Refinement(value, PhantomData)
+}
+
+macro_rules! constant_operators {
+    ($m:ident, $boundable:ident, $t:ty, $constant:ident; $($op:ident, $fun:ident);+) => {
+        $(
+            impl<Type, P, const N: $t> $op<$m::Equals<N>> for Refinement<Type, P>
+            where
+                Type: $m::$boundable + TryFrom<$t>,
+                P: Predicate<Type>,
+                Refinement<Type, P>: $op<Refinement<Type, $m::Equals<N>>>,
+            {
+                type Output = <Refinement<Type, P> as $op<Refinement<Type, $m::Equals<N>>>>::Output;
+
+                fn $fun(self, _: $m::Equals<N>) -> Self::Output {
+                    self.$fun($constant::<Type, N>())
+                }
+            }
+        )+
+    };
+}
+
+constant_operators! {
+    unsigned, UnsignedBoundable, u128, unsigned_constant;
+    Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem
+}
+
+constant_operators! {
+    signed, SignedBoundable, i128, signed_constant;
+    Add, add; Sub, sub; Mul, mul; Div, div; Rem, rem
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_unsigned_constant() {
+        let a = Refinement::<u8, unsigned::ClosedInterval<1, 10>>::refine(5).unwrap();
+        let b: Refinement<u8, unsigned::ClosedInterval<2, 11>> = a + unsigned::Equals::<1>;
+        assert_eq!(*b, 6);
+        let c: Refinement<u8, unsigned::LessThanEqual<2>> = a % unsigned::Equals::<3>;
+        assert_eq!(*c, 2);
+        // let _ = a + unsigned::Equals::<256>;
+    }
+
+    #[test]
+    fn test_signed_constant() {
+        let a = Refinement::<i16, signed::ClosedInterval<-5, 5>>::refine(-4).unwrap();
+        let b = a * signed::Equals::<-2>;
+        assert_eq!(*b, 8);
+        let c: Refinement<i16, signed::ClosedInterval<-8, 2>> = a - signed::Equals::<3>;
+        assert_eq!(*c, -7);
+    }
+}
@@ -1,11 +1,11 @@
-use core::{marker::PhantomData, ops::Div};
+use core::ops::Div;
 
 use crate::{boundable::*, Predicate, Refinement};
 
-use super::*;
+use super::{signed_result, unsigned_result};
 
 impl<
-        const A: usize,
+        const A: u128,
         Type: unsigned::UnsignedBoundable + Div<Output = Type>,
         B: UnsignedMax<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, unsigned::LessThan<A>>
@@ -15,12 +15,12 @@ where
     type Output = Refinement<Type, unsigned::LessThan<A>>;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        unsigned_result(self.0 / rhs.0)
     }
 }
 
 impl<
-        const A: usize,
+        const A: u128,
         Type: unsigned::UnsignedBoundable + Div<Output = Type>,
         B: UnsignedMax<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, unsigned::LessThanEqual<A>>
@@ -30,12 +30,12 @@ where
     type Output = Refinement<Type, unsigned::LessThanEqual<A>>;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        unsigned_result(self.0 / rhs.0)
     }
 }
 
 impl<
-        const A: usize,
+        const A: u128,
         Type: unsigned::UnsignedBoundable + Div<Output = Type>,
         B: UnsignedMin<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, unsigned::GreaterThan<A>>
@@ -45,12 +45,12 @@ where
     type Output = Refinement<Type, unsigned::GreaterThan<{ A / B::UMIN }>>;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        unsigned_result(self.0 / rhs.0)
     }
 }
 
 impl<
-        const A: usize,
+        const A: u128,
         Type: unsigned::UnsignedBoundable + Div<Output = Type>,
         B: UnsignedMin<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, unsigned::GreaterThanEqual<A>>
@@ -60,13 +60,13 @@ where
     type Output = Refinement<Type, unsigned::GreaterThanEqual<{ A / B::UMIN }>>;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        unsigned_result(self.0 / rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Div<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, unsigned::OpenInterval<MIN, MAX>>
@@ -82,13 +82,13 @@ where
     >;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        unsigned_result(self.0 / rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Div<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, unsigned::ClosedInterval<MIN, MAX>>
@@ -98,13 +98,13 @@ where
     type Output = Refinement<Type, unsigned::ClosedInterval<{ MIN / B::UMAX }, { MAX / B::UMIN }>>;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        unsigned_result(self.0 / rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Div<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, unsigned::OpenClosedInterval<MIN, MAX>>
@@ -118,13 +118,13 @@ where
     >;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        unsigned_result(self.0 / rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Div<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, unsigned::ClosedOpenInterval<MIN, MAX>>
@@ -138,7 +138,7 @@ where
     >;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        unsigned_result(self.0 / rhs.0)
     }
 }
 
@@ -237,8 +237,8 @@ mod unsigned_tests {
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Div<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, signed::OpenInterval<MIN, MAX>>
@@ -260,13 +260,13 @@ where
     >;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        signed_result(self.0 / rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Div<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, signed::ClosedInterval<MIN, MAX>>
@@ -288,13 +288,13 @@ where
     >;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        signed_result(self.0 / rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Div<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, signed::OpenClosedInterval<MIN, MAX>>
@@ -316,13 +316,13 @@ where
     >;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        signed_result(self.0 / rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Div<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Div<Refinement<Type, B>> for Refinement<Type, signed::ClosedOpenInterval<MIN, MAX>>
@@ -344,7 +344,7 @@ where
     >;
 
     fn div(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 / rhs.0, PhantomData)
+        signed_result(self.0 / rhs.0)
     }
 }
 
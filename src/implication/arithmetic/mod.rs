@@ -1,275 +1,102 @@
-use crate::{boundable::*, Predicate, SignedBoundable, UnsignedBoundable};
-
 mod add;
+mod constant;
 mod div;
 mod mul;
+mod neg;
+mod pow;
+mod rem;
+mod shift;
 mod sub;
 
-/// A type that has a statically knowable unsigned maximum value.
-pub trait UnsignedMax<T: UnsignedBoundable>: Predicate<T> {
-    /// The maximum value.
-    const UMAX: usize;
-}
-
-impl<T: UnsignedBoundable, const MAX: usize> UnsignedMax<T> for unsigned::LessThan<MAX> {
-    const UMAX: usize = MAX - 1;
-}
-
-impl<T: UnsignedBoundable, const MAX: usize> UnsignedMax<T> for unsigned::LessThanEqual<MAX> {
-    const UMAX: usize = MAX;
-}
-
-impl<T: UnsignedBoundable, const VAL: usize> UnsignedMax<T> for unsigned::Equals<VAL> {
-    const UMAX: usize = VAL;
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMax<T>
-    for unsigned::ClosedInterval<MIN, MAX>
-{
-    const UMAX: usize = MAX;
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMax<T>
-    for unsigned::OpenClosedInterval<MIN, MAX>
-{
-    const UMAX: usize = MAX;
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMax<T>
-    for unsigned::OpenInterval<MIN, MAX>
-{
-    const UMAX: usize = MAX - 1;
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMax<T>
-    for unsigned::ClosedOpenInterval<MIN, MAX>
-{
-    const UMAX: usize = MAX - 1;
-}
-
-/// A type that has a statically knowable unsigned minimum value.
-pub trait UnsignedMin<T: UnsignedBoundable>: Predicate<T> {
-    /// The minimum value.
-    const UMIN: usize;
-}
-
-impl<T: UnsignedBoundable, const MIN: usize> UnsignedMin<T> for unsigned::GreaterThan<MIN> {
-    const UMIN: usize = MIN + 1;
-}
-
-impl<T: UnsignedBoundable, const MIN: usize> UnsignedMin<T> for unsigned::GreaterThanEqual<MIN> {
-    const UMIN: usize = MIN;
-}
-
-impl<T: UnsignedBoundable, const VAL: usize> UnsignedMin<T> for unsigned::Equals<VAL> {
-    const UMIN: usize = VAL;
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMin<T>
-    for unsigned::ClosedInterval<MIN, MAX>
-{
-    const UMIN: usize = MIN;
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMin<T>
-    for unsigned::OpenClosedInterval<MIN, MAX>
-{
-    const UMIN: usize = MIN + 1;
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMin<T>
-    for unsigned::OpenInterval<MIN, MAX>
-{
-    const UMIN: usize = MIN + 1;
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMin<T>
-    for unsigned::ClosedOpenInterval<MIN, MAX>
-{
-    const UMIN: usize = MIN;
-}
-
-/// A type that has a statically knowable unsigned minimum value and maximum value.
-pub trait UnsignedMinMax<T: UnsignedBoundable>:
-    Predicate<T> + UnsignedMin<T> + UnsignedMax<T>
-{
-}
-
-impl<T: UnsignedBoundable, const VAL: usize> UnsignedMinMax<T> for unsigned::Equals<VAL> {}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMinMax<T>
-    for unsigned::ClosedInterval<MIN, MAX>
-{
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMinMax<T>
-    for unsigned::OpenClosedInterval<MIN, MAX>
-{
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMinMax<T>
-    for unsigned::OpenInterval<MIN, MAX>
-{
-}
-
-impl<T: UnsignedBoundable, const MIN: usize, const MAX: usize> UnsignedMinMax<T>
-    for unsigned::ClosedOpenInterval<MIN, MAX>
-{
-}
-
-/// A type that has a statically knowable signed maximum value.
-pub trait SignedMax<T: SignedBoundable>: Predicate<T> {
-    /// The maximum value.
-    const UMAX: isize;
-}
-
-impl<T: SignedBoundable, const MAX: isize> SignedMax<T> for signed::LessThan<MAX> {
-    const UMAX: isize = MAX - 1;
-}
-
-impl<T: SignedBoundable, const MAX: isize> SignedMax<T> for signed::LessThanEqual<MAX> {
-    const UMAX: isize = MAX;
-}
-
-impl<T: SignedBoundable, const VAL: isize> SignedMax<T> for signed::Equals<VAL> {
-    const UMAX: isize = VAL;
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMax<T>
-    for signed::ClosedInterval<MIN, MAX>
-{
-    const UMAX: isize = MAX;
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMax<T>
-    for signed::OpenClosedInterval<MIN, MAX>
-{
-    const UMAX: isize = MAX;
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMax<T>
-    for signed::OpenInterval<MIN, MAX>
-{
-    const UMAX: isize = MAX - 1;
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMax<T>
-    for signed::ClosedOpenInterval<MIN, MAX>
-{
-    const UMAX: isize = MAX - 1;
-}
-
-/// A type that has a statically knowable signed minimum value.
-pub trait SignedMin<T: SignedBoundable>: Predicate<T> {
-    /// The minimum value.
-    const UMIN: isize;
-}
-
-impl<T: SignedBoundable, const MIN: isize> SignedMin<T> for signed::GreaterThan<MIN> {
-    const UMIN: isize = MIN + 1;
-}
-
-impl<T: SignedBoundable, const MIN: isize> SignedMin<T> for signed::GreaterThanEqual<MIN> {
-    const UMIN: isize = MIN;
-}
-
-impl<T: SignedBoundable, const VAL: isize> SignedMin<T> for signed::Equals<VAL> {
-    const UMIN: isize = VAL;
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMin<T>
-    for signed::ClosedInterval<MIN, MAX>
-{
-    const UMIN: isize = MIN;
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMin<T>
-    for signed::OpenClosedInterval<MIN, MAX>
-{
-    const UMIN: isize = MIN + 1;
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMin<T>
-    for signed::OpenInterval<MIN, MAX>
-{
-    const UMIN: isize = MIN + 1;
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMin<T>
-    for signed::ClosedOpenInterval<MIN, MAX>
-{
-    const UMIN: isize = MIN;
-}
-
-/// A type that has a statically knowable signed minimum value and maximum value.
-pub trait SignedMinMax<T: SignedBoundable>: Predicate<T> + SignedMin<T> + SignedMax<T> {}
-
-impl<T: SignedBoundable, const VAL: isize> SignedMinMax<T> for signed::Equals<VAL> {}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMinMax<T>
-    for signed::ClosedInterval<MIN, MAX>
-{
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMinMax<T>
-    for signed::OpenClosedInterval<MIN, MAX>
-{
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMinMax<T>
-    for signed::OpenInterval<MIN, MAX>
-{
-}
-
-impl<T: SignedBoundable, const MIN: isize, const MAX: isize> SignedMinMax<T>
-    for signed::ClosedOpenInterval<MIN, MAX>
-{
-}
-
-pub const fn elem_min(a: isize, b: isize) -> isize {
-    if a <= b {
-        a
-    } else {
-        b
-    }
-}
-
-/// Calculates the minimum bounds for an interval over multiplication.
-pub const fn min_mul(xmin: isize, xmax: isize, ymin: isize, ymax: isize) -> isize {
-    elem_min(
-        xmin * ymin,
-        elem_min(xmin * ymax, elem_min(xmax * ymin, xmax * ymax)),
-    )
-}
-
-/// Calculates the minimum bounds for an interval over division.
-pub const fn min_div(xmin: isize, xmax: isize, ymin: isize, ymax: isize) -> isize {
-    elem_min(
-        xmin / ymin,
-        elem_min(xmin / ymax, elem_min(xmax / ymin, xmax / ymax)),
-    )
-}
-
-pub const fn elem_max(a: isize, b: isize) -> isize {
-    if a >= b {
-        a
-    } else {
-        b
-    }
-}
-
-/// Calculates the maximum bounds for an interval over multiplication.
-pub const fn max_mul(xmin: isize, xmax: isize, ymin: isize, ymax: isize) -> isize {
-    elem_max(
-        xmin * ymin,
-        elem_max(xmin * ymax, elem_max(xmax * ymin, xmax * ymax)),
-    )
-}
-
-/// Calculates the maximum bounds for an interval over division.
-pub const fn max_div(xmin: isize, xmax: isize, ymin: isize, ymax: isize) -> isize {
-    elem_max(
-        xmin / ymin,
-        elem_max(xmin / ymax, elem_max(xmax / ymin, xmax / ymax)),
-    )
+pub use crate::boundable::{
+    elem_max, elem_min, max_div, max_mul, max_pow, max_rem, max_shl, max_shr, min_div, min_mul,
+    min_pow, min_rem, min_shl, min_shr, signed_shl, unsigned_min, unsigned_shl, SignedMax,
+    SignedMin, SignedMinMax, UnsignedMax, UnsignedMin, UnsignedMinMax,
+};
+pub use pow::Pow;
+
+use core::marker::PhantomData;
+
+use crate::{boundable::*, Predicate, Refinement};
+
+/// The largest value satisfying a boundable predicate, which is the maximum of `T` for predicates
+/// without an upper bound.
+trait UnsignedRange<T: unsigned::UnsignedBoundable> {
+    const RMAX: u128;
+}
+
+/// The range of values satisfying a boundable predicate, where the range of `T` bounds any side that
+/// the predicate leaves open.
+trait SignedRange<T: signed::SignedBoundable> {
+    const RMIN: i128;
+    const RMAX: i128;
+}
+
+macro_rules! range {
+    ($range:ident, $m:ident, $boundable:ident, $t:ty; $([$($param:ident),+] $pred:ident => $(min: $lo:expr,)? max: $hi:expr;)+) => {
+        $(
+            impl<T: $m::$boundable, $(const $param: $t),+> $range<T> for $m::$pred<$($param),+> {
+                $(const RMIN: $t = $lo;)?
+                const RMAX: $t = $hi;
+            }
+        )+
+    };
+}
+
+range! {
+    UnsignedRange, unsigned, UnsignedBoundable, u128;
+    [A] LessThan => max: A - 1;
+    [A] LessThanEqual => max: A;
+    [A] GreaterThan => max: T::BOUND_MAX;
+    [A] GreaterThanEqual => max: T::BOUND_MAX;
+    [VAL] Equals => max: VAL;
+    [MIN, MAX] OpenInterval => max: MAX - 1;
+    [MIN, MAX] ClosedInterval => max: MAX;
+    [MIN, MAX] OpenClosedInterval => max: MAX;
+    [MIN, MAX] ClosedOpenInterval => max: MAX - 1;
+}
+
+range! {
+    SignedRange, signed, SignedBoundable, i128;
+    [A] LessThan => min: T::BOUND_MIN, max: A - 1;
+    [A] LessThanEqual => min: T::BOUND_MIN, max: A;
+    [A] GreaterThan => min: A + 1, max: T::BOUND_MAX;
+    [A] GreaterThanEqual => min: A, max: T::BOUND_MAX;
+    [VAL] Equals => min: VAL, max: VAL;
+    [MIN, MAX] OpenInterval => min: MIN + 1, max: MAX - 1;
+    [MIN, MAX] ClosedInterval => min: MIN, max: MAX;
+    [MIN, MAX] OpenClosedInterval => min: MIN + 1, max: MAX;
+    [MIN, MAX] ClosedOpenInterval => min: MIN, max: MAX - 1;
+}
+
+struct Fits<T, P>(PhantomData<(T, P)>);
+
+impl<T: unsigned::UnsignedBoundable, P: UnsignedRange<T>> Fits<T, P> {
+    const UNSIGNED: () = assert!(
+        P::RMAX <= T::BOUND_MAX,
+        "result bounds exceed the range of the bounded type"
+    );
+}
+
+impl<T: signed::SignedBoundable, P: SignedRange<T>> Fits<T, P> {
+    const SIGNED: () = assert!(
+        T::BOUND_MIN <= P::RMIN && P::RMAX <= T::BOUND_MAX,
+        "result bounds exceed the range of the bounded type"
+    );
+}
+
+/// Wraps the result of an operation, failing to build if its bounds do not fit within `T`.
+fn unsigned_result<T: unsigned::UnsignedBoundable, P: UnsignedRange<T> + Predicate<T>>(
+    value: T,
+) -> Refinement<T, P> {
+    let () = Fits::<T, P>::UNSIGNED;
+    Refinement(value, PhantomData)
+}
+
+/// Wraps the result of an operation, failing to build if its bounds do not fit within `T`.
+fn signed_result<T: signed::SignedBoundable, P: SignedRange<T> + Predicate<T>>(
+    value: T,
+) -> Refinement<T, P> {
+    let () = Fits::<T, P>::SIGNED;
+    Refinement(value, PhantomData)
 }
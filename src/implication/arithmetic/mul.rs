@@ -1,11 +1,11 @@
-use core::{marker::PhantomData, ops::Mul};
+use core::ops::Mul;
 
 use crate::{boundable::*, Predicate, Refinement};
 
-use super::*;
+use super::{signed_result, unsigned_result};
 
 impl<
-        const A: usize,
+        const A: u128,
         Type: unsigned::UnsignedBoundable + Mul<Output = Type>,
         B: UnsignedMax<Type> + Predicate<Type>,
     > Mul<Refinement<Type, B>> for Refinement<Type, unsigned::LessThan<A>>
@@ -15,12 +15,12 @@ where
     type Output = Refinement<Type, unsigned::LessThan<{ (A - 1) * B::UMAX + 1 }>>;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        unsigned_result(self.0 * rhs.0)
     }
 }
 
 impl<
-        const A: usize,
+        const A: u128,
         Type: unsigned::UnsignedBoundable + Mul<Output = Type>,
         B: UnsignedMax<Type> + Predicate<Type>,
     > Mul<Refinement<Type, B>> for Refinement<Type, unsigned::LessThanEqual<A>>
@@ -30,43 +30,13 @@ where
     type Output = Refinement<Type, unsigned::LessThanEqual<{ A * B::UMAX }>>;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        unsigned_result(self.0 * rhs.0)
     }
 }
 
 impl<
-        const A: usize,
-        Type: unsigned::UnsignedBoundable + Mul<Output = Type>,
-        B: UnsignedMin<Type> + Predicate<Type>,
-    > Mul<Refinement<Type, B>> for Refinement<Type, unsigned::GreaterThan<A>>
-where
-    Refinement<Type, unsigned::GreaterThan<{ (A + 1) * B::UMIN - 1 }>>: Sized,
-{
-    type Output = Refinement<Type, unsigned::GreaterThan<{ (A + 1) * B::UMIN - 1 }>>;
-
-    fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
-    }
-}
-
-impl<
-        const A: usize,
-        Type: unsigned::UnsignedBoundable + Mul<Output = Type>,
-        B: UnsignedMin<Type> + Predicate<Type>,
-    > Mul<Refinement<Type, B>> for Refinement<Type, unsigned::GreaterThanEqual<A>>
-where
-    Refinement<Type, unsigned::GreaterThanEqual<{ A * B::UMIN }>>: Sized,
-{
-    type Output = Refinement<Type, unsigned::GreaterThanEqual<{ A * B::UMIN }>>;
-
-    fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
-    }
-}
-
-impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Mul<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Mul<Refinement<Type, B>> for Refinement<Type, unsigned::OpenInterval<MIN, MAX>>
@@ -82,13 +52,13 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        unsigned_result(self.0 * rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Mul<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Mul<Refinement<Type, B>> for Refinement<Type, unsigned::ClosedInterval<MIN, MAX>>
@@ -98,13 +68,13 @@ where
     type Output = Refinement<Type, unsigned::ClosedInterval<{ MIN * B::UMIN }, { MAX * B::UMAX }>>;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        unsigned_result(self.0 * rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Mul<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Mul<Refinement<Type, B>> for Refinement<Type, unsigned::OpenClosedInterval<MIN, MAX>>
@@ -118,13 +88,13 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        unsigned_result(self.0 * rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Mul<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Mul<Refinement<Type, B>> for Refinement<Type, unsigned::ClosedOpenInterval<MIN, MAX>>
@@ -138,7 +108,7 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        unsigned_result(self.0 * rhs.0)
     }
 }
 
@@ -171,38 +141,6 @@ mod unsigned_tests {
         assert_eq!(*c, 18);
     }
 
-    #[test]
-    fn test_gt_mul_gt() {
-        let a = Refinement::<u8, unsigned::GreaterThan<10>>::refine(11).unwrap();
-        let b = Refinement::<u8, unsigned::GreaterThan<10>>::refine(11).unwrap();
-        let c: Refinement<u8, unsigned::GreaterThan<120>> = a * b;
-        assert_eq!(*c, 121);
-    }
-
-    #[test]
-    fn test_gt_mul_gte() {
-        let a = Refinement::<u8, unsigned::GreaterThan<10>>::refine(11).unwrap();
-        let b = Refinement::<u8, unsigned::GreaterThanEqual<3>>::refine(3).unwrap();
-        let c: Refinement<u8, unsigned::GreaterThan<32>> = a * b;
-        assert_eq!(*c, 33);
-    }
-
-    #[test]
-    fn test_gte_mul_gte() {
-        let a = Refinement::<u8, unsigned::GreaterThanEqual<10>>::refine(12).unwrap();
-        let b = Refinement::<u8, unsigned::GreaterThanEqual<3>>::refine(3).unwrap();
-        let c: Refinement<u8, unsigned::GreaterThanEqual<30>> = a * b;
-        assert_eq!(*c, 36);
-    }
-
-    #[test]
-    fn test_gte_mul_gt() {
-        let a = Refinement::<u8, unsigned::GreaterThanEqual<12>>::refine(15).unwrap();
-        let b = Refinement::<u8, unsigned::GreaterThan<3>>::refine(4).unwrap();
-        let c: Refinement<u8, unsigned::GreaterThanEqual<48>> = a * b;
-        assert_eq!(*c, 60);
-    }
-
     #[test]
     fn test_open_closed_interval_mul() {
         let a = Refinement::<u8, unsigned::OpenClosedInterval<15, 20>>::refine(18).unwrap();
@@ -229,16 +167,16 @@ mod unsigned_tests {
 
     #[test]
     fn test_closed_interval_mul() {
-        let a = Refinement::<u8, unsigned::ClosedInterval<15, 50>>::refine(30).unwrap();
-        let b = Refinement::<u8, unsigned::ClosedInterval<3, 6>>::refine(6).unwrap();
-        let c: Refinement<u8, unsigned::ClosedInterval<45, 300>> = a * b;
+        let a = Refinement::<u16, unsigned::ClosedInterval<15, 50>>::refine(30).unwrap();
+        let b = Refinement::<u16, unsigned::ClosedInterval<3, 6>>::refine(6).unwrap();
+        let c: Refinement<u16, unsigned::ClosedInterval<45, 300>> = a * b;
         assert_eq!(*c, 180);
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Mul<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Mul<Refinement<Type, B>> for Refinement<Type, signed::OpenInterval<MIN, MAX>>
@@ -260,13 +198,13 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        signed_result(self.0 * rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Mul<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Mul<Refinement<Type, B>> for Refinement<Type, signed::ClosedInterval<MIN, MAX>>
@@ -288,13 +226,13 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        signed_result(self.0 * rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Mul<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Mul<Refinement<Type, B>> for Refinement<Type, signed::OpenClosedInterval<MIN, MAX>>
@@ -316,13 +254,13 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        signed_result(self.0 * rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Mul<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Mul<Refinement<Type, B>> for Refinement<Type, signed::ClosedOpenInterval<MIN, MAX>>
@@ -344,7 +282,7 @@ where
     >;
 
     fn mul(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 * rhs.0, PhantomData)
+        signed_result(self.0 * rhs.0)
     }
 }
 
@@ -371,9 +309,9 @@ mod signed_tests {
 
     #[test]
     fn test_open_interval_mul() {
-        let a = Refinement::<i8, signed::OpenInterval<15, 30>>::refine(16).unwrap();
-        let b = Refinement::<i8, signed::OpenInterval<-13, -6>>::refine(-7).unwrap();
-        let c: Refinement<i8, signed::OpenInterval<-349, -111>> = a * b;
+        let a = Refinement::<i16, signed::OpenInterval<15, 30>>::refine(16).unwrap();
+        let b = Refinement::<i16, signed::OpenInterval<-13, -6>>::refine(-7).unwrap();
+        let c: Refinement<i16, signed::OpenInterval<-349, -111>> = a * b;
         assert_eq!(*c, -112);
     }
 
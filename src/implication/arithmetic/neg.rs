@@ -0,0 +1,125 @@
+use core::ops::Neg;
+
+use crate::{boundable::*, Refinement};
+
+use super::signed_result;
+
+impl<const A: i128, Type: signed::SignedBoundable + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::GreaterThan<A>>
+where
+    Refinement<Type, signed::LessThan<{ -A }>>: Sized,
+{
+    type Output = Refinement<Type, signed::LessThan<{ -A }>>;
+
+    fn neg(self) -> Self::Output {
+        signed_result(-self.0)
+    }
+}
+
+impl<const A: i128, Type: signed::SignedBoundable + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::GreaterThanEqual<A>>
+where
+    Refinement<Type, signed::LessThanEqual<{ -A }>>: Sized,
+{
+    type Output = Refinement<Type, signed::LessThanEqual<{ -A }>>;
+
+    fn neg(self) -> Self::Output {
+        signed_result(-self.0)
+    }
+}
+
+impl<const VAL: i128, Type: signed::SignedBoundable + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::Equals<VAL>>
+where
+    Refinement<Type, signed::Equals<{ -VAL }>>: Sized,
+{
+    type Output = Refinement<Type, signed::Equals<{ -VAL }>>;
+
+    fn neg(self) -> Self::Output {
+        signed_result(-self.0)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128, Type: signed::SignedBoundable + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::OpenInterval<MIN, MAX>>
+where
+    Refinement<Type, signed::OpenInterval<{ -MAX }, { -MIN }>>: Sized,
+{
+    type Output = Refinement<Type, signed::OpenInterval<{ -MAX }, { -MIN }>>;
+
+    fn neg(self) -> Self::Output {
+        signed_result(-self.0)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128, Type: signed::SignedBoundable + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::ClosedInterval<MIN, MAX>>
+where
+    Refinement<Type, signed::ClosedInterval<{ -MAX }, { -MIN }>>: Sized,
+{
+    type Output = Refinement<Type, signed::ClosedInterval<{ -MAX }, { -MIN }>>;
+
+    fn neg(self) -> Self::Output {
+        signed_result(-self.0)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128, Type: signed::SignedBoundable + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::OpenClosedInterval<MIN, MAX>>
+where
+    Refinement<Type, signed::ClosedOpenInterval<{ -MAX }, { -MIN }>>: Sized,
+{
+    type Output = Refinement<Type, signed::ClosedOpenInterval<{ -MAX }, { -MIN }>>;
+
+    fn neg(self) -> Self::Output {
+        signed_result(-self.0)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128, Type: signed::SignedBoundable + Neg<Output = Type>> Neg
+    for Refinement<Type, signed::ClosedOpenInterval<MIN, MAX>>
+where
+    Refinement<Type, signed::OpenClosedInterval<{ -MAX }, { -MIN }>>: Sized,
+{
+    type Output = Refinement<Type, signed::OpenClosedInterval<{ -MAX }, { -MIN }>>;
+
+    fn neg(self) -> Self::Output {
+        signed_result(-self.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_neg_bounds() {
+        let a = Refinement::<i8, signed::GreaterThan<-10>>::refine(5).unwrap();
+        let b: Refinement<i8, signed::LessThan<10>> = -a;
+        assert_eq!(*b, -5);
+        let c = Refinement::<i8, signed::GreaterThanEqual<0>>::refine(3).unwrap();
+        let d: Refinement<i8, signed::LessThanEqual<0>> = -c;
+        assert_eq!(*d, -3);
+        // let _ = -Refinement::<i8, signed::LessThan<10>>::refine(-5).unwrap();
+    }
+
+    #[test]
+    fn test_neg_eq() {
+        let a = Refinement::<i16, signed::Equals<4>>::refine(4).unwrap();
+        let b: Refinement<i16, signed::Equals<-4>> = -a;
+        assert_eq!(*b, -4);
+    }
+
+    #[test]
+    fn test_neg_intervals() {
+        let a = Refinement::<i32, signed::OpenClosedInterval<-3, 7>>::refine(7).unwrap();
+        let b: Refinement<i32, signed::ClosedOpenInterval<-7, 3>> = -a;
+        assert_eq!(*b, -7);
+        let c: Refinement<i32, signed::OpenClosedInterval<-3, 7>> = -b;
+        assert_eq!(*c, 7);
+        let d = Refinement::<i32, signed::ClosedInterval<-2, 5>>::refine(0).unwrap();
+        let e: Refinement<i32, signed::ClosedInterval<-5, 2>> = -d;
+        assert_eq!(*e, 0);
+    }
+}
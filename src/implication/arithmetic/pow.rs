@@ -0,0 +1,293 @@
+use crate::{boundable::*, Refinement};
+
+use super::{signed_result, unsigned_result};
+
+/// Integer exponentiation, used by the `pow` methods of bounded refinements.
+pub trait Pow {
+    /// Raises `self` to the power of `exp`.
+    fn pow(self, exp: u32) -> Self;
+}
+
+macro_rules! pow_primitive {
+    ($($t:ty),+) => {
+        $(
+            impl Pow for $t {
+                fn pow(self, exp: u32) -> Self {
+                    <$t>::pow(self, exp)
+                }
+            }
+        )+
+    };
+}
+
+pow_primitive!(u8, u16, u32, u64, u128, usize, i8, i16, i32, i64, i128, isize);
+
+impl<const A: u128, Type: unsigned::UnsignedBoundable + Pow>
+    Refinement<Type, unsigned::LessThan<A>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(
+        self,
+    ) -> Refinement<Type, unsigned::LessThanEqual<{ u128::pow(A - 1, EXP) }>>
+    where
+        Refinement<Type, unsigned::LessThanEqual<{ u128::pow(A - 1, EXP) }>>: Sized,
+    {
+        unsigned_result(Pow::pow(self.0, EXP))
+    }
+}
+
+impl<const A: u128, Type: unsigned::UnsignedBoundable + Pow>
+    Refinement<Type, unsigned::LessThanEqual<A>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(
+        self,
+    ) -> Refinement<Type, unsigned::LessThanEqual<{ u128::pow(A, EXP) }>>
+    where
+        Refinement<Type, unsigned::LessThanEqual<{ u128::pow(A, EXP) }>>: Sized,
+    {
+        unsigned_result(Pow::pow(self.0, EXP))
+    }
+}
+
+impl<const VAL: u128, Type: unsigned::UnsignedBoundable + Pow>
+    Refinement<Type, unsigned::Equals<VAL>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(self) -> Refinement<Type, unsigned::Equals<{ u128::pow(VAL, EXP) }>>
+    where
+        Refinement<Type, unsigned::Equals<{ u128::pow(VAL, EXP) }>>: Sized,
+    {
+        unsigned_result(Pow::pow(self.0, EXP))
+    }
+}
+
+impl<const MIN: u128, const MAX: u128, Type: unsigned::UnsignedBoundable + Pow>
+    Refinement<Type, unsigned::OpenInterval<MIN, MAX>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(
+        self,
+    ) -> Refinement<
+        Type,
+        unsigned::ClosedInterval<{ u128::pow(MIN + 1, EXP) }, { u128::pow(MAX - 1, EXP) }>,
+    >
+    where
+        Refinement<
+            Type,
+            unsigned::ClosedInterval<{ u128::pow(MIN + 1, EXP) }, { u128::pow(MAX - 1, EXP) }>,
+        >: Sized,
+    {
+        unsigned_result(Pow::pow(self.0, EXP))
+    }
+}
+
+impl<const MIN: u128, const MAX: u128, Type: unsigned::UnsignedBoundable + Pow>
+    Refinement<Type, unsigned::ClosedInterval<MIN, MAX>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(
+        self,
+    ) -> Refinement<Type, unsigned::ClosedInterval<{ u128::pow(MIN, EXP) }, { u128::pow(MAX, EXP) }>>
+    where
+        Refinement<
+            Type,
+            unsigned::ClosedInterval<{ u128::pow(MIN, EXP) }, { u128::pow(MAX, EXP) }>,
+        >: Sized,
+    {
+        unsigned_result(Pow::pow(self.0, EXP))
+    }
+}
+
+impl<const MIN: u128, const MAX: u128, Type: unsigned::UnsignedBoundable + Pow>
+    Refinement<Type, unsigned::OpenClosedInterval<MIN, MAX>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(
+        self,
+    ) -> Refinement<
+        Type,
+        unsigned::ClosedInterval<{ u128::pow(MIN + 1, EXP) }, { u128::pow(MAX, EXP) }>,
+    >
+    where
+        Refinement<
+            Type,
+            unsigned::ClosedInterval<{ u128::pow(MIN + 1, EXP) }, { u128::pow(MAX, EXP) }>,
+        >: Sized,
+    {
+        unsigned_result(Pow::pow(self.0, EXP))
+    }
+}
+
+impl<const MIN: u128, const MAX: u128, Type: unsigned::UnsignedBoundable + Pow>
+    Refinement<Type, unsigned::ClosedOpenInterval<MIN, MAX>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(
+        self,
+    ) -> Refinement<
+        Type,
+        unsigned::ClosedInterval<{ u128::pow(MIN, EXP) }, { u128::pow(MAX - 1, EXP) }>,
+    >
+    where
+        Refinement<
+            Type,
+            unsigned::ClosedInterval<{ u128::pow(MIN, EXP) }, { u128::pow(MAX - 1, EXP) }>,
+        >: Sized,
+    {
+        unsigned_result(Pow::pow(self.0, EXP))
+    }
+}
+
+#[cfg(test)]
+mod unsigned_tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_interval_pow() {
+        let a = Refinement::<u16, unsigned::ClosedInterval<2, 4>>::refine(3).unwrap();
+        let b: Refinement<u16, unsigned::ClosedInterval<8, 64>> = a.pow::<3>();
+        assert_eq!(*b, 27);
+    }
+
+    #[test]
+    fn test_bound_pow() {
+        let a = Refinement::<u8, unsigned::LessThan<10>>::refine(9).unwrap();
+        let b: Refinement<u8, unsigned::LessThanEqual<81>> = a.pow::<2>();
+        assert_eq!(*b, 81);
+        // let _ = a.pow::<3>();
+        assert_eq!((*a).pow(2), 81);
+    }
+
+    #[test]
+    fn test_eq_pow() {
+        let a = Refinement::<u32, unsigned::Equals<3>>::refine(3).unwrap();
+        let b: Refinement<u32, unsigned::Equals<81>> = a.pow::<4>();
+        assert_eq!(*b, 81);
+        let c: Refinement<u32, unsigned::Equals<1>> = a.pow::<0>();
+        assert_eq!(*c, 1);
+    }
+}
+
+impl<const VAL: i128, Type: signed::SignedBoundable + Pow> Refinement<Type, signed::Equals<VAL>> {
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(self) -> Refinement<Type, signed::Equals<{ i128::pow(VAL, EXP) }>>
+    where
+        Refinement<Type, signed::Equals<{ i128::pow(VAL, EXP) }>>: Sized,
+    {
+        signed_result(Pow::pow(self.0, EXP))
+    }
+}
+
+impl<const MIN: i128, const MAX: i128, Type: signed::SignedBoundable + Pow>
+    Refinement<Type, signed::OpenInterval<MIN, MAX>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(
+        self,
+    ) -> Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_pow(MIN + 1, MAX - 1, EXP) },
+            { max_pow(MIN + 1, MAX - 1, EXP) },
+        >,
+    >
+    where
+        Refinement<
+            Type,
+            signed::ClosedInterval<
+                { min_pow(MIN + 1, MAX - 1, EXP) },
+                { max_pow(MIN + 1, MAX - 1, EXP) },
+            >,
+        >: Sized,
+    {
+        signed_result(Pow::pow(self.0, EXP))
+    }
+}
+
+impl<const MIN: i128, const MAX: i128, Type: signed::SignedBoundable + Pow>
+    Refinement<Type, signed::ClosedInterval<MIN, MAX>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(
+        self,
+    ) -> Refinement<
+        Type,
+        signed::ClosedInterval<{ min_pow(MIN, MAX, EXP) }, { max_pow(MIN, MAX, EXP) }>,
+    >
+    where
+        Refinement<
+            Type,
+            signed::ClosedInterval<{ min_pow(MIN, MAX, EXP) }, { max_pow(MIN, MAX, EXP) }>,
+        >: Sized,
+    {
+        signed_result(Pow::pow(self.0, EXP))
+    }
+}
+
+impl<const MIN: i128, const MAX: i128, Type: signed::SignedBoundable + Pow>
+    Refinement<Type, signed::OpenClosedInterval<MIN, MAX>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(
+        self,
+    ) -> Refinement<
+        Type,
+        signed::ClosedInterval<{ min_pow(MIN + 1, MAX, EXP) }, { max_pow(MIN + 1, MAX, EXP) }>,
+    >
+    where
+        Refinement<
+            Type,
+            signed::ClosedInterval<{ min_pow(MIN + 1, MAX, EXP) }, { max_pow(MIN + 1, MAX, EXP) }>,
+        >: Sized,
+    {
+        signed_result(Pow::pow(self.0, EXP))
+    }
+}
+
+impl<const MIN: i128, const MAX: i128, Type: signed::SignedBoundable + Pow>
+    Refinement<Type, signed::ClosedOpenInterval<MIN, MAX>>
+{
+    /// Raises the refined value to the power of `EXP`, bounding the result accordingly.
+    pub fn pow<const EXP: u32>(
+        self,
+    ) -> Refinement<
+        Type,
+        signed::ClosedInterval<{ min_pow(MIN, MAX - 1, EXP) }, { max_pow(MIN, MAX - 1, EXP) }>,
+    >
+    where
+        Refinement<
+            Type,
+            signed::ClosedInterval<{ min_pow(MIN, MAX - 1, EXP) }, { max_pow(MIN, MAX - 1, EXP) }>,
+        >: Sized,
+    {
+        signed_result(Pow::pow(self.0, EXP))
+    }
+}
+
+#[cfg(test)]
+mod signed_tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_interval_pow() {
+        let a = Refinement::<i32, signed::ClosedInterval<-3, 2>>::refine(-3).unwrap();
+        let b: Refinement<i32, signed::ClosedInterval<0, 9>> = a.pow::<2>();
+        assert_eq!(*b, 9);
+        let c: Refinement<i32, signed::ClosedInterval<-27, 8>> = a.pow::<3>();
+        assert_eq!(*c, -27);
+        let d = Refinement::<i32, signed::OpenInterval<-5, -1>>::refine(-2).unwrap();
+        let e: Refinement<i32, signed::ClosedInterval<4, 16>> = d.pow::<2>();
+        assert_eq!(*e, 4);
+    }
+
+    #[test]
+    fn test_eq_pow() {
+        let a = Refinement::<i64, signed::Equals<-2>>::refine(-2).unwrap();
+        let b: Refinement<i64, signed::Equals<-8>> = a.pow::<3>();
+        assert_eq!(*b, -8);
+        // let _ = a.pow::<128>();
+    }
+}
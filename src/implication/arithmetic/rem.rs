@@ -0,0 +1,336 @@
+use core::{marker::PhantomData, ops::Rem};
+
+use crate::{
+    boundable::*,
+    implication::{Assert, IsTrue},
+    Predicate, Refinement,
+};
+
+use super::{signed_result, unsigned_result, SignedRange};
+
+struct RemFits<T, A, B>(PhantomData<(T, A, B)>);
+
+impl<T: signed::SignedBoundable, A: SignedRange<T>, B: SignedMinMax<T>> RemFits<T, A, B> {
+    const CHECK: () = assert!(
+        A::RMIN > T::BOUND_MIN || B::UMIN > -1 || B::UMAX < -1,
+        "remainder of the minimum value by -1 overflows"
+    );
+}
+
+fn signed_rem<Type, A, B>(lhs: Refinement<Type, A>, rhs: Refinement<Type, B>) -> Type
+where
+    Type: signed::SignedBoundable + Rem<Output = Type>,
+    A: SignedRange<Type> + Predicate<Type>,
+    B: SignedMinMax<Type>,
+{
+    let () = RemFits::<Type, A, B>::CHECK;
+    lhs.0 % rhs.0
+}
+
+impl<
+        const A: u128,
+        Type: unsigned::UnsignedBoundable + Rem<Output = Type>,
+        B: UnsignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, unsigned::LessThan<A>>
+where
+    Assert<{ B::UMIN > 0 }>: IsTrue,
+    Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(A - 1, B::UMAX - 1) }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(A - 1, B::UMAX - 1) }>>;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        unsigned_result(self.0 % rhs.0)
+    }
+}
+
+impl<
+        const A: u128,
+        Type: unsigned::UnsignedBoundable + Rem<Output = Type>,
+        B: UnsignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, unsigned::LessThanEqual<A>>
+where
+    Assert<{ B::UMIN > 0 }>: IsTrue,
+    Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(A, B::UMAX - 1) }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(A, B::UMAX - 1) }>>;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        unsigned_result(self.0 % rhs.0)
+    }
+}
+
+impl<
+        const VAL: u128,
+        Type: unsigned::UnsignedBoundable + Rem<Output = Type>,
+        B: UnsignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, unsigned::Equals<VAL>>
+where
+    Assert<{ B::UMIN > 0 }>: IsTrue,
+    Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(VAL, B::UMAX - 1) }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(VAL, B::UMAX - 1) }>>;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        unsigned_result(self.0 % rhs.0)
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Rem<Output = Type>,
+        B: UnsignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, unsigned::OpenInterval<MIN, MAX>>
+where
+    Assert<{ B::UMIN > 0 }>: IsTrue,
+    Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(MAX - 1, B::UMAX - 1) }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(MAX - 1, B::UMAX - 1) }>>;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        unsigned_result(self.0 % rhs.0)
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Rem<Output = Type>,
+        B: UnsignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, unsigned::ClosedInterval<MIN, MAX>>
+where
+    Assert<{ B::UMIN > 0 }>: IsTrue,
+    Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(MAX, B::UMAX - 1) }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(MAX, B::UMAX - 1) }>>;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        unsigned_result(self.0 % rhs.0)
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Rem<Output = Type>,
+        B: UnsignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, unsigned::OpenClosedInterval<MIN, MAX>>
+where
+    Assert<{ B::UMIN > 0 }>: IsTrue,
+    Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(MAX, B::UMAX - 1) }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(MAX, B::UMAX - 1) }>>;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        unsigned_result(self.0 % rhs.0)
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Rem<Output = Type>,
+        B: UnsignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, unsigned::ClosedOpenInterval<MIN, MAX>>
+where
+    Assert<{ B::UMIN > 0 }>: IsTrue,
+    Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(MAX - 1, B::UMAX - 1) }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ unsigned_min(MAX - 1, B::UMAX - 1) }>>;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        unsigned_result(self.0 % rhs.0)
+    }
+}
+
+#[cfg(test)]
+mod unsigned_tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_lt_rem_closed_interval() {
+        let a = Refinement::<u8, unsigned::LessThan<100>>::refine(57).unwrap();
+        let b = Refinement::<u8, unsigned::ClosedInterval<1, 10>>::refine(10).unwrap();
+        let c: Refinement<u8, unsigned::LessThanEqual<9>> = a % b;
+        assert_eq!(*c, 7);
+    }
+
+    #[test]
+    fn test_closed_interval_rem_eq() {
+        let a = Refinement::<u8, unsigned::ClosedInterval<5, 8>>::refine(7).unwrap();
+        let b = Refinement::<u8, unsigned::Equals<10>>::refine(10).unwrap();
+        let c: Refinement<u8, unsigned::LessThanEqual<8>> = a % b;
+        assert_eq!(*c, 7);
+        // let _ = a % Refinement::<u8, unsigned::ClosedInterval<0, 10>>::refine(1).unwrap();
+    }
+}
+
+impl<
+        const VAL: i128,
+        Type: signed::SignedBoundable + Rem<Output = Type>,
+        B: SignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, signed::Equals<VAL>>
+where
+    Assert<{ (B::UMIN > 0) | (B::UMAX < 0) }>: IsTrue,
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_rem(VAL, VAL, B::UMIN, B::UMAX) },
+            { max_rem(VAL, VAL, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_rem(VAL, VAL, B::UMIN, B::UMAX) },
+            { max_rem(VAL, VAL, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        signed_result(signed_rem(self, rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Rem<Output = Type>,
+        B: SignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, signed::OpenInterval<MIN, MAX>>
+where
+    Assert<{ (B::UMIN > 0) | (B::UMAX < 0) }>: IsTrue,
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_rem(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+            { max_rem(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_rem(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+            { max_rem(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        signed_result(signed_rem(self, rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Rem<Output = Type>,
+        B: SignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, signed::ClosedInterval<MIN, MAX>>
+where
+    Assert<{ (B::UMIN > 0) | (B::UMAX < 0) }>: IsTrue,
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_rem(MIN, MAX, B::UMIN, B::UMAX) },
+            { max_rem(MIN, MAX, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_rem(MIN, MAX, B::UMIN, B::UMAX) },
+            { max_rem(MIN, MAX, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        signed_result(signed_rem(self, rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Rem<Output = Type>,
+        B: SignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, signed::OpenClosedInterval<MIN, MAX>>
+where
+    Assert<{ (B::UMIN > 0) | (B::UMAX < 0) }>: IsTrue,
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_rem(MIN + 1, MAX, B::UMIN, B::UMAX) },
+            { max_rem(MIN + 1, MAX, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_rem(MIN + 1, MAX, B::UMIN, B::UMAX) },
+            { max_rem(MIN + 1, MAX, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        signed_result(signed_rem(self, rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Rem<Output = Type>,
+        B: SignedMinMax<Type> + Predicate<Type>,
+    > Rem<Refinement<Type, B>> for Refinement<Type, signed::ClosedOpenInterval<MIN, MAX>>
+where
+    Assert<{ (B::UMIN > 0) | (B::UMAX < 0) }>: IsTrue,
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_rem(MIN, MAX - 1, B::UMIN, B::UMAX) },
+            { max_rem(MIN, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_rem(MIN, MAX - 1, B::UMIN, B::UMAX) },
+            { max_rem(MIN, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn rem(self, rhs: Refinement<Type, B>) -> Self::Output {
+        signed_result(signed_rem(self, rhs))
+    }
+}
+
+#[cfg(test)]
+mod signed_tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_closed_interval_rem_closed_interval() {
+        let a = Refinement::<i8, signed::ClosedInterval<-20, 20>>::refine(-17).unwrap();
+        let b = Refinement::<i8, signed::ClosedInterval<3, 5>>::refine(5).unwrap();
+        let c: Refinement<i8, signed::ClosedInterval<-4, 4>> = a % b;
+        assert_eq!(*c, -2);
+    }
+
+    #[test]
+    fn test_open_interval_rem_eq() {
+        let a = Refinement::<i8, signed::OpenInterval<0, 10>>::refine(9).unwrap();
+        let b = Refinement::<i8, signed::Equals<-4>>::refine(-4).unwrap();
+        let c: Refinement<i8, signed::ClosedInterval<0, 3>> = a % b;
+        assert_eq!(*c, 1);
+        // let _ = a % Refinement::<i8, signed::ClosedInterval<-1, 1>>::refine(1).unwrap();
+        // let _ = Refinement::<i8, signed::ClosedInterval<-128, 0>>::refine(-128).unwrap()
+        //     % Refinement::<i8, signed::Equals<-1>>::refine(-1).unwrap();
+    }
+}
@@ -0,0 +1,667 @@
+use core::{
+    marker::PhantomData,
+    ops::{Shl, Shr},
+};
+
+use crate::{boundable::*, Predicate, Refinement};
+
+use super::{signed_result, unsigned_result};
+
+struct ShiftFits<T, B>(PhantomData<(T, B)>);
+
+impl<T: unsigned::UnsignedBoundable, B: UnsignedMax<u32>> ShiftFits<T, B> {
+    const UNSIGNED: () = assert!(
+        B::UMAX < (128 - T::BOUND_MAX.leading_zeros()) as u128,
+        "shift amount may exceed the bit width of the bounded type"
+    );
+}
+
+impl<T: signed::SignedBoundable, B: UnsignedMax<u32>> ShiftFits<T, B> {
+    const SIGNED: () = assert!(
+        B::UMAX < (129 - T::BOUND_MAX.leading_zeros()) as u128,
+        "shift amount may exceed the bit width of the bounded type"
+    );
+}
+
+fn unsigned_shift<T: unsigned::UnsignedBoundable, B: UnsignedMax<u32>>(
+    rhs: Refinement<u32, B>,
+) -> u32 {
+    let () = ShiftFits::<T, B>::UNSIGNED;
+    rhs.0
+}
+
+fn signed_shift<T: signed::SignedBoundable, B: UnsignedMax<u32>>(rhs: Refinement<u32, B>) -> u32 {
+    let () = ShiftFits::<T, B>::SIGNED;
+    rhs.0
+}
+
+impl<
+        const A: u128,
+        Type: unsigned::UnsignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, unsigned::LessThan<A>>
+where
+    Refinement<Type, unsigned::LessThanEqual<{ unsigned_shl(A - 1, B::UMAX) }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ unsigned_shl(A - 1, B::UMAX) }>>;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 << unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const A: u128,
+        Type: unsigned::UnsignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, unsigned::LessThanEqual<A>>
+where
+    Refinement<Type, unsigned::LessThanEqual<{ unsigned_shl(A, B::UMAX) }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ unsigned_shl(A, B::UMAX) }>>;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 << unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const VAL: u128,
+        Type: unsigned::UnsignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, unsigned::Equals<VAL>>
+where
+    Refinement<
+        Type,
+        unsigned::ClosedInterval<{ unsigned_shl(VAL, B::UMIN) }, { unsigned_shl(VAL, B::UMAX) }>,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        unsigned::ClosedInterval<{ unsigned_shl(VAL, B::UMIN) }, { unsigned_shl(VAL, B::UMAX) }>,
+    >;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 << unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, unsigned::OpenInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        unsigned::ClosedInterval<
+            { unsigned_shl(MIN + 1, B::UMIN) },
+            { unsigned_shl(MAX - 1, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        unsigned::ClosedInterval<
+            { unsigned_shl(MIN + 1, B::UMIN) },
+            { unsigned_shl(MAX - 1, B::UMAX) },
+        >,
+    >;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 << unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, unsigned::ClosedInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        unsigned::ClosedInterval<{ unsigned_shl(MIN, B::UMIN) }, { unsigned_shl(MAX, B::UMAX) }>,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        unsigned::ClosedInterval<{ unsigned_shl(MIN, B::UMIN) }, { unsigned_shl(MAX, B::UMAX) }>,
+    >;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 << unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, unsigned::OpenClosedInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        unsigned::ClosedInterval<
+            { unsigned_shl(MIN + 1, B::UMIN) },
+            { unsigned_shl(MAX, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        unsigned::ClosedInterval<
+            { unsigned_shl(MIN + 1, B::UMIN) },
+            { unsigned_shl(MAX, B::UMAX) },
+        >,
+    >;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 << unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, unsigned::ClosedOpenInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        unsigned::ClosedInterval<
+            { unsigned_shl(MIN, B::UMIN) },
+            { unsigned_shl(MAX - 1, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        unsigned::ClosedInterval<
+            { unsigned_shl(MIN, B::UMIN) },
+            { unsigned_shl(MAX - 1, B::UMAX) },
+        >,
+    >;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 << unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const A: u128,
+        Type: unsigned::UnsignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, unsigned::LessThan<A>>
+where
+    Refinement<Type, unsigned::LessThanEqual<{ (A - 1) >> B::UMIN }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ (A - 1) >> B::UMIN }>>;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 >> unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const A: u128,
+        Type: unsigned::UnsignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, unsigned::LessThanEqual<A>>
+where
+    Refinement<Type, unsigned::LessThanEqual<{ A >> B::UMIN }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::LessThanEqual<{ A >> B::UMIN }>>;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 >> unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const A: u128,
+        Type: unsigned::UnsignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, unsigned::GreaterThan<A>>
+where
+    Refinement<Type, unsigned::GreaterThanEqual<{ (A + 1) >> B::UMAX }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::GreaterThanEqual<{ (A + 1) >> B::UMAX }>>;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 >> unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const A: u128,
+        Type: unsigned::UnsignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, unsigned::GreaterThanEqual<A>>
+where
+    Refinement<Type, unsigned::GreaterThanEqual<{ A >> B::UMAX }>>: Sized,
+{
+    type Output = Refinement<Type, unsigned::GreaterThanEqual<{ A >> B::UMAX }>>;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 >> unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const VAL: u128,
+        Type: unsigned::UnsignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, unsigned::Equals<VAL>>
+where
+    Refinement<Type, unsigned::ClosedInterval<{ VAL >> B::UMAX }, { VAL >> B::UMIN }>>: Sized,
+{
+    type Output =
+        Refinement<Type, unsigned::ClosedInterval<{ VAL >> B::UMAX }, { VAL >> B::UMIN }>>;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 >> unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, unsigned::OpenInterval<MIN, MAX>>
+where
+    Refinement<Type, unsigned::ClosedInterval<{ (MIN + 1) >> B::UMAX }, { (MAX - 1) >> B::UMIN }>>:
+        Sized,
+{
+    type Output = Refinement<
+        Type,
+        unsigned::ClosedInterval<{ (MIN + 1) >> B::UMAX }, { (MAX - 1) >> B::UMIN }>,
+    >;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 >> unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, unsigned::ClosedInterval<MIN, MAX>>
+where
+    Refinement<Type, unsigned::ClosedInterval<{ MIN >> B::UMAX }, { MAX >> B::UMIN }>>: Sized,
+{
+    type Output =
+        Refinement<Type, unsigned::ClosedInterval<{ MIN >> B::UMAX }, { MAX >> B::UMIN }>>;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 >> unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, unsigned::OpenClosedInterval<MIN, MAX>>
+where
+    Refinement<Type, unsigned::ClosedInterval<{ (MIN + 1) >> B::UMAX }, { MAX >> B::UMIN }>>: Sized,
+{
+    type Output =
+        Refinement<Type, unsigned::ClosedInterval<{ (MIN + 1) >> B::UMAX }, { MAX >> B::UMIN }>>;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 >> unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: u128,
+        const MAX: u128,
+        Type: unsigned::UnsignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, unsigned::ClosedOpenInterval<MIN, MAX>>
+where
+    Refinement<Type, unsigned::ClosedInterval<{ MIN >> B::UMAX }, { (MAX - 1) >> B::UMIN }>>: Sized,
+{
+    type Output =
+        Refinement<Type, unsigned::ClosedInterval<{ MIN >> B::UMAX }, { (MAX - 1) >> B::UMIN }>>;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        unsigned_result(self.0 >> unsigned_shift::<Type, B>(rhs))
+    }
+}
+
+#[cfg(test)]
+mod unsigned_tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_shl() {
+        let s = Refinement::<u32, unsigned::ClosedInterval<1, 3>>::refine(3).unwrap();
+        let a = Refinement::<u16, unsigned::LessThan<16>>::refine(15).unwrap();
+        let b: Refinement<u16, unsigned::LessThanEqual<120>> = a << s;
+        assert_eq!(*b, 120);
+        let c = Refinement::<u8, unsigned::GreaterThan<4>>::refine(200).unwrap();
+        let d: Refinement<u8, unsigned::GreaterThanEqual<0>> = c >> s;
+        assert_eq!(*d, 25);
+    }
+
+    #[test]
+    fn test_shr() {
+        let s = Refinement::<u32, unsigned::ClosedInterval<1, 3>>::refine(3).unwrap();
+        let a = Refinement::<u8, unsigned::ClosedInterval<16, 64>>::refine(40).unwrap();
+        let b: Refinement<u8, unsigned::ClosedInterval<2, 32>> = a >> s;
+        assert_eq!(*b, 5);
+        // let _ = Refinement::<u128, unsigned::Equals<1>>::refine(1).unwrap()
+        //     << Refinement::<u32, unsigned::Equals<128>>::refine(128).unwrap();
+    }
+}
+
+impl<
+        const VAL: i128,
+        Type: signed::SignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, signed::Equals<VAL>>
+where
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shl(VAL, VAL, B::UMIN, B::UMAX) },
+            { max_shl(VAL, VAL, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shl(VAL, VAL, B::UMIN, B::UMAX) },
+            { max_shl(VAL, VAL, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        signed_result(self.0 << signed_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, signed::OpenInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shl(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+            { max_shl(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shl(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+            { max_shl(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        signed_result(self.0 << signed_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, signed::ClosedInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shl(MIN, MAX, B::UMIN, B::UMAX) },
+            { max_shl(MIN, MAX, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shl(MIN, MAX, B::UMIN, B::UMAX) },
+            { max_shl(MIN, MAX, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        signed_result(self.0 << signed_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, signed::OpenClosedInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shl(MIN + 1, MAX, B::UMIN, B::UMAX) },
+            { max_shl(MIN + 1, MAX, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shl(MIN + 1, MAX, B::UMIN, B::UMAX) },
+            { max_shl(MIN + 1, MAX, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        signed_result(self.0 << signed_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Shl<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shl<Refinement<u32, B>> for Refinement<Type, signed::ClosedOpenInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shl(MIN, MAX - 1, B::UMIN, B::UMAX) },
+            { max_shl(MIN, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shl(MIN, MAX - 1, B::UMIN, B::UMAX) },
+            { max_shl(MIN, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn shl(self, rhs: Refinement<u32, B>) -> Self::Output {
+        signed_result(self.0 << signed_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const VAL: i128,
+        Type: signed::SignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, signed::Equals<VAL>>
+where
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shr(VAL, VAL, B::UMIN, B::UMAX) },
+            { max_shr(VAL, VAL, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shr(VAL, VAL, B::UMIN, B::UMAX) },
+            { max_shr(VAL, VAL, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        signed_result(self.0 >> signed_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, signed::OpenInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shr(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+            { max_shr(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shr(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+            { max_shr(MIN + 1, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        signed_result(self.0 >> signed_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, signed::ClosedInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shr(MIN, MAX, B::UMIN, B::UMAX) },
+            { max_shr(MIN, MAX, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shr(MIN, MAX, B::UMIN, B::UMAX) },
+            { max_shr(MIN, MAX, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        signed_result(self.0 >> signed_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, signed::OpenClosedInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shr(MIN + 1, MAX, B::UMIN, B::UMAX) },
+            { max_shr(MIN + 1, MAX, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shr(MIN + 1, MAX, B::UMIN, B::UMAX) },
+            { max_shr(MIN + 1, MAX, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        signed_result(self.0 >> signed_shift::<Type, B>(rhs))
+    }
+}
+
+impl<
+        const MIN: i128,
+        const MAX: i128,
+        Type: signed::SignedBoundable + Shr<u32, Output = Type>,
+        B: UnsignedMinMax<u32> + Predicate<u32>,
+    > Shr<Refinement<u32, B>> for Refinement<Type, signed::ClosedOpenInterval<MIN, MAX>>
+where
+    Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shr(MIN, MAX - 1, B::UMIN, B::UMAX) },
+            { max_shr(MIN, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >: Sized,
+{
+    type Output = Refinement<
+        Type,
+        signed::ClosedInterval<
+            { min_shr(MIN, MAX - 1, B::UMIN, B::UMAX) },
+            { max_shr(MIN, MAX - 1, B::UMIN, B::UMAX) },
+        >,
+    >;
+
+    fn shr(self, rhs: Refinement<u32, B>) -> Self::Output {
+        signed_result(self.0 >> signed_shift::<Type, B>(rhs))
+    }
+}
+
+#[cfg(test)]
+mod signed_tests {
+    use super::*;
+    use crate::prelude::*;
+
+    #[test]
+    fn test_shl() {
+        let s = Refinement::<u32, unsigned::ClosedInterval<1, 3>>::refine(2).unwrap();
+        let a = Refinement::<i16, signed::ClosedInterval<-4, 3>>::refine(-3).unwrap();
+        let b: Refinement<i16, signed::ClosedInterval<-32, 24>> = a << s;
+        assert_eq!(*b, -12);
+    }
+
+    #[test]
+    fn test_shr() {
+        let s = Refinement::<u32, unsigned::ClosedInterval<1, 3>>::refine(2).unwrap();
+        let a = Refinement::<i16, signed::ClosedInterval<-64, 32>>::refine(-64).unwrap();
+        let b: Refinement<i16, signed::ClosedInterval<-32, 16>> = a >> s;
+        assert_eq!(*b, -16);
+    }
+}
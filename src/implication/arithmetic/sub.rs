@@ -1,11 +1,11 @@
-use core::{marker::PhantomData, ops::Sub};
+use core::ops::Sub;
 
 use crate::{boundable::*, Predicate, Refinement};
 
-use super::*;
+use super::{signed_result, unsigned_result};
 
 impl<
-        const MIN: usize,
+        const MIN: u128,
         Type: unsigned::UnsignedBoundable + Sub<Output = Type>,
         B: UnsignedMax<Type> + Predicate<Type>,
     > Sub<Refinement<Type, B>> for Refinement<Type, unsigned::GreaterThan<MIN>>
@@ -15,12 +15,12 @@ where
     type Output = Refinement<Type, unsigned::GreaterThan<{ MIN - B::UMAX }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        unsigned_result(self.0 - rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
+        const MIN: u128,
         Type: unsigned::UnsignedBoundable + Sub<Output = Type>,
         B: UnsignedMax<Type> + Predicate<Type>,
     > Sub<Refinement<Type, B>> for Refinement<Type, unsigned::GreaterThanEqual<MIN>>
@@ -30,12 +30,12 @@ where
     type Output = Refinement<Type, unsigned::GreaterThanEqual<{ MIN - B::UMAX }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        unsigned_result(self.0 - rhs.0)
     }
 }
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Sub<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Sub<Refinement<Type, B>> for Refinement<Type, unsigned::OpenInterval<MIN, MAX>>
@@ -45,13 +45,13 @@ where
     type Output = Refinement<Type, unsigned::OpenInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        unsigned_result(self.0 - rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Sub<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Sub<Refinement<Type, B>> for Refinement<Type, unsigned::ClosedInterval<MIN, MAX>>
@@ -61,13 +61,13 @@ where
     type Output = Refinement<Type, unsigned::ClosedInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        unsigned_result(self.0 - rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Sub<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Sub<Refinement<Type, B>> for Refinement<Type, unsigned::OpenClosedInterval<MIN, MAX>>
@@ -78,13 +78,13 @@ where
         Refinement<Type, unsigned::OpenClosedInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        unsigned_result(self.0 - rhs.0)
     }
 }
 
 impl<
-        const MIN: usize,
-        const MAX: usize,
+        const MIN: u128,
+        const MAX: u128,
         Type: unsigned::UnsignedBoundable + Sub<Output = Type>,
         B: UnsignedMinMax<Type> + Predicate<Type>,
     > Sub<Refinement<Type, B>> for Refinement<Type, unsigned::ClosedOpenInterval<MIN, MAX>>
@@ -95,7 +95,7 @@ where
         Refinement<Type, unsigned::ClosedOpenInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        unsigned_result(self.0 - rhs.0)
     }
 }
 
@@ -186,8 +186,8 @@ mod unsigned_tests {
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Sub<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Sub<Refinement<Type, B>> for Refinement<Type, signed::OpenInterval<MIN, MAX>>
@@ -197,13 +197,13 @@ where
     type Output = Refinement<Type, signed::OpenInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        signed_result(self.0 - rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Sub<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Sub<Refinement<Type, B>> for Refinement<Type, signed::ClosedInterval<MIN, MAX>>
@@ -213,13 +213,13 @@ where
     type Output = Refinement<Type, signed::ClosedInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        signed_result(self.0 - rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Sub<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Sub<Refinement<Type, B>> for Refinement<Type, signed::OpenClosedInterval<MIN, MAX>>
@@ -230,13 +230,13 @@ where
         Refinement<Type, signed::OpenClosedInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        signed_result(self.0 - rhs.0)
     }
 }
 
 impl<
-        const MIN: isize,
-        const MAX: isize,
+        const MIN: i128,
+        const MAX: i128,
         Type: signed::SignedBoundable + Sub<Output = Type>,
         B: SignedMinMax<Type> + Predicate<Type>,
     > Sub<Refinement<Type, B>> for Refinement<Type, signed::ClosedOpenInterval<MIN, MAX>>
@@ -247,7 +247,7 @@ where
         Refinement<Type, signed::ClosedOpenInterval<{ MIN - B::UMAX }, { MAX - B::UMIN }>>;
 
     fn sub(self, rhs: Refinement<Type, B>) -> Self::Output {
-        Refinement(self.0 - rhs.0, PhantomData)
+        signed_result(self.0 - rhs.0)
     }
 }
 
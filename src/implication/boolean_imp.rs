@@ -1,15 +1,213 @@
-use core::marker::PhantomData;
-
 use crate::boolean::*;
 
-use super::Implies;
+use super::{index::*, Implies};
 
-impl<F1, T1, F2, T2> Implies<And<T1, T2>> for And<F1, F2>
+impl<F, T1, T2, I1, I2> Implies<And<T1, T2>, AndIntro<I1, I2>> for F
 where
-    F1: Implies<T1>,
-    F2: Implies<T2>,
+    F: Clone + Implies<T1, I1> + Implies<T2, I2>,
 {
+    const CHECK: () = {
+        let () = <F as Implies<T1, I1>>::CHECK;
+        let () = <F as Implies<T2, I2>>::CHECK;
+    };
+
     fn imply(self) -> And<T1, T2> {
-        And::<T1, T2>(PhantomData, PhantomData)
+        And(
+            <F as Implies<T1, I1>>::imply(self.clone()),
+            <F as Implies<T2, I2>>::imply(self),
+        )
+    }
+}
+
+impl<A, B, T, I> Implies<T, AndLeft<I>> for And<A, B>
+where
+    A: Implies<T, I>,
+{
+    const CHECK: () = A::CHECK;
+
+    fn imply(self) -> T {
+        self.0.imply()
+    }
+}
+
+impl<A, B, T, I> Implies<T, AndRight<I>> for And<A, B>
+where
+    B: Implies<T, I>,
+{
+    const CHECK: () = B::CHECK;
+
+    fn imply(self) -> T {
+        self.1.imply()
+    }
+}
+
+impl<F, A, B: Default, I> Implies<Or<A, B>, OrLeft<I>> for F
+where
+    F: Implies<A, I>,
+{
+    const CHECK: () = F::CHECK;
+
+    fn imply(self) -> Or<A, B> {
+        Or(self.imply(), B::default())
+    }
+}
+
+impl<F, A: Default, B, I> Implies<Or<A, B>, OrRight<I>> for F
+where
+    F: Implies<B, I>,
+{
+    const CHECK: () = F::CHECK;
+
+    fn imply(self) -> Or<A, B> {
+        Or(A::default(), self.imply())
+    }
+}
+
+impl<A, B, T, I1, I2> Implies<T, OrCases<I1, I2>> for Or<A, B>
+where
+    A: Implies<T, I1>,
+    B: Implies<T, I2>,
+{
+    const CHECK: () = {
+        let () = A::CHECK;
+        let () = B::CHECK;
+    };
+
+    fn imply(self) -> T {
+        self.0.imply()
+    }
+}
+
+impl<A, B, T, I> Implies<T, XorOr<I>> for Xor<A, B>
+where
+    Or<A, B>: Implies<T, I>,
+{
+    const CHECK: () = <Or<A, B> as Implies<T, I>>::CHECK;
+
+    fn imply(self) -> T {
+        Or(self.0, self.1).imply()
+    }
+}
+
+impl<A: Default, B, I> Implies<Not<A>, Contrapositive<I>> for Not<B>
+where
+    A: Implies<B, I>,
+{
+    const CHECK: () = A::CHECK;
+
+    fn imply(self) -> Not<A> {
+        Not(A::default())
+    }
+}
+
+impl<A, T, I> Implies<T, DoubleNegation<I>> for Not<Not<A>>
+where
+    A: Implies<T, I>,
+{
+    const CHECK: () = A::CHECK;
+
+    fn imply(self) -> T {
+        self.0 .0.imply()
+    }
+}
+
+macro_rules! variadic_implies {
+    ($($f:ident $t:ident $x:ident $i:tt),+) => {
+        impl<$($f, $t, $x),+> Implies<All<($($t,)+)>, Each<($($x,)+)>> for All<($($f,)+)>
+        where
+            $($f: Implies<$t, $x>),+
+        {
+            const CHECK: () = {
+                $(let () = $f::CHECK;)+
+            };
+
+            fn imply(self) -> All<($($t,)+)> {
+                All(($(self.0.$i.imply(),)+))
+            }
+        }
+
+        impl<$($f, $t, $x),+> Implies<Any<($($t,)+)>, Each<($($x,)+)>> for Any<($($f,)+)>
+        where
+            $($f: Implies<$t, $x>),+
+        {
+            const CHECK: () = {
+                $(let () = $f::CHECK;)+
+            };
+
+            fn imply(self) -> Any<($($t,)+)> {
+                Any(($(self.0.$i.imply(),)+))
+            }
+        }
+    };
+}
+
+variadic_implies!(F1 T1 I1 0);
+variadic_implies!(F1 T1 I1 0, F2 T2 I2 1);
+variadic_implies!(F1 T1 I1 0, F2 T2 I2 1, F3 T3 I3 2);
+variadic_implies!(F1 T1 I1 0, F2 T2 I2 1, F3 T3 I3 2, F4 T4 I4 3);
+variadic_implies!(F1 T1 I1 0, F2 T2 I2 1, F3 T3 I3 2, F4 T4 I4 3, F5 T5 I5 4);
+variadic_implies!(F1 T1 I1 0, F2 T2 I2 1, F3 T3 I3 2, F4 T4 I4 3, F5 T5 I5 4, F6 T6 I6 5);
+variadic_implies!(F1 T1 I1 0, F2 T2 I2 1, F3 T3 I3 2, F4 T4 I4 3, F5 T5 I5 4, F6 T6 I6 5, F7 T7 I7 6);
+variadic_implies!(F1 T1 I1 0, F2 T2 I2 1, F3 T3 I3 2, F4 T4 I4 3, F5 T5 I5 4, F6 T6 I6 5, F7 T7 I7 6, F8 T8 I8 7);
+
+#[cfg(test)]
+mod tests {
+    use crate::boolean::*;
+    use crate::boundable::unsigned::*;
+    use crate::implication::index::*;
+    use crate::*;
+
+    #[test]
+    fn test_and_elimination() {
+        let a = Refinement::<u8, ClosedInterval<10, 20>>::refine(12).unwrap();
+        let _: Refinement<u8, GTE<5>> = a.imply();
+        let _: Refinement<u8, LT<25>> = a.imply();
+    }
+
+    #[test]
+    fn test_and_introduction() {
+        let a = Refinement::<u8, And<GT<10>, LT<20>>>::refine(12).unwrap();
+        let _: Refinement<u8, And<GTE<5>, LTE<20>>> = a.imply();
+        let b = Refinement::<u8, Equals<12>>::refine(12).unwrap();
+        let _: Refinement<u8, And<Equals<12>, Equals<12>>> = b.imply();
+    }
+
+    #[test]
+    fn test_and_pairwise() {
+        let a = Refinement::<u8, And<GT<5>, GT<3>>>::refine(9).unwrap();
+        let b: Refinement<u8, And<GT<1>, GT<2>>> =
+            Implies::<_, AndIntro<AndLeft<_>, AndRight<_>>>::imply(a);
+        assert_eq!(*b, 9);
+    }
+
+    #[test]
+    fn test_or_introduction_and_cases() {
+        let a = Refinement::<u8, LT<5>>::refine(3).unwrap();
+        let b: Refinement<u8, Or<LT<10>, GT<20>>> = a.imply();
+        let _: Refinement<u8, Or<LT<15>, GT<20>>> = b.imply();
+        let c = Refinement::<u8, Or<LT<5>, ClosedInterval<10, 15>>>::refine(12).unwrap();
+        let _: Refinement<u8, LT<20>> = c.imply();
+        let d = Refinement::<u8, Xor<LT<5>, LT<10>>>::refine(7).unwrap();
+        let _: Refinement<u8, LTE<10>> = d.imply();
+    }
+
+    #[test]
+    fn test_negation() {
+        let a = Refinement::<u8, Not<LT<10>>>::refine(12).unwrap();
+        let _: Refinement<u8, Not<LT<5>>> = a.imply();
+        let b = Refinement::<u8, Not<Not<LT<10>>>>::refine(7).unwrap();
+        let _: Refinement<u8, LT<20>> = b.imply();
+    }
+
+    #[test]
+    fn test_all_implication() {
+        let a = Refinement::<u8, boolean::All<(GT<10>, LT<20>, LTE<15>)>>::refine(12).unwrap();
+        let _: Refinement<u8, boolean::All<(GTE<10>, LT<25>, LT<16>)>> = a.imply();
+    }
+
+    #[test]
+    fn test_predicate_macro_implication() {
+        let a = Refinement::<u8, predicate!(GT<10> & LT<20>)>::refine(12).unwrap();
+        let _: Refinement<u8, predicate!(GTE<5> & LTE<20>)> = a.imply();
     }
 }
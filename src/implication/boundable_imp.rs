@@ -1,17 +1,17 @@
-use crate::boundable::*;
+use crate::{boolean::Not, boundable::*};
 
 use super::{Assert, Implies, IsTrue};
 
-impl<const F: isize, const T: isize> Implies<signed::GreaterThan<T>> for signed::GreaterThan<F>
+impl<const F: i128, const T: i128> Implies<signed::GreaterThan<T>> for signed::GreaterThan<F>
 where
-    Assert<{ F > T }>: IsTrue,
+    Assert<{ F >= T }>: IsTrue,
 {
     fn imply(self) -> signed::GreaterThan<T> {
         signed::GreaterThan::<T>
     }
 }
 
-impl<const F: isize, const T: isize> Implies<signed::GreaterThan<T>> for signed::GreaterThanEqual<F>
+impl<const F: i128, const T: i128> Implies<signed::GreaterThan<T>> for signed::GreaterThanEqual<F>
 where
     Assert<{ F > T }>: IsTrue,
 {
@@ -20,7 +20,7 @@ where
     }
 }
 
-impl<const F: isize, const T: isize> Implies<signed::GreaterThanEqual<T>> for signed::GreaterThan<F>
+impl<const F: i128, const T: i128> Implies<signed::GreaterThanEqual<T>> for signed::GreaterThan<F>
 where
     Assert<{ F >= T }>: IsTrue,
 {
@@ -29,7 +29,7 @@ where
     }
 }
 
-impl<const F: isize, const T: isize> Implies<signed::GreaterThanEqual<T>>
+impl<const F: i128, const T: i128> Implies<signed::GreaterThanEqual<T>>
     for signed::GreaterThanEqual<F>
 where
     Assert<{ F >= T }>: IsTrue,
@@ -39,16 +39,16 @@ where
     }
 }
 
-impl<const F: isize, const T: isize> Implies<signed::LessThan<T>> for signed::LessThan<F>
+impl<const F: i128, const T: i128> Implies<signed::LessThan<T>> for signed::LessThan<F>
 where
-    Assert<{ F < T }>: IsTrue,
+    Assert<{ F <= T }>: IsTrue,
 {
     fn imply(self) -> signed::LessThan<T> {
         signed::LessThan::<T>
     }
 }
 
-impl<const F: isize, const T: isize> Implies<signed::LessThanEqual<T>> for signed::LessThan<F>
+impl<const F: i128, const T: i128> Implies<signed::LessThanEqual<T>> for signed::LessThan<F>
 where
     Assert<{ F <= T }>: IsTrue,
 {
@@ -57,7 +57,7 @@ where
     }
 }
 
-impl<const F: isize, const T: isize> Implies<signed::LessThanEqual<T>> for signed::LessThanEqual<F>
+impl<const F: i128, const T: i128> Implies<signed::LessThanEqual<T>> for signed::LessThanEqual<F>
 where
     Assert<{ F <= T }>: IsTrue,
 {
@@ -66,7 +66,7 @@ where
     }
 }
 
-impl<const F: isize, const T: isize> Implies<signed::LessThan<T>> for signed::LessThanEqual<F>
+impl<const F: i128, const T: i128> Implies<signed::LessThan<T>> for signed::LessThanEqual<F>
 where
     Assert<{ F < T }>: IsTrue,
 {
@@ -75,7 +75,7 @@ where
     }
 }
 
-impl<const VAL: isize, const MIN: isize> Implies<signed::GreaterThan<MIN>> for signed::Equals<VAL>
+impl<const VAL: i128, const MIN: i128> Implies<signed::GreaterThan<MIN>> for signed::Equals<VAL>
 where
     Assert<{ VAL > MIN }>: IsTrue,
 {
@@ -84,7 +84,7 @@ where
     }
 }
 
-impl<const VAL: isize, const MIN: isize> Implies<signed::GreaterThanEqual<MIN>>
+impl<const VAL: i128, const MIN: i128> Implies<signed::GreaterThanEqual<MIN>>
     for signed::Equals<VAL>
 where
     Assert<{ VAL >= MIN }>: IsTrue,
@@ -94,7 +94,7 @@ where
     }
 }
 
-impl<const VAL: isize, const MAX: isize> Implies<signed::LessThan<MAX>> for signed::Equals<VAL>
+impl<const VAL: i128, const MAX: i128> Implies<signed::LessThan<MAX>> for signed::Equals<VAL>
 where
     Assert<{ VAL < MAX }>: IsTrue,
 {
@@ -103,7 +103,7 @@ where
     }
 }
 
-impl<const VAL: isize, const MAX: isize> Implies<signed::LessThanEqual<MAX>> for signed::Equals<VAL>
+impl<const VAL: i128, const MAX: i128> Implies<signed::LessThanEqual<MAX>> for signed::Equals<VAL>
 where
     Assert<{ VAL <= MAX }>: IsTrue,
 {
@@ -112,16 +112,16 @@ where
     }
 }
 
-impl<const F: usize, const T: usize> Implies<unsigned::GreaterThan<T>> for unsigned::GreaterThan<F>
+impl<const F: u128, const T: u128> Implies<unsigned::GreaterThan<T>> for unsigned::GreaterThan<F>
 where
-    Assert<{ F > T }>: IsTrue,
+    Assert<{ F >= T }>: IsTrue,
 {
     fn imply(self) -> unsigned::GreaterThan<T> {
         unsigned::GreaterThan::<T>
     }
 }
 
-impl<const F: usize, const T: usize> Implies<unsigned::GreaterThanEqual<T>>
+impl<const F: u128, const T: u128> Implies<unsigned::GreaterThanEqual<T>>
     for unsigned::GreaterThan<F>
 where
     Assert<{ F >= T }>: IsTrue,
@@ -131,7 +131,7 @@ where
     }
 }
 
-impl<const F: usize, const T: usize> Implies<unsigned::GreaterThanEqual<T>>
+impl<const F: u128, const T: u128> Implies<unsigned::GreaterThanEqual<T>>
     for unsigned::GreaterThanEqual<F>
 where
     Assert<{ F >= T }>: IsTrue,
@@ -141,7 +141,7 @@ where
     }
 }
 
-impl<const F: usize, const T: usize> Implies<unsigned::GreaterThan<T>>
+impl<const F: u128, const T: u128> Implies<unsigned::GreaterThan<T>>
     for unsigned::GreaterThanEqual<F>
 where
     Assert<{ F > T }>: IsTrue,
@@ -151,16 +151,16 @@ where
     }
 }
 
-impl<const F: usize, const T: usize> Implies<unsigned::LessThan<T>> for unsigned::LessThan<F>
+impl<const F: u128, const T: u128> Implies<unsigned::LessThan<T>> for unsigned::LessThan<F>
 where
-    Assert<{ F < T }>: IsTrue,
+    Assert<{ F <= T }>: IsTrue,
 {
     fn imply(self) -> unsigned::LessThan<T> {
         unsigned::LessThan::<T>
     }
 }
 
-impl<const F: usize, const T: usize> Implies<unsigned::LessThanEqual<T>> for unsigned::LessThan<F>
+impl<const F: u128, const T: u128> Implies<unsigned::LessThanEqual<T>> for unsigned::LessThan<F>
 where
     Assert<{ F <= T }>: IsTrue,
 {
@@ -169,7 +169,7 @@ where
     }
 }
 
-impl<const F: usize, const T: usize> Implies<unsigned::LessThanEqual<T>>
+impl<const F: u128, const T: u128> Implies<unsigned::LessThanEqual<T>>
     for unsigned::LessThanEqual<F>
 where
     Assert<{ F <= T }>: IsTrue,
@@ -179,7 +179,7 @@ where
     }
 }
 
-impl<const F: usize, const T: usize> Implies<unsigned::LessThan<T>> for unsigned::LessThanEqual<F>
+impl<const F: u128, const T: u128> Implies<unsigned::LessThan<T>> for unsigned::LessThanEqual<F>
 where
     Assert<{ F < T }>: IsTrue,
 {
@@ -188,8 +188,7 @@ where
     }
 }
 
-impl<const VAL: usize, const MIN: usize> Implies<unsigned::GreaterThan<MIN>>
-    for unsigned::Equals<VAL>
+impl<const VAL: u128, const MIN: u128> Implies<unsigned::GreaterThan<MIN>> for unsigned::Equals<VAL>
 where
     Assert<{ VAL > MIN }>: IsTrue,
 {
@@ -198,7 +197,7 @@ where
     }
 }
 
-impl<const VAL: usize, const MIN: usize> Implies<unsigned::GreaterThanEqual<MIN>>
+impl<const VAL: u128, const MIN: u128> Implies<unsigned::GreaterThanEqual<MIN>>
     for unsigned::Equals<VAL>
 where
     Assert<{ VAL >= MIN }>: IsTrue,
@@ -208,7 +207,7 @@ where
     }
 }
 
-impl<const VAL: usize, const MAX: usize> Implies<unsigned::LessThan<MAX>> for unsigned::Equals<VAL>
+impl<const VAL: u128, const MAX: u128> Implies<unsigned::LessThan<MAX>> for unsigned::Equals<VAL>
 where
     Assert<{ VAL < MAX }>: IsTrue,
 {
@@ -217,7 +216,7 @@ where
     }
 }
 
-impl<const VAL: usize, const MAX: usize> Implies<unsigned::LessThanEqual<MAX>>
+impl<const VAL: u128, const MAX: u128> Implies<unsigned::LessThanEqual<MAX>>
     for unsigned::Equals<VAL>
 where
     Assert<{ VAL <= MAX }>: IsTrue,
@@ -227,6 +226,582 @@ where
     }
 }
 
+impl<const VAL: i128, const DIV: i128, const MOD: i128> Implies<signed::Modulo<DIV, MOD>>
+    for signed::Equals<VAL>
+where
+    Assert<{ VAL % DIV == MOD }>: IsTrue,
+{
+    fn imply(self) -> signed::Modulo<DIV, MOD> {
+        signed::Modulo::<DIV, MOD>
+    }
+}
+
+impl<const VAL: i128, const DIV: i128, const MOD: i128> Implies<Not<signed::Modulo<DIV, MOD>>>
+    for signed::Equals<VAL>
+where
+    Assert<{ VAL % DIV != MOD }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::Modulo<DIV, MOD>> {
+        Not::new(signed::Modulo::<DIV, MOD>)
+    }
+}
+
+impl<const VAL: i128, const OTHER: i128> Implies<Not<signed::Equals<OTHER>>> for signed::Equals<VAL>
+where
+    Assert<{ VAL != OTHER }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::Equals<OTHER>> {
+        Not::new(signed::Equals::<OTHER>)
+    }
+}
+
+impl<const F_DIV: i128, const F_MOD: i128, const DIV: i128, const MOD: i128>
+    Implies<signed::Modulo<DIV, MOD>> for signed::Modulo<F_DIV, F_MOD>
+where
+    Assert<{ (F_DIV % DIV == 0) & (F_MOD % DIV == MOD) }>: IsTrue,
+{
+    fn imply(self) -> signed::Modulo<DIV, MOD> {
+        signed::Modulo::<DIV, MOD>
+    }
+}
+
+impl<const F_DIV: i128, const F_MOD: i128, const DIV: i128, const MOD: i128>
+    Implies<Not<signed::Modulo<DIV, MOD>>> for signed::Modulo<F_DIV, F_MOD>
+where
+    Assert<{ (F_DIV % DIV == 0) & (F_MOD % DIV != MOD) }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::Modulo<DIV, MOD>> {
+        Not::new(signed::Modulo::<DIV, MOD>)
+    }
+}
+
+impl<const VAL: i128, const MIN: i128> Implies<Not<signed::GreaterThan<MIN>>>
+    for signed::Equals<VAL>
+where
+    Assert<{ VAL <= MIN }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::GreaterThan<MIN>> {
+        Not::new(signed::GreaterThan::<MIN>)
+    }
+}
+
+impl<const VAL: i128, const MIN: i128> Implies<Not<signed::GreaterThanEqual<MIN>>>
+    for signed::Equals<VAL>
+where
+    Assert<{ VAL < MIN }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::GreaterThanEqual<MIN>> {
+        Not::new(signed::GreaterThanEqual::<MIN>)
+    }
+}
+
+impl<const VAL: i128, const MAX: i128> Implies<Not<signed::LessThan<MAX>>> for signed::Equals<VAL>
+where
+    Assert<{ VAL >= MAX }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::LessThan<MAX>> {
+        Not::new(signed::LessThan::<MAX>)
+    }
+}
+
+impl<const VAL: i128, const MAX: i128> Implies<Not<signed::LessThanEqual<MAX>>>
+    for signed::Equals<VAL>
+where
+    Assert<{ VAL > MAX }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::LessThanEqual<MAX>> {
+        Not::new(signed::LessThanEqual::<MAX>)
+    }
+}
+
+impl<const MIN: i128, const VAL: i128> Implies<Not<signed::Equals<VAL>>>
+    for signed::GreaterThan<MIN>
+where
+    Assert<{ VAL <= MIN }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::Equals<VAL>> {
+        Not::new(signed::Equals::<VAL>)
+    }
+}
+
+impl<const MIN: i128, const VAL: i128> Implies<Not<signed::Equals<VAL>>>
+    for signed::GreaterThanEqual<MIN>
+where
+    Assert<{ VAL < MIN }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::Equals<VAL>> {
+        Not::new(signed::Equals::<VAL>)
+    }
+}
+
+impl<const MAX: i128, const VAL: i128> Implies<Not<signed::Equals<VAL>>> for signed::LessThan<MAX>
+where
+    Assert<{ VAL >= MAX }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::Equals<VAL>> {
+        Not::new(signed::Equals::<VAL>)
+    }
+}
+
+impl<const MAX: i128, const VAL: i128> Implies<Not<signed::Equals<VAL>>>
+    for signed::LessThanEqual<MAX>
+where
+    Assert<{ VAL > MAX }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::Equals<VAL>> {
+        Not::new(signed::Equals::<VAL>)
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<Not<signed::LessThan<T>>> for signed::GreaterThan<F>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::LessThan<T>> {
+        Not::new(signed::LessThan::<T>)
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<Not<signed::LessThanEqual<T>>> for signed::GreaterThan<F>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::LessThanEqual<T>> {
+        Not::new(signed::LessThanEqual::<T>)
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<Not<signed::LessThan<T>>> for signed::GreaterThanEqual<F>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::LessThan<T>> {
+        Not::new(signed::LessThan::<T>)
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<Not<signed::LessThanEqual<T>>>
+    for signed::GreaterThanEqual<F>
+where
+    Assert<{ F > T }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::LessThanEqual<T>> {
+        Not::new(signed::LessThanEqual::<T>)
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<Not<signed::GreaterThan<T>>> for signed::LessThan<F>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::GreaterThan<T>> {
+        Not::new(signed::GreaterThan::<T>)
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<Not<signed::GreaterThanEqual<T>>> for signed::LessThan<F>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::GreaterThanEqual<T>> {
+        Not::new(signed::GreaterThanEqual::<T>)
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<Not<signed::GreaterThan<T>>> for signed::LessThanEqual<F>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::GreaterThan<T>> {
+        Not::new(signed::GreaterThan::<T>)
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<Not<signed::GreaterThanEqual<T>>>
+    for signed::LessThanEqual<F>
+where
+    Assert<{ F < T }>: IsTrue,
+{
+    fn imply(self) -> Not<signed::GreaterThanEqual<T>> {
+        Not::new(signed::GreaterThanEqual::<T>)
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<signed::GreaterThan<T>> for Not<signed::LessThanEqual<F>>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> signed::GreaterThan<T> {
+        signed::GreaterThan::<T>
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<signed::GreaterThanEqual<T>>
+    for Not<signed::LessThanEqual<F>>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> signed::GreaterThanEqual<T> {
+        signed::GreaterThanEqual::<T>
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<signed::GreaterThan<T>> for Not<signed::LessThan<F>>
+where
+    Assert<{ F > T }>: IsTrue,
+{
+    fn imply(self) -> signed::GreaterThan<T> {
+        signed::GreaterThan::<T>
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<signed::GreaterThanEqual<T>> for Not<signed::LessThan<F>>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> signed::GreaterThanEqual<T> {
+        signed::GreaterThanEqual::<T>
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<signed::LessThan<T>> for Not<signed::GreaterThanEqual<F>>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> signed::LessThan<T> {
+        signed::LessThan::<T>
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<signed::LessThanEqual<T>>
+    for Not<signed::GreaterThanEqual<F>>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> signed::LessThanEqual<T> {
+        signed::LessThanEqual::<T>
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<signed::LessThan<T>> for Not<signed::GreaterThan<F>>
+where
+    Assert<{ F < T }>: IsTrue,
+{
+    fn imply(self) -> signed::LessThan<T> {
+        signed::LessThan::<T>
+    }
+}
+
+impl<const F: i128, const T: i128> Implies<signed::LessThanEqual<T>> for Not<signed::GreaterThan<F>>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> signed::LessThanEqual<T> {
+        signed::LessThanEqual::<T>
+    }
+}
+
+impl<const VAL: u128, const DIV: u128, const MOD: u128> Implies<unsigned::Modulo<DIV, MOD>>
+    for unsigned::Equals<VAL>
+where
+    Assert<{ VAL % DIV == MOD }>: IsTrue,
+{
+    fn imply(self) -> unsigned::Modulo<DIV, MOD> {
+        unsigned::Modulo::<DIV, MOD>
+    }
+}
+
+impl<const VAL: u128, const DIV: u128, const MOD: u128> Implies<Not<unsigned::Modulo<DIV, MOD>>>
+    for unsigned::Equals<VAL>
+where
+    Assert<{ VAL % DIV != MOD }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::Modulo<DIV, MOD>> {
+        Not::new(unsigned::Modulo::<DIV, MOD>)
+    }
+}
+
+impl<const VAL: u128, const OTHER: u128> Implies<Not<unsigned::Equals<OTHER>>>
+    for unsigned::Equals<VAL>
+where
+    Assert<{ VAL != OTHER }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::Equals<OTHER>> {
+        Not::new(unsigned::Equals::<OTHER>)
+    }
+}
+
+impl<const F_DIV: u128, const F_MOD: u128, const DIV: u128, const MOD: u128>
+    Implies<unsigned::Modulo<DIV, MOD>> for unsigned::Modulo<F_DIV, F_MOD>
+where
+    Assert<{ F_DIV.is_multiple_of(DIV) & (F_MOD % DIV == MOD) }>: IsTrue,
+{
+    fn imply(self) -> unsigned::Modulo<DIV, MOD> {
+        unsigned::Modulo::<DIV, MOD>
+    }
+}
+
+impl<const F_DIV: u128, const F_MOD: u128, const DIV: u128, const MOD: u128>
+    Implies<Not<unsigned::Modulo<DIV, MOD>>> for unsigned::Modulo<F_DIV, F_MOD>
+where
+    Assert<{ F_DIV.is_multiple_of(DIV) & (F_MOD % DIV != MOD) }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::Modulo<DIV, MOD>> {
+        Not::new(unsigned::Modulo::<DIV, MOD>)
+    }
+}
+
+impl<const VAL: u128, const MIN: u128> Implies<Not<unsigned::GreaterThan<MIN>>>
+    for unsigned::Equals<VAL>
+where
+    Assert<{ VAL <= MIN }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::GreaterThan<MIN>> {
+        Not::new(unsigned::GreaterThan::<MIN>)
+    }
+}
+
+impl<const VAL: u128, const MIN: u128> Implies<Not<unsigned::GreaterThanEqual<MIN>>>
+    for unsigned::Equals<VAL>
+where
+    Assert<{ VAL < MIN }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::GreaterThanEqual<MIN>> {
+        Not::new(unsigned::GreaterThanEqual::<MIN>)
+    }
+}
+
+impl<const VAL: u128, const MAX: u128> Implies<Not<unsigned::LessThan<MAX>>>
+    for unsigned::Equals<VAL>
+where
+    Assert<{ VAL >= MAX }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::LessThan<MAX>> {
+        Not::new(unsigned::LessThan::<MAX>)
+    }
+}
+
+impl<const VAL: u128, const MAX: u128> Implies<Not<unsigned::LessThanEqual<MAX>>>
+    for unsigned::Equals<VAL>
+where
+    Assert<{ VAL > MAX }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::LessThanEqual<MAX>> {
+        Not::new(unsigned::LessThanEqual::<MAX>)
+    }
+}
+
+impl<const MIN: u128, const VAL: u128> Implies<Not<unsigned::Equals<VAL>>>
+    for unsigned::GreaterThan<MIN>
+where
+    Assert<{ VAL <= MIN }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::Equals<VAL>> {
+        Not::new(unsigned::Equals::<VAL>)
+    }
+}
+
+impl<const MIN: u128, const VAL: u128> Implies<Not<unsigned::Equals<VAL>>>
+    for unsigned::GreaterThanEqual<MIN>
+where
+    Assert<{ VAL < MIN }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::Equals<VAL>> {
+        Not::new(unsigned::Equals::<VAL>)
+    }
+}
+
+impl<const MAX: u128, const VAL: u128> Implies<Not<unsigned::Equals<VAL>>>
+    for unsigned::LessThan<MAX>
+where
+    Assert<{ VAL >= MAX }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::Equals<VAL>> {
+        Not::new(unsigned::Equals::<VAL>)
+    }
+}
+
+impl<const MAX: u128, const VAL: u128> Implies<Not<unsigned::Equals<VAL>>>
+    for unsigned::LessThanEqual<MAX>
+where
+    Assert<{ VAL > MAX }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::Equals<VAL>> {
+        Not::new(unsigned::Equals::<VAL>)
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<Not<unsigned::LessThan<T>>> for unsigned::GreaterThan<F>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::LessThan<T>> {
+        Not::new(unsigned::LessThan::<T>)
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<Not<unsigned::LessThanEqual<T>>>
+    for unsigned::GreaterThan<F>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::LessThanEqual<T>> {
+        Not::new(unsigned::LessThanEqual::<T>)
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<Not<unsigned::LessThan<T>>>
+    for unsigned::GreaterThanEqual<F>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::LessThan<T>> {
+        Not::new(unsigned::LessThan::<T>)
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<Not<unsigned::LessThanEqual<T>>>
+    for unsigned::GreaterThanEqual<F>
+where
+    Assert<{ F > T }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::LessThanEqual<T>> {
+        Not::new(unsigned::LessThanEqual::<T>)
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<Not<unsigned::GreaterThan<T>>> for unsigned::LessThan<F>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::GreaterThan<T>> {
+        Not::new(unsigned::GreaterThan::<T>)
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<Not<unsigned::GreaterThanEqual<T>>>
+    for unsigned::LessThan<F>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::GreaterThanEqual<T>> {
+        Not::new(unsigned::GreaterThanEqual::<T>)
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<Not<unsigned::GreaterThan<T>>>
+    for unsigned::LessThanEqual<F>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::GreaterThan<T>> {
+        Not::new(unsigned::GreaterThan::<T>)
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<Not<unsigned::GreaterThanEqual<T>>>
+    for unsigned::LessThanEqual<F>
+where
+    Assert<{ F < T }>: IsTrue,
+{
+    fn imply(self) -> Not<unsigned::GreaterThanEqual<T>> {
+        Not::new(unsigned::GreaterThanEqual::<T>)
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<unsigned::GreaterThan<T>>
+    for Not<unsigned::LessThanEqual<F>>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> unsigned::GreaterThan<T> {
+        unsigned::GreaterThan::<T>
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<unsigned::GreaterThanEqual<T>>
+    for Not<unsigned::LessThanEqual<F>>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> unsigned::GreaterThanEqual<T> {
+        unsigned::GreaterThanEqual::<T>
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<unsigned::GreaterThan<T>> for Not<unsigned::LessThan<F>>
+where
+    Assert<{ F > T }>: IsTrue,
+{
+    fn imply(self) -> unsigned::GreaterThan<T> {
+        unsigned::GreaterThan::<T>
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<unsigned::GreaterThanEqual<T>>
+    for Not<unsigned::LessThan<F>>
+where
+    Assert<{ F >= T }>: IsTrue,
+{
+    fn imply(self) -> unsigned::GreaterThanEqual<T> {
+        unsigned::GreaterThanEqual::<T>
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<unsigned::LessThan<T>>
+    for Not<unsigned::GreaterThanEqual<F>>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> unsigned::LessThan<T> {
+        unsigned::LessThan::<T>
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<unsigned::LessThanEqual<T>>
+    for Not<unsigned::GreaterThanEqual<F>>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> unsigned::LessThanEqual<T> {
+        unsigned::LessThanEqual::<T>
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<unsigned::LessThan<T>> for Not<unsigned::GreaterThan<F>>
+where
+    Assert<{ F < T }>: IsTrue,
+{
+    fn imply(self) -> unsigned::LessThan<T> {
+        unsigned::LessThan::<T>
+    }
+}
+
+impl<const F: u128, const T: u128> Implies<unsigned::LessThanEqual<T>>
+    for Not<unsigned::GreaterThan<F>>
+where
+    Assert<{ F <= T }>: IsTrue,
+{
+    fn imply(self) -> unsigned::LessThanEqual<T> {
+        unsigned::LessThanEqual::<T>
+    }
+}
+
+impl<const MIN: u128> Implies<unsigned::GreaterThanEqual<MIN>> for Not<unsigned::Equals<0>>
+where
+    Assert<{ MIN <= 1 }>: IsTrue,
+{
+    fn imply(self) -> unsigned::GreaterThanEqual<MIN> {
+        unsigned::GreaterThanEqual::<MIN>
+    }
+}
+
+impl Implies<unsigned::GreaterThan<0>> for Not<unsigned::Equals<0>> {
+    fn imply(self) -> unsigned::GreaterThan<0> {
+        unsigned::GreaterThan::<0>
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -366,4 +941,68 @@ mod tests {
         let smaller = Refinement::<u8, unsigned::OpenInterval<25, 75>>::refine(50).unwrap();
         assert_eq!(bigger, smaller.imply());
     }
+
+    #[test]
+    fn test_eq_interval_implication() {
+        let e = Refinement::<u8, unsigned::Equals<5>>::refine(5).unwrap();
+        let _: Refinement<u8, unsigned::ClosedInterval<0, 10>> = e.imply();
+        let _: Refinement<u8, unsigned::NonZero> = e.imply();
+        let _: Refinement<u8, unsigned::Odd> = e.imply();
+        // let _: Refinement<u8, unsigned::ClosedInterval<6, 10>> = e.imply();
+        let e = Refinement::<i8, signed::Equals<-5>>::refine(-5).unwrap();
+        let _: Refinement<i8, signed::OpenInterval<-10, 0>> = e.imply();
+        let _: Refinement<i8, signed::NonPositive> = e.imply();
+        let _: Refinement<i8, signed::Modulo<2, -1>> = e.imply();
+    }
+
+    #[test]
+    fn test_modulo_implication() {
+        let m = Refinement::<u32, unsigned::Modulo<4, 0>>::refine(8).unwrap();
+        let _: Refinement<u32, unsigned::Even> = m.imply();
+        let _: Refinement<u32, unsigned::Divisible<4>> = m.imply();
+        // let _: Refinement<u32, unsigned::Divisible<8>> = m.imply();
+        let m = Refinement::<u32, unsigned::Modulo<6, 3>>::refine(9).unwrap();
+        let _: Refinement<u32, unsigned::Odd> = m.imply();
+        let _: Refinement<u32, unsigned::Divisible<3>> = m.imply();
+        let m = Refinement::<i32, signed::Modulo<10, -3>>::refine(-13).unwrap();
+        let _: Refinement<i32, signed::Modulo<5, -3>> = m.imply();
+        let _: Refinement<i32, signed::Odd> = m.imply();
+    }
+
+    #[test]
+    fn test_zero_implication() {
+        let z = Refinement::<i32, signed::Zero>::refine(0).unwrap();
+        let _: Refinement<i32, signed::NonPositive> = z.imply();
+        let _: Refinement<i32, signed::NonNegative> = z.imply();
+        let _: Refinement<i32, signed::Even> = z.imply();
+        // let _: Refinement<i32, signed::NonZero> = z.imply();
+        let z = Refinement::<u32, unsigned::Zero>::refine(0).unwrap();
+        let _: Refinement<u32, unsigned::Even> = z.imply();
+        let _: Refinement<u32, unsigned::LessThan<1>> = z.imply();
+    }
+
+    #[test]
+    fn test_derived_alias_implication() {
+        let p = Refinement::<i32, signed::Positive>::refine(3).unwrap();
+        let _: Refinement<i32, signed::NonZero> = p.imply();
+        let _: Refinement<i32, signed::NonNegative> = p.imply();
+        let n = Refinement::<i32, signed::NonNegative>::refine(0).unwrap();
+        let _: Refinement<i32, signed::GreaterThanEqual<-1>> = n.imply();
+        let _: Refinement<i32, signed::GreaterThan<-1>> = n.imply();
+        let n = Refinement::<i32, signed::NonPositive>::refine(0).unwrap();
+        let _: Refinement<i32, signed::LessThan<1>> = n.imply();
+        let n = Refinement::<u32, unsigned::NonZero>::refine(1).unwrap();
+        let _: Refinement<u32, unsigned::GreaterThanEqual<1>> = n.imply();
+        let _: Refinement<u32, unsigned::GreaterThan<0>> = n.imply();
+        let g = Refinement::<u32, unsigned::GreaterThan<0>>::refine(1).unwrap();
+        let _: Refinement<u32, unsigned::NonZero> = g.imply();
+    }
+
+    #[test]
+    fn test_wide_implication() {
+        let l = Refinement::<u128, unsigned::LessThan<{ u128::MAX }>>::refine(10).unwrap();
+        let _: Refinement<u128, unsigned::LessThanEqual<{ u128::MAX }>> = l.imply();
+        let g = Refinement::<i128, signed::GreaterThan<{ i128::MIN }>>::refine(10).unwrap();
+        let _: Refinement<i128, signed::GreaterThanEqual<{ i128::MIN }>> = g.imply();
+    }
 }
@@ -0,0 +1,162 @@
+//! Bounds-tracked arithmetic for stable Rust.
+//!
+//! The `arithmetic` feature computes the bounds of each result as const generic parameters, which requires
+//! `generic_const_exprs`. With the `stable-arithmetic` feature, operations between refinements instead
+//! produce an expression predicate such as [unsigned::Sum] whose bounds are computed from those of its
+//! operands by associated constants. Expression predicates can be operated on further, and are converted
+//! into regular boundable predicates using [Implies](crate::Implies).
+//!
+//! Operations are provided between any two predicates implementing
+//! [UnsignedMinMax](crate::boundable::UnsignedMinMax) (or [SignedMinMax](crate::boundable::SignedMinMax)),
+//! where the left-hand side is one of the interval types, `Equals`, or an expression predicate. Operations
+//! whose bounds cannot be computed, or which do not fit within the bounded type (such as a possibly
+//! negative unsigned [unsigned::Difference]), fail to build.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::ClosedInterval};
+//!
+//! type SkillLevel = Refinement<u8, ClosedInterval<1, 10>>;
+//!
+//! fn couple_skill(a: SkillLevel, b: SkillLevel) -> Refinement<u8, ClosedInterval<2, 20>> {
+//!   (a + b).imply()
+//! }
+//!
+//! let tom_skill = SkillLevel::refine(9).unwrap();
+//! let sally_skill = SkillLevel::refine(6).unwrap();
+//! assert_eq!(*couple_skill(tom_skill, sally_skill), 15);
+//! ```
+
+macro_rules! expression {
+    (
+        $m:ident, $int:ty, $boundable:ident, $min:ident, $max:ident, $minmax:ident;
+        $(#[$doc:meta])*
+        $name:ident { min: $lo:expr, max: $hi:expr $(, requires: $check:expr, $msg:literal)? }
+    ) => {
+        $(#[$doc])*
+        #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
+        pub struct $name<T, A, B>(PhantomData<(T, A, B)>);
+
+        impl<T: $boundable, A: $minmax<T>, B: $minmax<T>> Predicate<T> for $name<T, A, B> {
+            fn test(value: &T) -> bool {
+                let value = value.bounding_value();
+                <Self as $min<T>>::UMIN <= value && value <= <Self as $max<T>>::UMAX
+            }
+
+            #[cfg(feature = "alloc")]
+            fn error() -> ErrorMessage {
+                alloc::format!(
+                    "must be greater than or equal to {} and less than or equal to {}",
+                    <Self as $min<T>>::UMIN,
+                    <Self as $max<T>>::UMAX
+                )
+            }
+
+            #[cfg(not(feature = "alloc"))]
+            fn error() -> ErrorMessage {
+                "interval"
+            }
+
+            unsafe fn optimize(value: &T) {
+                core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
+            }
+        }
+
+        impl<T, A, B> Stateless for $name<T, A, B> where Self: Default {}
+
+        impl<T: $boundable, A: $minmax<T>, B: $minmax<T>> $min<T> for $name<T, A, B> {
+            const UMIN: $int = {
+                $(assert!($check, $msg);)?
+                $lo
+            };
+        }
+
+        impl<T: $boundable, A: $minmax<T>, B: $minmax<T>> $max<T> for $name<T, A, B> {
+            const UMAX: $int = {
+                $(assert!($check, $msg);)?
+                $hi
+            };
+        }
+
+        impl<T: $boundable, A: $minmax<T>, B: $minmax<T>> $minmax<T> for $name<T, A, B> {}
+
+        impl<T: $boundable, A: $minmax<T>, B: $minmax<T>, const MIN: $int>
+            Implies<$m::GreaterThan<MIN>> for $name<T, A, B>
+        {
+            const CHECK: () = assert!(
+                <Self as $min<T>>::UMIN > MIN,
+                concat!(stringify!($name), " does not imply GreaterThan")
+            );
+
+            fn imply(self) -> $m::GreaterThan<MIN> {
+                $m::GreaterThan::<MIN>
+            }
+        }
+
+        impl<T: $boundable, A: $minmax<T>, B: $minmax<T>, const MIN: $int>
+            Implies<$m::GreaterThanEqual<MIN>> for $name<T, A, B>
+        {
+            const CHECK: () = assert!(
+                <Self as $min<T>>::UMIN >= MIN,
+                concat!(stringify!($name), " does not imply GreaterThanEqual")
+            );
+
+            fn imply(self) -> $m::GreaterThanEqual<MIN> {
+                $m::GreaterThanEqual::<MIN>
+            }
+        }
+
+        impl<T: $boundable, A: $minmax<T>, B: $minmax<T>, const MAX: $int>
+            Implies<$m::LessThan<MAX>> for $name<T, A, B>
+        {
+            const CHECK: () = assert!(
+                <Self as $max<T>>::UMAX < MAX,
+                concat!(stringify!($name), " does not imply LessThan")
+            );
+
+            fn imply(self) -> $m::LessThan<MAX> {
+                $m::LessThan::<MAX>
+            }
+        }
+
+        impl<T: $boundable, A: $minmax<T>, B: $minmax<T>, const MAX: $int>
+            Implies<$m::LessThanEqual<MAX>> for $name<T, A, B>
+        {
+            const CHECK: () = assert!(
+                <Self as $max<T>>::UMAX <= MAX,
+                concat!(stringify!($name), " does not imply LessThanEqual")
+            );
+
+            fn imply(self) -> $m::LessThanEqual<MAX> {
+                $m::LessThanEqual::<MAX>
+            }
+        }
+    };
+}
+
+macro_rules! operators {
+    ($boundable:ident, $minmax:ident; $([$($param:tt)*] $lhs:ty;)+) => {
+        $(
+            operators!(@op $boundable, $minmax, Add, add, Sum, [$($param)*] $lhs);
+            operators!(@op $boundable, $minmax, Sub, sub, Difference, [$($param)*] $lhs);
+            operators!(@op $boundable, $minmax, Mul, mul, Product, [$($param)*] $lhs);
+            operators!(@op $boundable, $minmax, Div, div, Quotient, [$($param)*] $lhs);
+        )+
+    };
+    (@op $boundable:ident, $minmax:ident, $op:ident, $fun:ident, $expr:ident, [$($param:tt)*] $lhs:ty) => {
+        impl<Type: $boundable + $op<Output = Type>, B: $minmax<Type>, $($param)*> $op<Refinement<Type, B>>
+            for Refinement<Type, $lhs>
+        {
+            type Output = Refinement<Type, $expr<Type, $lhs, B>>;
+
+            fn $fun(self, rhs: Refinement<Type, B>) -> Self::Output {
+                let () = Fits::<Type, $expr<Type, $lhs, B>>::CHECK;
+                Refinement(self.0.$fun(rhs.0), PhantomData)
+            }
+        }
+    };
+}
+
+pub mod signed;
+pub mod unsigned;
@@ -0,0 +1,102 @@
+//! Bounds-tracked arithmetic over signed refinements.
+
+use core::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::{
+    boundable::{
+        max_div, max_mul, min_div, min_mul, signed, signed::SignedBoundable, SignedMax, SignedMin,
+        SignedMinMax,
+    },
+    ErrorMessage, Implies, Predicate, Refinement, Stateless,
+};
+
+struct Fits<T, P>(PhantomData<(T, P)>);
+
+impl<T: SignedBoundable, P: SignedMinMax<T>> Fits<T, P> {
+    const CHECK: () = assert!(
+        T::BOUND_MIN <= P::UMIN && P::UMAX <= T::BOUND_MAX,
+        "result bounds exceed the range of the bounded type"
+    );
+}
+
+expression! {
+    signed, i128, SignedBoundable, SignedMin, SignedMax, SignedMinMax;
+    /// The result of adding refinements satisfying `A` and `B`.
+    Sum { min: A::UMIN + B::UMIN, max: A::UMAX + B::UMAX }
+}
+
+expression! {
+    signed, i128, SignedBoundable, SignedMin, SignedMax, SignedMinMax;
+    /// The result of subtracting a refinement satisfying `B` from one satisfying `A`.
+    Difference { min: A::UMIN - B::UMAX, max: A::UMAX - B::UMIN }
+}
+
+expression! {
+    signed, i128, SignedBoundable, SignedMin, SignedMax, SignedMinMax;
+    /// The result of multiplying refinements satisfying `A` and `B`.
+    Product {
+        min: min_mul(A::UMIN, A::UMAX, B::UMIN, B::UMAX),
+        max: max_mul(A::UMIN, A::UMAX, B::UMIN, B::UMAX)
+    }
+}
+
+expression! {
+    signed, i128, SignedBoundable, SignedMin, SignedMax, SignedMinMax;
+    /// The result of dividing a refinement satisfying `A` by one satisfying `B`.
+    Quotient {
+        min: min_div(A::UMIN, A::UMAX, B::UMIN, B::UMAX),
+        max: max_div(A::UMIN, A::UMAX, B::UMIN, B::UMAX),
+        requires: B::UMIN > 0 || B::UMAX < 0, "divisor may be zero"
+    }
+}
+
+operators! {
+    SignedBoundable, SignedMinMax;
+    [const VAL: i128] signed::Equals<VAL>;
+    [const MIN: i128, const MAX: i128] signed::OpenInterval<MIN, MAX>;
+    [const MIN: i128, const MAX: i128] signed::OpenClosedInterval<MIN, MAX>;
+    [const MIN: i128, const MAX: i128] signed::ClosedOpenInterval<MIN, MAX>;
+    [const MIN: i128, const MAX: i128] signed::ClosedInterval<MIN, MAX>;
+    [L: SignedMinMax<Type>, R: SignedMinMax<Type>] Sum<Type, L, R>;
+    [L: SignedMinMax<Type>, R: SignedMinMax<Type>] Difference<Type, L, R>;
+    [L: SignedMinMax<Type>, R: SignedMinMax<Type>] Product<Type, L, R>;
+    [L: SignedMinMax<Type>, R: SignedMinMax<Type>] Quotient<Type, L, R>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::signed::*, *};
+
+    #[test]
+    fn test_add_sub() {
+        let a = Refinement::<i8, ClosedInterval<-10, 10>>::refine(-7).unwrap();
+        let b = Refinement::<i8, OpenInterval<0, 5>>::refine(3).unwrap();
+        let c: Refinement<i8, ClosedInterval<-9, 14>> = (a + b).imply();
+        assert_eq!(*c, -4);
+        let d: Refinement<i8, ClosedInterval<-14, 9>> = (a - b).imply();
+        assert_eq!(*d, -10);
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let a = Refinement::<i16, ClosedInterval<-10, 5>>::refine(-6).unwrap();
+        let b = Refinement::<i16, ClosedInterval<-3, -1>>::refine(-2).unwrap();
+        let c: Refinement<i16, ClosedInterval<-15, 30>> = (a * b).imply();
+        assert_eq!(*c, 12);
+        let d: Refinement<i16, ClosedInterval<-5, 10>> = (a / b).imply();
+        assert_eq!(*d, 3);
+        // let _ = b / a;
+    }
+
+    #[test]
+    fn test_chained() {
+        let a = Refinement::<i32, Equals<-2>>::refine(-2).unwrap();
+        let b = Refinement::<i32, ClosedOpenInterval<1, 4>>::refine(3).unwrap();
+        let c: Refinement<i32, Negative> = ((a * b) + a).imply();
+        assert_eq!(*c, -8);
+        // let _ = a * a * a * a * a * a * a * a * a * a * a * a * a * a * a * a * b;
+    }
+}
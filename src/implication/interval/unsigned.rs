@@ -0,0 +1,121 @@
+//! Bounds-tracked arithmetic over unsigned refinements.
+
+use core::{
+    marker::PhantomData,
+    ops::{Add, Div, Mul, Sub},
+};
+
+use crate::{
+    boundable::{unsigned, unsigned::UnsignedBoundable, UnsignedMax, UnsignedMin, UnsignedMinMax},
+    ErrorMessage, Implies, Predicate, Refinement, Stateless,
+};
+
+struct Fits<T, P>(PhantomData<(T, P)>);
+
+impl<T: UnsignedBoundable, P: UnsignedMax<T>> Fits<T, P> {
+    const CHECK: () = assert!(
+        P::UMAX <= T::BOUND_MAX,
+        "result bounds exceed the range of the bounded type"
+    );
+}
+
+expression! {
+    unsigned, u128, UnsignedBoundable, UnsignedMin, UnsignedMax, UnsignedMinMax;
+    /// The result of adding refinements satisfying `A` and `B`.
+    Sum { min: A::UMIN + B::UMIN, max: A::UMAX + B::UMAX }
+}
+
+expression! {
+    unsigned, u128, UnsignedBoundable, UnsignedMin, UnsignedMax, UnsignedMinMax;
+    /// The result of subtracting a refinement satisfying `B` from one satisfying `A`.
+    Difference {
+        min: A::UMIN - B::UMAX,
+        max: A::UMAX - B::UMIN,
+        requires: A::UMIN >= B::UMAX, "difference may be negative"
+    }
+}
+
+expression! {
+    unsigned, u128, UnsignedBoundable, UnsignedMin, UnsignedMax, UnsignedMinMax;
+    /// The result of multiplying refinements satisfying `A` and `B`.
+    Product { min: A::UMIN * B::UMIN, max: A::UMAX * B::UMAX }
+}
+
+expression! {
+    unsigned, u128, UnsignedBoundable, UnsignedMin, UnsignedMax, UnsignedMinMax;
+    /// The result of dividing a refinement satisfying `A` by one satisfying `B`.
+    Quotient {
+        min: A::UMIN / B::UMAX,
+        max: A::UMAX / B::UMIN,
+        requires: B::UMIN > 0, "divisor may be zero"
+    }
+}
+
+operators! {
+    UnsignedBoundable, UnsignedMinMax;
+    [const VAL: u128] unsigned::Equals<VAL>;
+    [const MIN: u128, const MAX: u128] unsigned::OpenInterval<MIN, MAX>;
+    [const MIN: u128, const MAX: u128] unsigned::OpenClosedInterval<MIN, MAX>;
+    [const MIN: u128, const MAX: u128] unsigned::ClosedOpenInterval<MIN, MAX>;
+    [const MIN: u128, const MAX: u128] unsigned::ClosedInterval<MIN, MAX>;
+    [L: UnsignedMinMax<Type>, R: UnsignedMinMax<Type>] Sum<Type, L, R>;
+    [L: UnsignedMinMax<Type>, R: UnsignedMinMax<Type>] Difference<Type, L, R>;
+    [L: UnsignedMinMax<Type>, R: UnsignedMinMax<Type>] Product<Type, L, R>;
+    [L: UnsignedMinMax<Type>, R: UnsignedMinMax<Type>] Quotient<Type, L, R>;
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::unsigned::*, *};
+
+    #[test]
+    fn test_add() {
+        let a = Refinement::<u8, ClosedInterval<1, 10>>::refine(9).unwrap();
+        let b = Refinement::<u8, OpenInterval<0, 5>>::refine(4).unwrap();
+        let c: Refinement<u8, ClosedInterval<2, 14>> = (a + b).imply();
+        assert_eq!(*c, 13);
+        // let _: Refinement<u8, ClosedInterval<2, 13>> = (a + b).imply();
+    }
+
+    #[test]
+    fn test_sub() {
+        let a = Refinement::<u16, ClosedInterval<10, 20>>::refine(15).unwrap();
+        let b = Refinement::<u16, Equals<10>>::refine(10).unwrap();
+        let c: Refinement<u16, ClosedInterval<0, 10>> = (a - b).imply();
+        assert_eq!(*c, 5);
+        // let _ = b - a;
+    }
+
+    #[test]
+    fn test_mul_div() {
+        let a = Refinement::<u32, ClosedOpenInterval<2, 10>>::refine(6).unwrap();
+        let b = Refinement::<u32, OpenClosedInterval<1, 3>>::refine(3).unwrap();
+        let c: Refinement<u32, ClosedInterval<4, 27>> = (a * b).imply();
+        assert_eq!(*c, 18);
+        let d: Refinement<u32, LessThanEqual<4>> = (a / b).imply();
+        assert_eq!(*d, 2);
+    }
+
+    #[test]
+    fn test_chained() {
+        let a = Refinement::<u8, ClosedInterval<1, 10>>::refine(3).unwrap();
+        let b = Refinement::<u8, ClosedInterval<1, 10>>::refine(4).unwrap();
+        let c = Refinement::<u8, Equals<2>>::refine(2).unwrap();
+        let d: Refinement<u8, ClosedInterval<4, 40>> = ((a + b) * c).imply();
+        assert_eq!(*d, 14);
+        // let _ = (a * b) * (a * b) * c;
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_expression_predicate() {
+        use alloc::string::ToString;
+
+        type Total = interval::unsigned::Sum<u8, ClosedInterval<1, 10>, Equals<5>>;
+        assert!(Refinement::<u8, Total>::refine(10).is_ok());
+        assert_eq!(
+            Refinement::<u8, Total>::refine(16).unwrap_err().to_string(),
+            "refinement violated: must be greater than or equal to 6 and less than or equal to 15"
+        );
+    }
+}
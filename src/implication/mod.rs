@@ -37,18 +37,105 @@
 /// for an example of how the internals work currently. To create your own implementation, you need some version
 /// of `Assert` and `IsTrue`. The idea is that the generic const expr in the `Implies` implementation trait
 /// bound will only resolve to a valid `IsTrue` implementation when the logical predicate is satisfied.
-pub trait Implies<T> {
+///
+/// ## Derivation indices
+///
+/// The [boolean](crate::boolean) combinators support the usual rules of logic, such as `And<A, B> => A`
+/// and `A => Or<A, B>`. Because several of these rules would otherwise overlap, each rule is identified by
+/// a type from the [index] module through the second type parameter `I`. The index is usually inferred by
+/// the compiler, but a conversion that can be derived in more than one way (such as
+/// `And<LessThan<5>, LessThan<6>> => LessThan<10>`) is ambiguous and will not compile without naming
+/// the rule. For example, conjunctions are converted pairwise by introducing a conjunction whose
+/// sides are eliminated from the left and right of the source respectively:
+///
+/// ```
+/// use refined::{prelude::*, boolean::And, boundable::unsigned::GreaterThan, implication::index::*};
+///
+/// let a = Refinement::<u8, And<GreaterThan<5>, GreaterThan<3>>>::refine(9).unwrap();
+/// // let b: Refinement<u8, And<GreaterThan<1>, GreaterThan<2>>> = a.imply(); // Ambiguous
+/// let b: Refinement<u8, And<GreaterThan<1>, GreaterThan<2>>> =
+///     Implies::<_, AndIntro<AndLeft<_>, AndRight<_>>>::imply(a);
+/// assert_eq!(*b, 9);
+/// ```
+///
+/// ## Stable Rust
+///
+/// The `stable-implication` feature provides `Implies` without `generic_const_exprs`. The boundable
+/// comparison rules, `Equals` into bounds, and `Modulo` rules are implemented for all constants, and each
+/// conversion is instead checked by a constant assertion when the program is built:
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::{ClosedInterval, LessThan}};
+///
+/// let level: Refinement<u8, ClosedInterval<1, 10>> = Refinement::refine(5).unwrap();
+/// let small: Refinement<u8, LessThan<20>> = level.imply();
+/// // let tiny: Refinement<u8, LessThan<8>> = level.imply(); // Fails to build
+/// assert_eq!(*small, 5);
+/// ```
+///
+/// Because failing conversions still type-check, these errors are reported by `cargo build` rather than
+/// `cargo check`. Rules relating negated predicates (such as `Zero => NonPositive`) are only provided by
+/// the `implication` feature, where they can be excluded during type checking.
+pub trait Implies<T, I = ()> {
+    /// Asserts that the implication holds; evaluated whenever a [Refinement](crate::Refinement) is implied.
+    #[doc(hidden)]
+    const CHECK: () = ();
+
     fn imply(self) -> T;
 }
 
+/// Type-level indices identifying the rule used to derive an [Implies] implementation.
+///
+/// These types are never constructed, and only exist so that the compiler can distinguish between rules.
+pub mod index {
+    use core::marker::PhantomData;
+
+    /// `F => And<T1, T2>` when `F => T1` and `F => T2`.
+    pub struct AndIntro<I1, I2>(PhantomData<(I1, I2)>);
+
+    /// `And<A, B> => T` when `A => T`.
+    pub struct AndLeft<I>(PhantomData<I>);
+
+    /// `And<A, B> => T` when `B => T`.
+    pub struct AndRight<I>(PhantomData<I>);
+
+    /// `F => Or<A, B>` when `F => A`.
+    pub struct OrLeft<I>(PhantomData<I>);
+
+    /// `F => Or<A, B>` when `F => B`.
+    pub struct OrRight<I>(PhantomData<I>);
+
+    /// `Or<A, B> => T` when `A => T` and `B => T`.
+    pub struct OrCases<I1, I2>(PhantomData<(I1, I2)>);
+
+    /// `Xor<A, B> => T` when `Or<A, B> => T`.
+    pub struct XorOr<I>(PhantomData<I>);
+
+    /// `Not<B> => Not<A>` when `A => B`.
+    pub struct Contrapositive<I>(PhantomData<I>);
+
+    /// `Not<Not<A>> => T` when `A => T`.
+    pub struct DoubleNegation<I>(PhantomData<I>);
+
+    /// `All<(F1, ..)> => All<(T1, ..)>` or `Any<(F1, ..)> => Any<(T1, ..)>` when each `Fn => Tn`.
+    pub struct Each<Is>(PhantomData<Is>);
+}
+
+#[cfg(feature = "implication")]
 pub(crate) enum Assert<const CHECK: bool> {}
 
+#[cfg(feature = "implication")]
 pub(crate) trait IsTrue {}
 
+#[cfg(feature = "implication")]
 impl IsTrue for Assert<true> {}
 
 mod boolean_imp;
+#[cfg(feature = "implication")]
 mod boundable_imp;
+mod reflexive_imp;
+#[cfg(not(feature = "implication"))]
+mod stable_imp;
 
 #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
 #[cfg(feature = "arithmetic")]
@@ -57,3 +144,7 @@ mod arithmetic;
 #[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
 #[cfg(feature = "arithmetic")]
 pub use arithmetic::*;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "stable-arithmetic")))]
+#[cfg(all(feature = "stable-arithmetic", not(feature = "arithmetic")))]
+pub mod interval;
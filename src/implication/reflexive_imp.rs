@@ -0,0 +1,68 @@
+//! Every predicate implies itself. The boundable comparisons already do so via their range rules, so
+//! only the remaining leaf predicates are covered here.
+
+use crate::{boundable::*, character::*, composite::*, float};
+
+#[cfg(feature = "alloc")]
+use crate::string::*;
+
+use super::Implies;
+
+macro_rules! reflexive {
+    ($([$($param:tt)*] $t:ty;)+) => {
+        $(
+            impl<$($param)*> Implies<$t> for $t {
+                fn imply(self) -> $t {
+                    self
+                }
+            }
+        )+
+    };
+}
+
+reflexive! {
+    [] crate::boolean::True;
+    [] crate::boolean::False;
+    [] IsControl;
+    [] IsDigit;
+    [] IsLowercase;
+    [] IsUppercase;
+    [] IsNumeric;
+    [] IsWhitespace;
+    [] IsHexDigit;
+    [] float::NotNan;
+    [] float::Finite;
+    [const N: isize, const D: usize] float::GreaterThan<N, D>;
+    [const N: isize, const D: usize] float::GreaterThanEqual<N, D>;
+    [const N: isize, const D: usize] float::LessThan<N, D>;
+    [const N: isize, const D: usize] float::LessThanEqual<N, D>;
+    [const N: usize] Element<N>;
+    [] Ordered;
+    [] StrictlyOrdered;
+    [F, P] Project<F, P>;
+    [F, G, P] Zip<F, G, P>;
+    [const VAL: u128] unsigned::Equals<VAL>;
+    [] unsigned::DynGreaterThan;
+    [] unsigned::DynGreaterThanEqual;
+    [] unsigned::DynLessThan;
+    [] unsigned::DynLessThanEqual;
+    [const VAL: i128] signed::Equals<VAL>;
+    [] signed::DynGreaterThan;
+    [] signed::DynGreaterThanEqual;
+    [] signed::DynLessThan;
+    [] signed::DynLessThanEqual;
+}
+
+#[cfg(feature = "alloc")]
+reflexive! {
+    [S: crate::TypeString] StartsWith<S>;
+    [S: crate::TypeString] EndsWith<S>;
+    [S: crate::TypeString] Contains<S>;
+    [] Trimmed;
+}
+
+#[cfg(feature = "regex")]
+reflexive! {
+    [S: crate::TypeString] Regex<S>;
+    [] DynRegex;
+}
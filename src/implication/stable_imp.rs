@@ -0,0 +1,121 @@
+//! Boundable implication for stable Rust.
+//!
+//! These rules mirror those of `boundable_imp`, but because their conditions cannot be expressed as trait
+//! bounds without `generic_const_exprs`, they are implemented for all constants and checked by `CHECK`.
+
+use crate::boundable::*;
+
+use super::Implies;
+
+macro_rules! bound_implies {
+    ($m:ident, $t:ty; $($target:ident<$to:ident> for $source:ident<$from:ident> if $cond:expr;)+) => {
+        $(
+            impl<const $from: $t, const $to: $t> Implies<$m::$target<$to>> for $m::$source<$from> {
+                const CHECK: () = assert!(
+                    $cond,
+                    concat!(stringify!($source), " does not imply ", stringify!($target))
+                );
+
+                fn imply(self) -> $m::$target<$to> {
+                    $m::$target::<$to>
+                }
+            }
+        )+
+    };
+}
+
+macro_rules! modulo_implies {
+    ($m:ident, $t:ty) => {
+        impl<const VAL: $t, const DIV: $t, const MOD: $t> Implies<$m::Modulo<DIV, MOD>>
+            for $m::Equals<VAL>
+        {
+            const CHECK: () = assert!(VAL % DIV == MOD, "Equals does not imply Modulo");
+
+            fn imply(self) -> $m::Modulo<DIV, MOD> {
+                $m::Modulo::<DIV, MOD>
+            }
+        }
+
+        impl<const F_DIV: $t, const F_MOD: $t, const DIV: $t, const MOD: $t>
+            Implies<$m::Modulo<DIV, MOD>> for $m::Modulo<F_DIV, F_MOD>
+        {
+            const CHECK: () = assert!(
+                F_DIV % DIV == 0 && F_MOD % DIV == MOD,
+                "Modulo does not imply Modulo"
+            );
+
+            fn imply(self) -> $m::Modulo<DIV, MOD> {
+                $m::Modulo::<DIV, MOD>
+            }
+        }
+    };
+}
+
+bound_implies! {
+    signed, i128;
+    GreaterThan<T> for GreaterThan<F> if F >= T;
+    GreaterThan<T> for GreaterThanEqual<F> if F > T;
+    GreaterThanEqual<T> for GreaterThan<F> if F >= T;
+    GreaterThanEqual<T> for GreaterThanEqual<F> if F >= T;
+    LessThan<T> for LessThan<F> if F <= T;
+    LessThanEqual<T> for LessThan<F> if F <= T;
+    LessThanEqual<T> for LessThanEqual<F> if F <= T;
+    LessThan<T> for LessThanEqual<F> if F < T;
+    GreaterThan<MIN> for Equals<VAL> if VAL > MIN;
+    GreaterThanEqual<MIN> for Equals<VAL> if VAL >= MIN;
+    LessThan<MAX> for Equals<VAL> if VAL < MAX;
+    LessThanEqual<MAX> for Equals<VAL> if VAL <= MAX;
+}
+
+bound_implies! {
+    unsigned, u128;
+    GreaterThan<T> for GreaterThan<F> if F >= T;
+    GreaterThan<T> for GreaterThanEqual<F> if F > T;
+    GreaterThanEqual<T> for GreaterThan<F> if F >= T;
+    GreaterThanEqual<T> for GreaterThanEqual<F> if F >= T;
+    LessThan<T> for LessThan<F> if F <= T;
+    LessThanEqual<T> for LessThan<F> if F <= T;
+    LessThanEqual<T> for LessThanEqual<F> if F <= T;
+    LessThan<T> for LessThanEqual<F> if F < T;
+    GreaterThan<MIN> for Equals<VAL> if VAL > MIN;
+    GreaterThanEqual<MIN> for Equals<VAL> if VAL >= MIN;
+    LessThan<MAX> for Equals<VAL> if VAL < MAX;
+    LessThanEqual<MAX> for Equals<VAL> if VAL <= MAX;
+}
+
+modulo_implies!(signed, i128);
+modulo_implies!(unsigned, u128);
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::*, *};
+
+    #[test]
+    fn test_bound_implication() {
+        let g = Refinement::<i32, signed::GreaterThan<15>>::refine(20).unwrap();
+        let _: Refinement<i32, signed::GreaterThan<10>> = g.imply();
+        let _: Refinement<i32, signed::GreaterThanEqual<15>> = g.imply();
+        let l = Refinement::<u8, unsigned::LessThanEqual<15>>::refine(10).unwrap();
+        let _: Refinement<u8, unsigned::LessThan<16>> = l.imply();
+        // let _: Refinement<u8, unsigned::LessThan<15>> = l.imply();
+    }
+
+    #[test]
+    fn test_interval_implication() {
+        let i = Refinement::<u8, unsigned::OpenInterval<25, 75>>::refine(50).unwrap();
+        let _: Refinement<u8, unsigned::OpenInterval<1, 100>> = i.imply();
+        let _: Refinement<u8, unsigned::GreaterThanEqual<25>> = i.imply();
+        let _: Refinement<u8, unsigned::LessThanEqual<75>> = i.imply();
+        let e = Refinement::<i8, signed::Equals<-5>>::refine(-5).unwrap();
+        let _: Refinement<i8, signed::ClosedInterval<-10, 0>> = e.imply();
+    }
+
+    #[test]
+    fn test_modulo_implication() {
+        let m = Refinement::<u32, unsigned::Modulo<4, 0>>::refine(8).unwrap();
+        let _: Refinement<u32, unsigned::Even> = m.imply();
+        let z = Refinement::<i32, signed::Zero>::refine(0).unwrap();
+        let _: Refinement<i32, signed::Even> = z.imply();
+        // let _: Refinement<u32, unsigned::Divisible<8>> = m.imply();
+    }
+}
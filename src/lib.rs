@@ -55,7 +55,7 @@
 //!
 //! assert!(Frobnicator::new("Good name".to_string(), 99).is_ok());
 //! assert_eq!(Frobnicator::new("Bad name, too long".to_string(), 99).unwrap_err().to_string(),
-//!            "refinement violated: must be greater than or equal to 1 and must be less than or equal to 10");
+//!            "refinement violated: must be less than or equal to 10");
 //! assert_eq!(Frobnicator::new("Good name".to_string(), 123).unwrap_err().to_string(),
 //!            "refinement violated: must be less than or equal to 100");
 //! ```
@@ -99,7 +99,8 @@
 //! certification without the underlying refinement type being aware of how it was materialized. This means that
 //! the `serde` feature functions transparently with stateful predicates, but it's important to be aware that the
 //! `Serialize` and `Deserialize` implementations will use the stateless variants (as there's no way to easily
-//! "inject" the predicate state into the serde process).
+//! "inject" the predicate state into the serde process). When the state matters during deserialization, the
+//! [stateful_serde] module provides a `DeserializeSeed` and a scoped `deserialize_with` helper that supply it.
 //!
 //! The `regex` feature provides a good motivation for when it could make sense to use [StatefulRefinementOps]; compiling
 //! the regular expression can be an expensive operation, often more expensive than certifying the predicate itself. We
@@ -111,7 +112,7 @@
 //! type_string!(AllZs, "^z+$");
 //! type OopsAllZs = Refinement<String, Regex<AllZs>>;
 //!
-//! // Stateless refinement as usual, requires re-compiling the regex for every certification
+//! // Stateless refinement as usual, relies upon a global cache of compiled regexes (with `std`)
 //! assert!(OopsAllZs::refine("zzzzz".to_string()).is_ok());
 //!
 //! // Stateful refinement, we carry around the pre-compiled regex so that it can be re-used
@@ -120,6 +121,23 @@
 //! assert!(OopsAllZs::refine_with_state(&all_zs, "zazzy".to_string()).is_err());
 //! ```
 //!
+//! Stateful predicates can also be parameterized at runtime, which is useful when bounds come from configuration
+//! rather than from the type. The `Dyn` predicates (such as [DynLessThan](boundable::unsigned::DynLessThan) and
+//! `string::DynRegex`) carry their bounds in the predicate instance and can only be certified statefully. They combine
+//! with the [boolean] combinators (alongside any [Stateless] predicate), and [Certified] ties a value to the instance
+//! that certified it so that later modifications are re-certified against the same bounds:
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::{DynClosedInterval, DynGreaterThanEqual, DynLessThanEqual}};
+//!
+//! // Perhaps read from a configuration file
+//! let (min_len, max_len) = (3, 8);
+//! let name_length = DynClosedInterval::new(DynGreaterThanEqual::new(min_len), DynLessThanEqual::new(max_len));
+//!
+//! let name = Certified::new(&name_length, "refined".to_string()).unwrap();
+//! assert!(name.modify(|name| name + " types").is_err());
+//! ```
+//!
 //! ## Named refinement
 //!
 //! As you can see in the error messages in the first example, there are two possible fields that could have led to the error in refinement,
@@ -158,7 +176,7 @@
 //!
 //! assert!(Frobnicator::new("Good name".to_string(), 99).is_ok());
 //! assert_eq!(Frobnicator::new("Bad name, too long".to_string(), 99).unwrap_err().to_string(),
-//!            "refinement violated: name must be greater than or equal to 1 and must be less than or equal to 10");
+//!            "refinement violated: name must be less than or equal to 10");
 //! assert_eq!(Frobnicator::new("Good name".to_string(), 123).unwrap_err().to_string(),
 //!            "refinement violated: size must be less than or equal to 100");
 //! ```
@@ -248,6 +266,24 @@
 //! Note that the order matters here; the smaller range refinement can be implied to the larger range,
 //! but the opposite is logically invalid.
 //!
+//! The [boolean] combinators follow the usual rules of logic, so an interval implies each of its bounds,
+//! and any refinement implies a disjunction that includes it:
+//!
+//! ```
+//! #![allow(incomplete_features)]
+//! #![feature(generic_const_exprs)]
+//!
+//! use refined::{prelude::*, boolean::Or, boundable::unsigned::{ClosedInterval, Equals, GreaterThanEqual, LessThan}};
+//!
+//! let level: Refinement<u8, ClosedInterval<1, 10>> = Refinement::refine(5).unwrap();
+//! let at_least_one: Refinement<u8, GreaterThanEqual<1>> = level.imply();
+//! assert_eq!(*at_least_one, 5);
+//!
+//! let small: Refinement<u8, LessThan<10>> = Refinement::refine(5).unwrap();
+//! let small_or_max: Refinement<u8, Or<LessThan<20>, Equals<255>>> = small.imply();
+//! assert_eq!(*small_or_max, 5);
+//! ```
+//!
 //! ## Arithmetic
 //!
 //! With the `arithmetic` feature enabled, refinements with mutually compatible bounds can be operated on
@@ -276,14 +312,45 @@
 //! #![allow(incomplete_features)]
 //! #![feature(generic_const_exprs)]
 //!
-//! use refined::{prelude::*, boundable::signed::LessThan};
+//! use refined::{prelude::*, boundable::signed::{ClosedInterval, OpenInterval}};
 //!
-//! type LT100 = Refinement<i16, LessThan<100>>;
-//! type LT50 = Refinement<i16, LessThan<50>>;
-//! let result: Refinement<i16, LessThan<149>> = LT100::refine(99).unwrap() + LT50::refine(49).unwrap();
+//! type Small = Refinement<i16, OpenInterval<-100, 100>>;
+//! type Tiny = Refinement<i16, ClosedInterval<-50, 50>>;
+//! let result: Refinement<i16, OpenInterval<-150, 150>> = Small::refine(99).unwrap() + Tiny::refine(49).unwrap();
 //! assert_eq!(*result, 148);
 //! ````
 //!
+//! `Rem`, `Neg`, `Shl` and `Shr` are also supported, as is raising to a constant power with `pow`.
+//! Constants can be used as operands directly by way of `Equals`:
+//!
+//! ```
+//! #![allow(incomplete_features)]
+//! #![feature(generic_const_exprs)]
+//!
+//! use refined::{prelude::*, boundable::unsigned::{ClosedInterval, Equals, LessThanEqual}};
+//!
+//! let level = Refinement::<u16, ClosedInterval<1, 10>>::refine(4).unwrap();
+//! let next: Refinement<u16, ClosedInterval<2, 11>> = level + Equals::<1>;
+//! let squared: Refinement<u16, ClosedInterval<4, 121>> = next.pow::<2>();
+//! let digit: Refinement<u16, LessThanEqual<9>> = squared % Equals::<10>;
+//! assert_eq!(*digit, 5);
+//! ```
+//!
+//! When the result should keep the same refinement type, the `checked_*` and `saturating_*` methods of
+//! primitive integer refinements can be used instead. These are available without any features:
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::{ClosedInterval, LessThan}};
+//!
+//! let percent = Refinement::<u8, LessThan<100>>::refine(60).unwrap();
+//! assert_eq!(*percent.checked_add(30).unwrap(), 90);
+//! assert!(percent.checked_add(40).is_err());
+//!
+//! let volume = Refinement::<u8, ClosedInterval<1, 11>>::refine(10).unwrap();
+//! assert_eq!(*volume.saturating_add(5), 11);
+//! assert_eq!(*volume.saturating_sub(20), 1);
+//! ```
+//!
 //! # Provided refinements
 //!
 //! `refined` comes packaged with a large number of refinements over commonly used `std` types. The refinements
@@ -299,6 +366,12 @@
 //!   `i8`, `i64`, and `isize`
 //! * [boolean] contains "combinator" refinements that allow other refinements to be combined with one another. Examples include
 //!   [And](boolean::And) and [Or](boolean::Or)
+//! * [composite] contains refinements of tuples and structs, including cross-field predicates like [Ordered](composite::Ordered)
+//!   and the [Project](composite::Project) combinator that applies other refinements to individual fields
+//! * [collection] contains refinements of the elements of collections, such as [ForAll](collection::ForAll),
+//!   [Sorted](collection::Sorted), and [Unique](collection::Unique)
+//! * [float] contains refinements of [f32] and [f64]. Examples include [Finite](float::Finite), [NotNan](float::NotNan),
+//!   and [UnitInterval](float::UnitInterval)
 //! * [character] contains refinements of [char]. Examples include [IsLowercase](character::IsLowercase) and [IsWhitespace](character::IsWhitespace)
 //! * [string] contains refinements of any type that implements [AsRef\<str\>](AsRef). Examples include [Contains](string::Contains),
 //!   [Trimmed](string::Trimmed), and [Regex](string::Regex)
@@ -338,6 +411,53 @@
 //! a default feature in the future. See [my blog](https://jordankaye.dev/posts/refined_0_0_4/#optimized) for an example of
 //! the effect of this feature on generated assembly.
 //!
+//! ## `trusted`
+//!
+//! Enabling trusted provides the [trusted](mod@trusted) module, which allows refinements to be deserialized from trusted
+//! sources (such as data that was refined before it was stored) without testing their predicates a second time. Like
+//! `optimized`, this is not included in `full`: it trades a runtime check for an assumption about your data. To keep the
+//! soundness story unchanged, predicates are still tested when `optimized` is also enabled, as well as in debug builds.
+//! [Refinement::new_unchecked] is the equivalent for values that are not deserialized.
+//!
+//! ## `derive`
+//!
+//! Enabling derive provides the [Refined](macro@Refined) derive macro, which implements [RefinementOps] and
+//! related traits for newtype wrappers so that domain types can have their own names, inherent methods, and trait
+//! implementations. This carries a dependency on the `refined-derive` proc-macro crate and also requires the
+//! `alloc` feature.
+//!
+#![cfg_attr(feature = "derive", doc = "```")]
+#![cfg_attr(not(feature = "derive"), doc = "```ignore")]
+//! use refined::{prelude::*, boundable::unsigned::ClosedInterval, Refined};
+//!
+//! #[derive(Debug, Refined)]
+//! #[refined(predicate = ClosedInterval<1, 10>)]
+//! struct Rating(u8);
+//!
+//! assert_eq!(*Rating::refine(5).unwrap(), 5);
+//! assert_eq!(Rating::refine(11).unwrap_err().to_string(),
+//!            "refinement violated: Rating must be less than or equal to 10");
+//! ```
+//!
+//! The [RefineFields](macro@RefineFields) derive macro generates an unrefined mirror of a struct whose fields are
+//! refinements, allowing every field to be refined at once with all failures reported together as [FieldErrors]:
+//!
+#![cfg_attr(feature = "derive", doc = "```")]
+#![cfg_attr(not(feature = "derive"), doc = "```ignore")]
+//! use refined::{prelude::*, boundable::unsigned::{LessThanEqual, NonZero}, RefineFields};
+//!
+//! #[derive(Debug, RefineFields)]
+//! #[refined(raw = RawFrobnicator, raw_derive(Debug))]
+//! struct Frobnicator {
+//!   name: Refinement<String, NonZero>,
+//!   size: Refinement<u8, LessThanEqual<100>>,
+//! }
+//!
+//! let errors = Frobnicator::refine_fields(RawFrobnicator { name: String::new(), size: 101 }).unwrap_err();
+//! assert_eq!(errors.len(), 2);
+//! assert!(errors.get("size").is_some());
+//! ```
+//!
 //! ## `implication`
 //!
 //! Enabling implication allows the use of the [Implies] trait; this is behind an off-by-default
@@ -357,18 +477,51 @@
 //! Following the types that implement arithmetic can be difficult. The support for bounds across different types is not perfect,
 //! and may be improved in the future. Currently, support is provided for the four primary arithmetic operations
 //! ([core::ops::Add], [core::ops::Sub], [core::ops::Mul], and [core::ops::Div]) for all meaningful combinations of both
-//! signed and unsigned boundable ranges. For unsigned ranges, this means addition, multiplication, and division operations are implemented for all range types,
-//! while subtraction is implemented only for ranges with both minimum _and_ maximum bounds. For
-//! signed ranges, addition is implemented for all range types, while subtraction, multiplication, and division are implemented
-//! only for ranges with both minimum _and_ maximum bounds.
+//! signed and unsigned boundable ranges. For unsigned ranges, this means addition and multiplication are implemented for
+//! ranges with a maximum bound, subtraction for ranges with a minimum bound, and division for all range types. For
+//! signed ranges, all four operations are implemented only for ranges with both minimum _and_ maximum bounds.
+//!
+//! For example, [boundable::unsigned::LessThan] can be added, multiplied, or divided with any type
+//! that satisfies [implication::UnsignedMax], while [boundable::unsigned::GreaterThan] instead supports subtraction and
+//! division against [implication::UnsignedMax] and [implication::UnsignedMin] respectively. The range types support operations
+//! against one another via [implication::UnsignedMinMax].
 //!
-//! For example, [boundable::unsigned::LessThan] can be added, subtracted, multiplied, or divided with any type
-//! that satisfies [implication::UnsignedMax], while [boundable::unsigned::GreaterThan] instead supports operations
-//! against [implication::UnsignedMin]. The range types support operations against one another via [implication::UnsignedMinMax].
+//! The bounds of every result must fit within the range of the refined type, so that refined arithmetic can never
+//! overflow. Operations whose result bounds exceed the range, such as adding two `Refinement<u8, LessThan<200>>`, fail
+//! to build. Operations that could overflow on a side left unbounded by their operands, such as adding two
+//! [boundable::unsigned::GreaterThan] refinements, are not implemented.
 //!
 //! Similarly, the signed variants are [implication::SignedMin], [implication::SignedMax], and [implication::SignedMinMax].
 //!
 //! See the examples above for more intuition.
+//!
+//! ## `stable-implication` and `stable-arithmetic`
+//!
+//! These features provide [Implies] and bounds-tracked arithmetic without `generic_const_exprs`, so they can be
+//! used with the stable toolchain. Rather than excluding invalid conversions during type checking, conversions are
+//! verified by constant assertions when the program is built. Boundable comparisons, `Equals`, `Modulo`, and the
+//! [boolean] combinators are supported, while some rules relating negated predicates require `implication`.
+//!
+//! Arithmetic results are represented by the expression predicates of [implication::interval], which can be
+//! implied into regular boundable predicates:
+//!
+#![cfg_attr(
+    all(feature = "stable-arithmetic", not(feature = "arithmetic")),
+    doc = "```"
+)]
+#![cfg_attr(
+    not(all(feature = "stable-arithmetic", not(feature = "arithmetic"))),
+    doc = "```ignore"
+)]
+//! use refined::{prelude::*, boundable::unsigned::{ClosedInterval, LessThan}};
+//!
+//! let a: Refinement<u8, ClosedInterval<1, 10>> = Refinement::refine(9).unwrap();
+//! let b: Refinement<u8, ClosedInterval<1, 10>> = Refinement::refine(6).unwrap();
+//! let sum: Refinement<u8, LessThan<21>> = (a + b).imply();
+//! assert_eq!(*sum, 15);
+//! ```
+//!
+//! When `implication` or `arithmetic` are also enabled, they take precedence over their stable counterparts.
 #![cfg_attr(
     feature = "implication",
     allow(incomplete_features),
@@ -380,11 +533,8 @@
 #[cfg(feature = "alloc")]
 extern crate alloc;
 
-#[cfg(feature = "alloc")]
-use core::fmt::Display;
-
-#[cfg(feature = "alloc")]
-use thiserror::Error;
+#[cfg(all(test, feature = "derive"))]
+extern crate self as refined;
 
 #[cfg(feature = "serde")]
 use serde::{Deserialize, Serialize};
@@ -392,10 +542,38 @@ use serde::{Deserialize, Serialize};
 pub mod boolean;
 pub mod boundable;
 pub mod character;
+pub mod collection;
+pub mod composite;
+pub mod float;
 pub mod prelude;
+#[cfg_attr(docsrs, doc(cfg(all(feature = "serde", feature = "alloc"))))]
+#[cfg(all(feature = "serde", feature = "alloc"))]
+pub mod stateful_serde;
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 #[cfg(feature = "alloc")]
 pub mod string;
+#[cfg_attr(docsrs, doc(cfg(feature = "trusted")))]
+#[cfg(feature = "trusted")]
+pub mod trusted;
+
+mod error;
+pub use error::*;
+
+#[cfg(feature = "alloc")]
+mod fields;
+#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+#[cfg(feature = "alloc")]
+pub use fields::*;
+
+#[cfg(feature = "derive")]
+mod derive;
+#[cfg(feature = "derive")]
+#[doc(hidden)]
+pub use derive::__private;
+
+#[cfg_attr(docsrs, doc(cfg(feature = "derive")))]
+#[cfg(feature = "derive")]
+pub use refined_derive::{RefineFields, Refined};
 
 mod refinement;
 pub use refinement::*;
@@ -403,11 +581,17 @@ pub use refinement::*;
 pub use boundable::signed::SignedBoundable;
 pub use boundable::unsigned::UnsignedBoundable;
 
-#[cfg_attr(docsrs, doc(cfg(feature = "implication")))]
-#[cfg(feature = "implication")]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "implication", feature = "stable-implication")))
+)]
+#[cfg(any(feature = "implication", feature = "stable-implication"))]
 pub mod implication;
-#[cfg_attr(docsrs, doc(cfg(feature = "implication")))]
-#[cfg(feature = "implication")]
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "implication", feature = "stable-implication")))
+)]
+#[cfg(any(feature = "implication", feature = "stable-implication"))]
 pub use implication::*;
 
 /// A string lifted into a context where it can be used as a type.
@@ -422,7 +606,7 @@ pub trait TypeString: Default {
 ///
 /// `$name` is the name of a type to create to hold the type-level string.
 /// `$value` is the string that should be lifted into the type system.
-/// 
+///
 /// # Example
 ///
 /// ```
@@ -464,6 +648,49 @@ pub trait Predicate<T> {
     /// An error message to display when the predicate doesn't hold.
     fn error() -> ErrorMessage;
 
+    /// A structured description of the predicate to report when it doesn't hold.
+    ///
+    /// The default implementation reports a [custom](PredicateKind::Custom) violation using
+    /// the message from [Predicate::error]. Predicates provided by `refined` override this to
+    /// report their kind and bounds.
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn violation() -> Violation {
+        Violation::custom(Self::error())
+    }
+
+    /// A structured description of why `value` does not satisfy the predicate.
+    ///
+    /// This is used in place of [Predicate::violation] when refinement fails, allowing
+    /// predicates to inspect the rejected value and report only the parts of themselves that
+    /// were actually violated. The default implementation returns [Predicate::violation].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn explain(_value: &T) -> Violation {
+        Self::violation()
+    }
+
+    /// A structured description of why `value` satisfies the predicate.
+    ///
+    /// This is reported by [negations](crate::boolean::Not) of the predicate, allowing them to
+    /// describe only the parts of the predicate that actually held. The default implementation
+    /// returns [Predicate::violation].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn explain_satisfied(_value: &T) -> Violation {
+        Self::violation()
+    }
+
+    /// Whether certification depends upon state that is only available at runtime.
+    ///
+    /// The stateless [Predicate::test] of such predicates (for example, the `Dyn` bounds) cannot
+    /// hold for values that were certified statefully. Combinators propagate this from their children
+    /// and do not [optimize](Predicate::optimize) using their stateless test when it is set. Stateless
+    /// refinement (including `TryFrom`, deserialization, and `trusted` deserialization)
+    /// always rejects such predicates, even when a combinator such as [Not](boolean::Not) would invert
+    /// the result of their stateless test.
+    const RUNTIME_STATE: bool = false;
+
     /// Applies a potentially unsafe optimization to call sites that can take advantage of
     /// information provided by the predicate. This function is unused by `refined` unless
     /// the `optimized` feature is enabled.
@@ -509,6 +736,34 @@ pub trait StatefulPredicate<T>: Default + Predicate<T> {
         <Self as Predicate<T>>::error()
     }
 
+    /// A structured description of the predicate to report when it doesn't hold.
+    ///
+    /// The default implementation reports a [custom](PredicateKind::Custom) violation using
+    /// the message from [StatefulPredicate::error].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn violation(&self) -> Violation {
+        Violation::custom(self.error())
+    }
+
+    /// A structured description of why `value` does not satisfy the predicate.
+    ///
+    /// The default implementation returns [StatefulPredicate::violation].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn explain(&self, _value: &T) -> Violation {
+        self.violation()
+    }
+
+    /// A structured description of why `value` satisfies the predicate.
+    ///
+    /// The default implementation returns [StatefulPredicate::violation].
+    #[cfg(feature = "alloc")]
+    #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
+    fn explain_satisfied(&self, _value: &T) -> Violation {
+        self.violation()
+    }
+
     /// Applies a potentially unsafe optimization to call sites that can take advantage of
     /// information provided by the predicate. This function is unused by `refined` unless
     /// the `optimized` feature is enabled.
@@ -536,23 +791,64 @@ pub trait StatefulPredicate<T>: Default + Predicate<T> {
     unsafe fn optimize(_value: &T) {}
 }
 
-/// An internal implementation detail that must be exposed publicly for proper serde support.
-#[cfg_attr(feature = "serde", derive(Deserialize, Serialize), serde(transparent))]
-pub struct Refined<T>(T);
+/// A value that is known at compile time.
+///
+/// Constant values are most easily created using the [refine_const] macro, which defines a
+/// `ConstValue` for a literal and refines it in a single step.
+pub trait ConstValue<T> {
+    /// The constant value.
+    const VALUE: T;
+}
 
-/// An [Error] that can result from failed refinement.
-#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
-#[cfg_attr(feature = "alloc", derive(Error))]
-pub struct RefinementError(ErrorMessage);
+/// A [Predicate] that can be evaluated at compile time for a [ConstValue].
+///
+/// Boundable, character, and string predicates (over `&'static str`) provided by `refined`
+/// implement this trait, as do the boolean combinators of other constant predicates.
+pub trait ConstPredicate<T, V: ConstValue<T>>: Predicate<T> {
+    /// Whether the constant value satisfies the predicate.
+    const HOLDS: bool;
+}
 
-#[cfg(feature = "alloc")]
-#[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
-impl Display for RefinementError {
-    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
-        write!(f, "refinement violated: {}", self.0)
-    }
+/// Creates a [Refinement] from a constant value, checking the predicate at compile time.
+///
+/// `$type` is the refinement type to create and `$value` is a constant expression of its
+/// underlying type. Values that do not satisfy the predicate fail to compile.
+///
+/// # Example
+///
+/// ```
+/// use refined::{refine_const, Refinement, boundable::unsigned::ClosedInterval};
+///
+/// type Port = Refinement<u16, ClosedInterval<1, 65535>>;
+///
+/// const DEFAULT_PORT: Port = refine_const!(Port, 8080);
+/// assert_eq!(*DEFAULT_PORT, 8080);
+/// ```
+///
+/// ```compile_fail
+/// use refined::{refine_const, Refinement, boundable::unsigned::ClosedInterval};
+///
+/// type Port = Refinement<u16, ClosedInterval<1, 65535>>;
+///
+/// const BAD_PORT: Port = refine_const!(Port, 0);
+/// ```
+#[macro_export]
+macro_rules! refine_const {
+    ($type:ty, $value:expr) => {{
+        struct __RefinedConstValue;
+
+        impl $crate::ConstValue<<$type as $crate::RefinementOps>::T> for __RefinedConstValue {
+            const VALUE: <$type as $crate::RefinementOps>::T = $value;
+        }
+
+        <$type>::from_const::<__RefinedConstValue>()
+    }};
 }
 
+/// An internal implementation detail that must be exposed publicly for proper serde support.
+#[cfg_attr(feature = "serde", derive(Deserialize, Serialize), serde(transparent))]
+pub struct Refined<T>(T);
+
 /// Operations that can be made available on all types of refinement.
 pub trait RefinementOps:
     TryFrom<Refined<Self::T>, Error = RefinementError> + core::ops::Deref<Target = Self::T>
@@ -579,6 +875,29 @@ pub trait RefinementOps:
         Self::refine(value)
     }
 
+    /// Attempts to refine a runtime value with the type's imbued predicate, returning the
+    /// value alongside the error if it is rejected.
+    fn try_refine(value: Self::T) -> Result<Self, RejectedValue<Self::T>>;
+
+    /// Attempts a modification of a refined value, re-certifying that the predicate
+    /// still holds after the modification is complete.
+    ///
+    /// If the predicate does not hold, the modified value is returned alongside the error.
+    fn try_modify<F>(self, fun: F) -> Result<Self, RejectedValue<Self::T>>
+    where
+        F: FnOnce(Self::T) -> Self::T,
+    {
+        Self::try_refine(fun(self.take()))
+    }
+
+    /// Attempts a replacement of a refined value, re-certifying that the predicate
+    /// holds for the new value.
+    ///
+    /// If the predicate does not hold, the new value is returned alongside the error.
+    fn try_replace(self, value: Self::T) -> Result<Self, RejectedValue<Self::T>> {
+        Self::try_refine(value)
+    }
+
     /// Destructively removes the refined value from the `Refinement` wrapper.
     ///
     /// For a non-destructive version, use the [core::ops::Deref] implementation instead.
@@ -594,6 +913,43 @@ pub trait RefinementOps:
     fn extract(self) -> Self::T;
 }
 
+/// A [Predicate] that carries no runtime state.
+///
+/// Every stateless predicate is also a [StatefulPredicate] that ignores its (empty) state. This
+/// allows stateless predicates to be mixed with stateful ones in the [boolean] combinators, such as
+/// `And<Regex<S>, LessThan<10>>`. All stateless predicates provided by `refined` implement this
+/// trait; implement it for your own stateless predicates to use them the same way.
+pub trait Stateless: Default {}
+
+impl<T, P: Stateless + Predicate<T>> StatefulPredicate<T> for P {
+    fn test(&self, value: &T) -> bool {
+        <P as Predicate<T>>::test(value)
+    }
+
+    fn error(&self) -> ErrorMessage {
+        <P as Predicate<T>>::error()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn violation(&self) -> Violation {
+        <P as Predicate<T>>::violation()
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain(&self, value: &T) -> Violation {
+        <P as Predicate<T>>::explain(value)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn explain_satisfied(&self, value: &T) -> Violation {
+        <P as Predicate<T>>::explain_satisfied(value)
+    }
+
+    unsafe fn optimize(value: &T) {
+        <P as Predicate<T>>::optimize(value)
+    }
+}
+
 /// Operations that can be made available on all types of stateful refinement.
 pub trait StatefulRefinementOps<T, P: StatefulPredicate<T>>: RefinementOps<T = T> {
     /// Attempts to refine a runtime value with the type's imbued predicate, statefully.
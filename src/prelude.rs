@@ -1,8 +1,9 @@
 //! Convenience re-exports for the most common `refined` functionality.
 
 pub use crate::{
-    type_string, ErrorMessage, Predicate, Refinement, RefinementError, RefinementOps,
-    StatefulPredicate, StatefulRefinementOps, TypeString,
+    predicate, refine_const, type_string, Certified, ErrorMessage, Predicate, Refinement,
+    RefinementError, RefinementOps, RejectedValue, StatefulPredicate, StatefulRefinementOps,
+    Stateless, TypeString,
 };
 
 #[cfg(all(feature = "serde", feature = "alloc"))]
@@ -11,5 +12,5 @@ pub use crate::NamedSerde;
 #[cfg(feature = "alloc")]
 pub use crate::Named;
 
-#[cfg(feature = "implication")]
+#[cfg(any(feature = "implication", feature = "stable-implication"))]
 pub use crate::Implies;
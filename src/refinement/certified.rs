@@ -0,0 +1,135 @@
+use core::{fmt::Display, ops::Deref};
+
+use crate::{Refinement, RefinementError, StatefulPredicate, StatefulRefinementOps};
+
+/// A [Refinement] that is tied to the [StatefulPredicate] instance that certified it.
+///
+/// Stateful predicates with runtime parameters, such as
+/// [DynLessThan](crate::boundable::unsigned::DynLessThan), are only meaningful alongside the
+/// specific instance that was used to test a value. `Certified` keeps a reference to that
+/// instance so that all subsequent modifications are re-certified against the same state.
+///
+/// # Example
+///
+/// ```
+/// use refined::{Certified, boundable::unsigned::DynLessThan};
+///
+/// let max_upload = DynLessThan::new(1024);
+///
+/// let upload = Certified::new(&max_upload, 512u64).unwrap();
+/// assert_eq!(*upload, 512);
+/// assert_eq!(upload.predicate().max, 1024);
+///
+/// let upload = upload.modify(|size| size * 2).unwrap_err();
+/// assert_eq!(upload.to_string(), "refinement violated: must be less than 1024");
+/// ```
+pub struct Certified<'p, T, P: StatefulPredicate<T>> {
+    refinement: Refinement<T, P>,
+    predicate: &'p P,
+}
+
+impl<'p, T, P: StatefulPredicate<T>> Certified<'p, T, P> {
+    /// Attempts to refine `value` using `predicate`.
+    pub fn new(predicate: &'p P, value: T) -> Result<Self, RefinementError> {
+        Ok(Self {
+            refinement: Refinement::refine_with_state(predicate, value)?,
+            predicate,
+        })
+    }
+
+    /// The predicate instance that certified the value.
+    pub fn predicate(&self) -> &'p P {
+        self.predicate
+    }
+
+    /// Attempts a modification of the value, re-certifying it with the same predicate instance.
+    pub fn modify<F>(self, fun: F) -> Result<Self, RefinementError>
+    where
+        F: FnOnce(T) -> T,
+    {
+        Self::new(self.predicate, fun(self.refinement.0))
+    }
+
+    /// Attempts a replacement of the value, re-certifying it with the same predicate instance.
+    pub fn replace(self, value: T) -> Result<Self, RefinementError> {
+        Self::new(self.predicate, value)
+    }
+
+    /// Destructively removes the certified value.
+    pub fn take(self) -> T {
+        self.refinement.0
+    }
+
+    /// Discards the predicate instance, leaving only the refinement.
+    pub fn into_refinement(self) -> Refinement<T, P> {
+        self.refinement
+    }
+}
+
+impl<T: Clone, P: StatefulPredicate<T>> Clone for Certified<'_, T, P> {
+    fn clone(&self) -> Self {
+        Self {
+            refinement: Refinement(self.refinement.0.clone(), self.refinement.1),
+            predicate: self.predicate,
+        }
+    }
+}
+
+impl<T: core::fmt::Debug, P: StatefulPredicate<T> + core::fmt::Debug> core::fmt::Debug
+    for Certified<'_, T, P>
+{
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        f.debug_struct("Certified")
+            .field("value", &self.refinement.0)
+            .field("predicate", self.predicate)
+            .finish()
+    }
+}
+
+impl<T, P: StatefulPredicate<T>> Deref for Certified<'_, T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.refinement.0
+    }
+}
+
+impl<T, P: StatefulPredicate<T>> AsRef<Refinement<T, P>> for Certified<'_, T, P> {
+    fn as_ref(&self) -> &Refinement<T, P> {
+        &self.refinement
+    }
+}
+
+impl<T: Display, P: StatefulPredicate<T>> Display for Certified<'_, T, P> {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        write!(f, "{}", self.refinement.0)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::boundable::unsigned::*;
+
+    #[test]
+    fn test_certified_modify_uses_same_instance() {
+        let small = DynLessThan::new(5);
+        let large = DynLessThan::new(50);
+
+        let value = Certified::new(&large, 10u8).unwrap();
+        assert!(Certified::new(&small, 10u8).is_err());
+        let value = value.modify(|x| x * 4).unwrap();
+        assert_eq!(*value, 40);
+        assert!(value.clone().replace(50).is_err());
+        assert_eq!(value.predicate(), &large);
+        assert_eq!(value.take(), 40);
+    }
+
+    #[test]
+    fn test_certified_into_refinement() {
+        let bounds = DynClosedInterval::new(DynGreaterThanEqual::new(1), DynLessThanEqual::new(3));
+        let value = Certified::new(&bounds, 2u8).unwrap().into_refinement();
+        assert_eq!(*value, 2);
+        assert!(value.modify_with_state(&bounds, |x| x + 2).is_err());
+    }
+}
@@ -0,0 +1,560 @@
+use core::{borrow::Borrow, marker::PhantomData};
+
+use alloc::{
+    collections::{BTreeMap, BTreeSet, VecDeque},
+    vec::Vec,
+};
+#[cfg(feature = "std")]
+use std::{
+    collections::{HashMap, HashSet},
+    hash::Hash,
+};
+
+#[cfg(feature = "arithmetic")]
+use core::ops::{Add, Sub};
+
+#[cfg(feature = "arithmetic")]
+use crate::boundable::unsigned;
+use crate::{
+    boundable::{unsigned::UnsignedBoundable, UnsignedMin},
+    Predicate, Refinement, RefinementError, RejectedValue,
+};
+
+struct NonEmpty<C, P>(PhantomData<(C, P)>);
+
+impl<C: UnsignedBoundable, P: UnsignedMin<C>> NonEmpty<C, P> {
+    const CHECK: () = assert!(P::UMIN >= 1, "collection may be empty");
+}
+
+impl<C, P: Predicate<C>> Refinement<C, P> {
+    fn retest(&self) -> Result<(), RefinementError> {
+        if Self::test_stateless(&self.0) {
+            Ok(())
+        } else {
+            Err(RefinementError::of::<C, P>(&self.0))
+        }
+    }
+}
+
+impl<T, P: UnsignedMin<Vec<T>>> Refinement<Vec<T>, P> {
+    /// Returns the first element of a collection that is known to be non-empty.
+    ///
+    /// Fails to build if the predicate admits empty collections.
+    pub fn first_nonempty(&self) -> &T {
+        let () = NonEmpty::<Vec<T>, P>::CHECK;
+        self.0.first().expect("refined collection is non-empty")
+    }
+
+    /// Returns the last element of a collection that is known to be non-empty.
+    ///
+    /// Fails to build if the predicate admits empty collections.
+    pub fn last_nonempty(&self) -> &T {
+        let () = NonEmpty::<Vec<T>, P>::CHECK;
+        self.0.last().expect("refined collection is non-empty")
+    }
+
+    /// Returns the first element and the remaining elements of a collection that is known to be
+    /// non-empty.
+    ///
+    /// Fails to build if the predicate admits empty collections.
+    pub fn split_first_nonempty(&self) -> (&T, &[T]) {
+        let () = NonEmpty::<Vec<T>, P>::CHECK;
+        self.0
+            .split_first()
+            .expect("refined collection is non-empty")
+    }
+
+    /// Returns the last element and the remaining elements of a collection that is known to be
+    /// non-empty.
+    ///
+    /// Fails to build if the predicate admits empty collections.
+    pub fn split_last_nonempty(&self) -> (&T, &[T]) {
+        let () = NonEmpty::<Vec<T>, P>::CHECK;
+        self.0
+            .split_last()
+            .expect("refined collection is non-empty")
+    }
+}
+
+impl<T, P: Predicate<Vec<T>>> Refinement<Vec<T>, P> {
+    /// Attempts to append an element, re-certifying that the predicate still holds.
+    ///
+    /// If the predicate does not hold, the element is removed and returned alongside the error.
+    pub fn try_push(&mut self, value: T) -> Result<(), RejectedValue<T>> {
+        self.0.push(value);
+        self.retest().map_err(|error| {
+            let value = self.0.pop().expect("element was just pushed");
+            RejectedValue::new(value, error)
+        })
+    }
+
+    /// Attempts to remove the last element, re-certifying that the predicate still holds.
+    ///
+    /// If the predicate does not hold, the element is restored.
+    pub fn try_pop(&mut self) -> Result<Option<T>, RefinementError> {
+        let value = self.0.pop();
+        match self.retest() {
+            Ok(()) => Ok(value),
+            Err(error) => {
+                self.0.extend(value);
+                Err(error)
+            }
+        }
+    }
+
+    /// Attempts to append the elements of an iterator, re-certifying that the predicate still holds.
+    ///
+    /// If the predicate does not hold, the appended elements are removed and returned alongside the
+    /// error.
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), RejectedValue<Vec<T>>> {
+        let len = self.0.len();
+        self.0.extend(iter);
+        self.retest()
+            .map_err(|error| RejectedValue::new(self.0.split_off(len), error))
+    }
+}
+
+/// The refined length of a collection, tracked by the arithmetic on `usize` refinements.
+#[cfg(feature = "arithmetic")]
+type Length<P> = Refinement<usize, P>;
+
+#[cfg(feature = "arithmetic")]
+const ONE: Length<unsigned::Equals<1>> = Refinement(1, PhantomData);
+
+#[cfg_attr(docsrs, doc(cfg(feature = "arithmetic")))]
+#[cfg(feature = "arithmetic")]
+impl<T, P: Predicate<Vec<T>> + Predicate<usize>> Refinement<Vec<T>, P> {
+    /// Appends an element, producing a refinement whose length bounds are one greater.
+    ///
+    /// ```
+    /// #![allow(incomplete_features)]
+    /// #![feature(generic_const_exprs)]
+    ///
+    /// use refined::{boundable::unsigned::{ClosedInterval, LessThan}, prelude::*};
+    ///
+    /// let names = Refinement::<Vec<&str>, LessThan<3>>::refine(vec!["a", "b"]).unwrap();
+    /// let names: Refinement<Vec<&str>, LessThan<4>> = names.push("c");
+    /// assert_eq!(names.len(), 3);
+    ///
+    /// let names = Refinement::<Vec<&str>, ClosedInterval<1, 3>>::refine(vec!["a"]).unwrap();
+    /// let (names, last): (Refinement<Vec<&str>, ClosedInterval<0, 2>>, _) = names.pop();
+    /// assert_eq!(last, "a");
+    /// assert!(names.is_empty());
+    /// ```
+    pub fn push<Q: Predicate<Vec<T>> + Predicate<usize>>(
+        mut self,
+        value: T,
+    ) -> Refinement<Vec<T>, Q>
+    where
+        Length<P>: Add<Length<unsigned::Equals<1>>, Output = Length<Q>>,
+    {
+        let len = Refinement::<usize, P>(self.0.len(), PhantomData) + ONE;
+        self.0.push(value);
+        debug_assert_eq!(len.0, self.0.len());
+        Refinement(self.0, PhantomData)
+    }
+
+    /// Removes the last element of a collection that is known to be non-empty, producing a
+    /// refinement whose length bounds are one less.
+    pub fn pop<Q: Predicate<Vec<T>> + Predicate<usize>>(mut self) -> (Refinement<Vec<T>, Q>, T)
+    where
+        P: UnsignedMin<Vec<T>>,
+        Length<P>: Sub<Length<unsigned::Equals<1>>, Output = Length<Q>>,
+    {
+        let () = NonEmpty::<Vec<T>, P>::CHECK;
+        let len = Refinement::<usize, P>(self.0.len(), PhantomData) - ONE;
+        let value = self.0.pop().expect("refined collection is non-empty");
+        debug_assert_eq!(len.0, self.0.len());
+        (Refinement(self.0, PhantomData), value)
+    }
+
+    /// Removes the first element of a collection that is known to be non-empty, producing the
+    /// element and a refined tail whose length bounds are one less.
+    pub fn into_split_first<Q: Predicate<Vec<T>> + Predicate<usize>>(
+        mut self,
+    ) -> (T, Refinement<Vec<T>, Q>)
+    where
+        P: UnsignedMin<Vec<T>>,
+        Length<P>: Sub<Length<unsigned::Equals<1>>, Output = Length<Q>>,
+    {
+        let () = NonEmpty::<Vec<T>, P>::CHECK;
+        let len = Refinement::<usize, P>(self.0.len(), PhantomData) - ONE;
+        let value = self.0.remove(0);
+        debug_assert_eq!(len.0, self.0.len());
+        (value, Refinement(self.0, PhantomData))
+    }
+}
+
+impl<T, P: UnsignedMin<VecDeque<T>>> Refinement<VecDeque<T>, P> {
+    /// Returns the first element of a collection that is known to be non-empty.
+    ///
+    /// Fails to build if the predicate admits empty collections.
+    pub fn front_nonempty(&self) -> &T {
+        let () = NonEmpty::<VecDeque<T>, P>::CHECK;
+        self.0.front().expect("refined collection is non-empty")
+    }
+
+    /// Returns the last element of a collection that is known to be non-empty.
+    ///
+    /// Fails to build if the predicate admits empty collections.
+    pub fn back_nonempty(&self) -> &T {
+        let () = NonEmpty::<VecDeque<T>, P>::CHECK;
+        self.0.back().expect("refined collection is non-empty")
+    }
+}
+
+impl<T, P: Predicate<VecDeque<T>>> Refinement<VecDeque<T>, P> {
+    /// Attempts to append an element, re-certifying that the predicate still holds.
+    ///
+    /// If the predicate does not hold, the element is removed and returned alongside the error.
+    pub fn try_push_back(&mut self, value: T) -> Result<(), RejectedValue<T>> {
+        self.0.push_back(value);
+        self.retest().map_err(|error| {
+            let value = self.0.pop_back().expect("element was just pushed");
+            RejectedValue::new(value, error)
+        })
+    }
+
+    /// Attempts to prepend an element, re-certifying that the predicate still holds.
+    ///
+    /// If the predicate does not hold, the element is removed and returned alongside the error.
+    pub fn try_push_front(&mut self, value: T) -> Result<(), RejectedValue<T>> {
+        self.0.push_front(value);
+        self.retest().map_err(|error| {
+            let value = self.0.pop_front().expect("element was just pushed");
+            RejectedValue::new(value, error)
+        })
+    }
+
+    /// Attempts to remove the last element, re-certifying that the predicate still holds.
+    ///
+    /// If the predicate does not hold, the element is restored.
+    pub fn try_pop_back(&mut self) -> Result<Option<T>, RefinementError> {
+        let value = self.0.pop_back();
+        match self.retest() {
+            Ok(()) => Ok(value),
+            Err(error) => {
+                self.0.extend(value);
+                Err(error)
+            }
+        }
+    }
+
+    /// Attempts to remove the first element, re-certifying that the predicate still holds.
+    ///
+    /// If the predicate does not hold, the element is restored.
+    pub fn try_pop_front(&mut self) -> Result<Option<T>, RefinementError> {
+        let value = self.0.pop_front();
+        match self.retest() {
+            Ok(()) => Ok(value),
+            Err(error) => {
+                if let Some(value) = value {
+                    self.0.push_front(value);
+                }
+                Err(error)
+            }
+        }
+    }
+
+    /// Attempts to append the elements of an iterator, re-certifying that the predicate still holds.
+    ///
+    /// If the predicate does not hold, the appended elements are removed and returned alongside the
+    /// error.
+    pub fn try_extend<I: IntoIterator<Item = T>>(
+        &mut self,
+        iter: I,
+    ) -> Result<(), RejectedValue<VecDeque<T>>> {
+        let len = self.0.len();
+        self.0.extend(iter);
+        self.retest()
+            .map_err(|error| RejectedValue::new(self.0.split_off(len), error))
+    }
+}
+
+impl<K: Ord, V, P: UnsignedMin<BTreeMap<K, V>>> Refinement<BTreeMap<K, V>, P> {
+    /// Returns the first entry of a map that is known to be non-empty.
+    ///
+    /// Fails to build if the predicate admits empty collections.
+    pub fn first_key_value_nonempty(&self) -> (&K, &V) {
+        let () = NonEmpty::<BTreeMap<K, V>, P>::CHECK;
+        self.0
+            .first_key_value()
+            .expect("refined collection is non-empty")
+    }
+
+    /// Returns the last entry of a map that is known to be non-empty.
+    ///
+    /// Fails to build if the predicate admits empty collections.
+    pub fn last_key_value_nonempty(&self) -> (&K, &V) {
+        let () = NonEmpty::<BTreeMap<K, V>, P>::CHECK;
+        self.0
+            .last_key_value()
+            .expect("refined collection is non-empty")
+    }
+}
+
+impl<T: Ord, P: UnsignedMin<BTreeSet<T>>> Refinement<BTreeSet<T>, P> {
+    /// Returns the first element of a set that is known to be non-empty.
+    ///
+    /// Fails to build if the predicate admits empty collections.
+    pub fn first_nonempty(&self) -> &T {
+        let () = NonEmpty::<BTreeSet<T>, P>::CHECK;
+        self.0.first().expect("refined collection is non-empty")
+    }
+
+    /// Returns the last element of a set that is known to be non-empty.
+    ///
+    /// Fails to build if the predicate admits empty collections.
+    pub fn last_nonempty(&self) -> &T {
+        let () = NonEmpty::<BTreeSet<T>, P>::CHECK;
+        self.0.last().expect("refined collection is non-empty")
+    }
+}
+
+macro_rules! map_ops {
+    ($map:ident, $($key:tt)+) => {
+        impl<K: $($key)+ + Clone, V, P: Predicate<$map<K, V>>> Refinement<$map<K, V>, P> {
+            /// Attempts to insert an entry, re-certifying that the predicate still holds.
+            ///
+            /// If the predicate does not hold, any previous value is restored and the entry is
+            /// returned alongside the error.
+            pub fn try_insert(&mut self, key: K, value: V) -> Result<Option<V>, RejectedValue<(K, V)>> {
+                let previous = self.0.insert(key.clone(), value);
+                match self.retest() {
+                    Ok(()) => Ok(previous),
+                    Err(error) => {
+                        let (key, value) = self
+                            .0
+                            .remove_entry(&key)
+                            .expect("entry was just inserted");
+                        if let Some(previous) = previous {
+                            self.0.insert(key.clone(), previous);
+                        }
+                        Err(RejectedValue::new((key, value), error))
+                    }
+                }
+            }
+
+            /// Attempts to remove an entry, re-certifying that the predicate still holds.
+            ///
+            /// If the predicate does not hold, the entry is restored.
+            pub fn try_remove<Q: $($key)+ + ?Sized>(&mut self, key: &Q) -> Result<Option<V>, RefinementError>
+            where
+                K: Borrow<Q>,
+            {
+                let entry = self.0.remove_entry(key);
+                match self.retest() {
+                    Ok(()) => Ok(entry.map(|(_, value)| value)),
+                    Err(error) => {
+                        if let Some((key, value)) = entry {
+                            self.0.insert(key, value);
+                        }
+                        Err(error)
+                    }
+                }
+            }
+        }
+    };
+}
+
+macro_rules! set_ops {
+    ($set:ident, $($key:tt)+) => {
+        impl<T: $($key)+ + Clone, P: Predicate<$set<T>>> Refinement<$set<T>, P> {
+            /// Attempts to insert an element, re-certifying that the predicate still holds.
+            ///
+            /// If the predicate does not hold, a newly inserted element is removed and returned
+            /// alongside the error.
+            pub fn try_insert(&mut self, value: T) -> Result<bool, RejectedValue<T>> {
+                let inserted = self.0.insert(value.clone());
+                self.retest().map(|()| inserted).map_err(|error| {
+                    if inserted {
+                        self.0.remove(&value);
+                    }
+                    RejectedValue::new(value, error)
+                })
+            }
+
+            /// Attempts to remove an element, re-certifying that the predicate still holds.
+            ///
+            /// If the predicate does not hold, the element is restored.
+            pub fn try_remove<Q: $($key)+ + ?Sized>(&mut self, value: &Q) -> Result<bool, RefinementError>
+            where
+                T: Borrow<Q>,
+            {
+                let removed = self.0.take(value);
+                match self.retest() {
+                    Ok(()) => Ok(removed.is_some()),
+                    Err(error) => {
+                        if let Some(removed) = removed {
+                            self.0.insert(removed);
+                        }
+                        Err(error)
+                    }
+                }
+            }
+        }
+    };
+}
+
+map_ops!(BTreeMap, Ord);
+set_ops!(BTreeSet, Ord);
+
+#[cfg(feature = "std")]
+map_ops!(HashMap, Hash + Eq);
+#[cfg(feature = "std")]
+set_ops!(HashSet, Hash + Eq);
+
+#[cfg(test)]
+mod tests {
+    use alloc::{
+        collections::{BTreeMap, BTreeSet, VecDeque},
+        vec,
+        vec::Vec,
+    };
+
+    use crate::{boundable::unsigned::*, *};
+
+    #[test]
+    fn test_non_empty_access() {
+        let values = Refinement::<Vec<u8>, NonZero>::refine(vec![1, 2, 3]).unwrap();
+        assert_eq!(*values.first_nonempty(), 1);
+        assert_eq!(*values.last_nonempty(), 3);
+        assert_eq!(values.split_first_nonempty(), (&1, &[2, 3][..]));
+        assert_eq!(values.split_last_nonempty(), (&3, &[1, 2][..]));
+
+        let values = Refinement::<VecDeque<u8>, GreaterThan<0>>::refine([1, 2].into()).unwrap();
+        assert_eq!((*values.front_nonempty(), *values.back_nonempty()), (1, 2));
+
+        let values =
+            Refinement::<BTreeSet<u8>, ClosedInterval<1, 3>>::refine([2, 1].into()).unwrap();
+        assert_eq!((*values.first_nonempty(), *values.last_nonempty()), (1, 2));
+
+        let values =
+            Refinement::<BTreeMap<u8, char>, GreaterThanEqual<1>>::refine([(1, 'a')].into())
+                .unwrap();
+        assert_eq!(values.first_key_value_nonempty(), (&1, &'a'));
+        assert_eq!(values.last_key_value_nonempty(), (&1, &'a'));
+
+        // The `Option` returning methods remain reachable for every predicate.
+        let values = Refinement::<Vec<u8>, LessThan<3>>::refine(vec![]).unwrap();
+        assert_eq!(values.first(), None);
+        let values = Refinement::<Vec<u8>, ClosedInterval<0, 3>>::refine(vec![1]).unwrap();
+        assert_eq!(values.last(), Some(&1));
+    }
+
+    #[test]
+    fn test_try_push_pop() {
+        let mut values = Refinement::<Vec<u8>, ClosedInterval<1, 2>>::refine(vec![1]).unwrap();
+        assert!(values.try_push(2).is_ok());
+        let rejected = values.try_push(3).unwrap_err();
+        assert_eq!(*rejected.value(), 3);
+        assert_eq!(*values, vec![1, 2]);
+        assert_eq!(values.try_pop().unwrap(), Some(2));
+        assert!(values.try_pop().is_err());
+        assert_eq!(*values, vec![1]);
+    }
+
+    #[test]
+    fn test_try_runtime_state() {
+        use crate::boolean::Not;
+
+        let state = Not::new(DynLessThan::new(3));
+        let mut values =
+            Refinement::<Vec<u8>, Not<DynLessThan>>::refine_with_state(&state, vec![1, 2, 3])
+                .unwrap();
+        assert!(values.try_pop().is_err());
+        assert_eq!(*values, vec![1, 2, 3]);
+
+        let mut values = Refinement::<BTreeSet<u8>, Not<DynLessThan>>::refine_with_state(
+            &state,
+            [1, 2, 3].into(),
+        )
+        .unwrap();
+        assert!(values.try_remove(&1).is_err());
+        assert_eq!(*values, [1, 2, 3].into());
+    }
+
+    #[test]
+    fn test_try_extend() {
+        let mut values = Refinement::<Vec<u8>, LessThanEqual<3>>::refine(vec![1]).unwrap();
+        assert!(values.try_extend([2]).is_ok());
+        let rejected = values.try_extend([3, 4]).unwrap_err();
+        assert_eq!(rejected.into_value(), vec![3, 4]);
+        assert_eq!(*values, vec![1, 2]);
+
+        let mut values = Refinement::<VecDeque<u8>, LessThanEqual<2>>::refine([1].into()).unwrap();
+        assert!(values.try_extend([2, 3]).is_err());
+        assert_eq!(*values, [1]);
+    }
+
+    #[test]
+    fn test_try_deque() {
+        let mut values =
+            Refinement::<VecDeque<u8>, ClosedInterval<1, 2>>::refine([2].into()).unwrap();
+        assert!(values.try_push_front(1).is_ok());
+        assert!(values.try_push_back(3).is_err());
+        assert!(values.try_push_front(0).is_err());
+        assert_eq!(values.try_pop_front().unwrap(), Some(1));
+        assert!(values.try_pop_back().is_err());
+        assert_eq!(*values, [2]);
+    }
+
+    #[test]
+    fn test_try_map() {
+        let mut values =
+            Refinement::<BTreeMap<u8, char>, ClosedInterval<1, 2>>::refine([(1, 'a')].into())
+                .unwrap();
+        assert_eq!(values.try_insert(1, 'b').unwrap(), Some('a'));
+        assert_eq!(values.try_insert(2, 'c').unwrap(), None);
+        assert_eq!(
+            values.try_insert(3, 'd').unwrap_err().into_value(),
+            (3, 'd')
+        );
+        assert_eq!(values.try_remove(&1).unwrap(), Some('b'));
+        assert!(values.try_remove(&2).is_err());
+        assert_eq!(*values, [(2, 'c')].into());
+    }
+
+    #[test]
+    fn test_try_set() {
+        let mut values =
+            Refinement::<BTreeSet<u8>, ClosedInterval<1, 2>>::refine([1].into()).unwrap();
+        assert!(values.try_insert(2).unwrap());
+        assert!(!values.try_insert(2).unwrap());
+        assert_eq!(values.try_insert(3).unwrap_err().into_value(), 3);
+        assert!(values.try_remove(&1).unwrap());
+        assert!(values.try_remove(&2).is_err());
+        assert_eq!(*values, [2].into());
+    }
+
+    #[test]
+    #[cfg(feature = "std")]
+    fn test_try_hash() {
+        use std::collections::{HashMap, HashSet};
+
+        let mut map = Refinement::<HashMap<u8, char>, LessThan<2>>::refine(HashMap::new()).unwrap();
+        assert!(map.try_insert(1, 'a').is_ok());
+        assert!(map.try_insert(2, 'b').is_err());
+        assert_eq!(map.len(), 1);
+
+        let mut set = Refinement::<HashSet<u8>, GreaterThan<0>>::refine([1].into()).unwrap();
+        assert!(set.try_remove(&1).is_err());
+        assert!(set.contains(&1));
+    }
+
+    #[test]
+    #[cfg(feature = "arithmetic")]
+    fn test_typed_ops() {
+        let values = Refinement::<Vec<u8>, ClosedInterval<1, 2>>::refine(vec![1]).unwrap();
+        let values: Refinement<Vec<u8>, ClosedInterval<2, 3>> = values.push(2);
+        let (values, last): (Refinement<Vec<u8>, ClosedInterval<1, 2>>, _) = values.pop();
+        assert_eq!(last, 2);
+        let (head, tail): (_, Refinement<Vec<u8>, ClosedInterval<0, 1>>) =
+            values.into_split_first();
+        assert_eq!(head, 1);
+        assert!(tail.is_empty());
+    }
+}
@@ -0,0 +1,118 @@
+use core::{
+    marker::PhantomData,
+    ops::{Deref, DerefMut},
+};
+
+use crate::{Predicate, Refinement, RefinementError, RefinementOps, RejectedValue};
+
+/// An unrefined value taken out of a [Refinement] for modification.
+///
+/// Created by [Refinement::mutate]. The guard owns the value, allowing it to be modified in place
+/// without rebuilding values such as collections that are expensive to clone. The modified value is
+/// only refined again by [finish](RefinementGuard::finish), which tests the predicate;
+/// [check](RefinementGuard::check) can be used to test it beforehand. A guard that is dropped without
+/// being finished simply drops its value, so no refinement can ever hold a value that violates its
+/// predicate.
+///
+/// # Example
+///
+/// ```
+/// use refined::{prelude::*, boundable::unsigned::LessThan};
+///
+/// let names = Refinement::<Vec<&str>, LessThan<3>>::refine(vec!["alice"]).unwrap();
+///
+/// let mut guard = names.mutate();
+/// guard.push("bob");
+/// guard.push("carol");
+/// assert!(guard.check().is_err());
+/// guard.pop();
+/// let names = guard.finish().unwrap();
+///
+/// assert_eq!(*names, vec!["alice", "bob"]);
+/// ```
+#[derive(Debug)]
+pub struct RefinementGuard<T, P: Predicate<T>> {
+    value: T,
+    predicate: PhantomData<P>,
+}
+
+impl<T, P: Predicate<T>> RefinementGuard<T, P> {
+    pub(crate) fn new(value: T) -> Self {
+        Self {
+            value,
+            predicate: PhantomData,
+        }
+    }
+
+    /// Tests whether the modified value still satisfies the predicate.
+    pub fn check(&self) -> Result<(), RefinementError> {
+        if Refinement::<T, P>::test_stateless(&self.value) {
+            Ok(())
+        } else {
+            Err(RefinementError::of::<T, P>(&self.value))
+        }
+    }
+
+    /// Completes the modification, refining the modified value with the predicate.
+    ///
+    /// If the predicate no longer holds, the modified value is returned alongside the error.
+    pub fn finish(self) -> Result<Refinement<T, P>, RejectedValue<T>> {
+        Refinement::try_refine(self.value)
+    }
+}
+
+impl<T, P: Predicate<T>> Deref for RefinementGuard<T, P> {
+    type Target = T;
+
+    fn deref(&self) -> &Self::Target {
+        &self.value
+    }
+}
+
+impl<T, P: Predicate<T>> DerefMut for RefinementGuard<T, P> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.value
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::unsigned::*, *};
+
+    #[test]
+    fn test_guard() {
+        let value = Refinement::<u8, LessThan<10>>::refine(5).unwrap();
+        let mut guard = value.mutate();
+        *guard += 4;
+        assert!(guard.check().is_ok());
+        *guard += 1;
+        assert!(guard.check().is_err());
+        *guard -= 2;
+        assert_eq!(*guard.finish().unwrap(), 8);
+    }
+
+    #[test]
+    fn test_guard_violation() {
+        let value = Refinement::<u8, LessThan<10>>::refine(5).unwrap();
+        let mut guard = value.mutate();
+        *guard = 10;
+        let rejected = guard.finish().unwrap_err();
+        assert_eq!(rejected.into_value(), 10);
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_try_mutate_unwind() {
+        let mut value = Refinement::<u8, LessThan<10>>::refine(5).unwrap();
+        let result = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+            value
+                .try_mutate(|x| {
+                    *x = 20;
+                    panic!("interrupted");
+                })
+                .ok();
+        }));
+        assert!(result.is_err());
+        assert_eq!(*value, 5);
+    }
+}
@@ -1,8 +1,15 @@
+mod certified;
+#[cfg(feature = "alloc")]
+mod collection;
+mod guard;
 #[cfg(feature = "alloc")]
 mod named;
+mod ops;
 
 use core::{fmt::Display, marker::PhantomData};
 
+pub use certified::*;
+pub use guard::*;
 #[cfg_attr(docsrs, doc(cfg(feature = "alloc")))]
 #[cfg(feature = "alloc")]
 pub use named::*;
@@ -13,16 +20,94 @@ use serde::Deserialize;
 use serde::Serialize;
 
 use crate::{
-    Predicate, Refined, RefinementError, RefinementOps, StatefulPredicate, StatefulRefinementOps,
+    ConstPredicate, ConstValue, Predicate, Refined, RefinementError, RefinementOps, RejectedValue,
+    StatefulPredicate, StatefulRefinementOps,
 };
 
-#[cfg(feature = "implication")]
+#[cfg(any(feature = "implication", feature = "stable-implication"))]
 use crate::Implies;
 
 /// A refinement of a type `T` certifying that the [Predicate] `P` holds.
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct Refinement<T, P: Predicate<T>>(pub(crate) T, pub(crate) PhantomData<P>);
 
+struct ConstCheck<T, P, V>(PhantomData<(T, P, V)>);
+
+impl<T, V: ConstValue<T>, P: ConstPredicate<T, V>> ConstCheck<T, P, V> {
+    const HOLDS: () = assert!(P::HOLDS, "constant value does not satisfy the refinement");
+}
+
+impl<T, P: Predicate<T>> Refinement<T, P> {
+    /// Whether `value` is certified by the stateless test of the predicate.
+    ///
+    /// Predicates with [runtime state](Predicate::RUNTIME_STATE) can only be certified statefully,
+    /// as combinators such as [Not](crate::boolean::Not) would otherwise invert their stateless test.
+    pub(crate) fn test_stateless(value: &T) -> bool {
+        !P::RUNTIME_STATE && P::test(value)
+    }
+
+    /// Refines a [ConstValue], checking the predicate at compile time.
+    ///
+    /// Values that do not satisfy the predicate fail to compile. The [refine_const](crate::refine_const)
+    /// macro is generally more convenient than implementing [ConstValue] directly.
+    pub const fn from_const<V: ConstValue<T>>() -> Self
+    where
+        P: ConstPredicate<T, V>,
+    {
+        let () = ConstCheck::<T, P, V>::HOLDS;
+        Self(V::VALUE, PhantomData)
+    }
+
+    /// Creates a refinement without testing the predicate.
+    ///
+    /// This is intended for trusted data paths, such as values that were already refined by a
+    /// previous stage, where testing the predicate a second time is wasteful. In debug builds,
+    /// the predicate is still checked using [debug_assert].
+    ///
+    /// # Safety
+    ///
+    /// `value` must satisfy the predicate `P`. Much like [Predicate::optimize], this turns a
+    /// _correctness_ property into a _soundness_ property: when the `optimized` feature is enabled,
+    /// the compiler is permitted to assume that every refinement satisfies its predicate, so
+    /// constructing one from an invalid value can lead to undefined behavior.
+    pub unsafe fn new_unchecked(value: T) -> Self {
+        debug_assert!(
+            P::test(&value),
+            "Refinement::new_unchecked called with a value that does not satisfy the predicate"
+        );
+        Self(value, PhantomData)
+    }
+
+    /// Attempts an in-place modification of a refined value, re-certifying that the predicate
+    /// still holds after the modification is complete.
+    ///
+    /// The modification is applied to a copy of the value, which replaces the refined value only if
+    /// the predicate holds. Otherwise (or if `fun` panics), the refined value is left unchanged.
+    pub fn try_mutate<F>(&mut self, fun: F) -> Result<(), RefinementError>
+    where
+        T: Clone,
+        F: FnOnce(&mut T),
+    {
+        let mut modified = self.0.clone();
+        fun(&mut modified);
+        if Self::test_stateless(&modified) {
+            self.0 = modified;
+            Ok(())
+        } else {
+            Err(RefinementError::of::<T, P>(&modified))
+        }
+    }
+
+    /// Takes the refined value out for in-place modification through a [RefinementGuard], which
+    /// refines it again when [finished](RefinementGuard::finish).
+    ///
+    /// Unlike [try_mutate](Refinement::try_mutate), the value is not cloned, but the refinement is
+    /// consumed: if the modification violates the predicate, only the modified value is recovered.
+    pub fn mutate(self) -> RefinementGuard<T, P> {
+        RefinementGuard::new(self.0)
+    }
+}
+
 #[cfg_attr(docsrs, doc(cfg(feature = "serde")))]
 #[cfg(feature = "serde")]
 impl<T: Serialize, P: Predicate<T>> Serialize for Refinement<T, P> {
@@ -49,6 +134,15 @@ impl<'de, T: Deserialize<'de>, P: Predicate<T>> Deserialize<'de> for Refinement<
 impl<T, P: Predicate<T>> RefinementOps for Refinement<T, P> {
     type T = T;
 
+    fn try_refine(value: T) -> Result<Self, RejectedValue<T>> {
+        if Self::test_stateless(&value) {
+            Ok(Self(value, PhantomData))
+        } else {
+            let error = RefinementError::of::<T, P>(&value);
+            Err(RejectedValue::new(value, error))
+        }
+    }
+
     fn take(self) -> T {
         #[cfg(feature = "optimized")]
         unsafe {
@@ -94,22 +188,22 @@ impl<T, P: Predicate<T>> TryFrom<Refined<T>> for Refinement<T, P> {
     type Error = RefinementError;
 
     fn try_from(value: Refined<T>) -> Result<Self, Self::Error> {
-        if P::test(&value.0) {
-            Ok(Self(value.0, PhantomData))
-        } else {
-            Err(RefinementError(P::error()))
-        }
+        Self::try_refine(value.0).map_err(RejectedValue::into_error)
     }
 }
 
-#[cfg_attr(docsrs, doc(cfg(feature = "implication")))]
-#[cfg(feature = "implication")]
-impl<F, T, Type> Implies<Refinement<Type, T>> for Refinement<Type, F>
+#[cfg_attr(
+    docsrs,
+    doc(cfg(any(feature = "implication", feature = "stable-implication")))
+)]
+#[cfg(any(feature = "implication", feature = "stable-implication"))]
+impl<F, T, Type, I> Implies<Refinement<Type, T>, I> for Refinement<Type, F>
 where
-    F: Predicate<Type> + Implies<T>,
+    F: Predicate<Type> + Implies<T, I>,
     T: Predicate<Type>,
 {
     fn imply(self) -> Refinement<Type, T> {
+        let () = <F as Implies<T, I>>::CHECK;
         Refinement(self.0, PhantomData)
     }
 }
@@ -119,7 +213,7 @@ impl<T, P: StatefulPredicate<T>> StatefulRefinementOps<T, P> for Refinement<T, P
         if predicate.test(&value) {
             Ok(Self(value, PhantomData))
         } else {
-            Err(RefinementError(predicate.error()))
+            Err(RefinementError::of_stateful(predicate, &value))
         }
     }
 }
@@ -188,6 +282,37 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_refinement_try_mutate_success() {
+        let mut value = Refinement::<u8, boundable::unsigned::LessThan<5>>(3, PhantomData);
+        value.try_mutate(|x| *x += 1).unwrap();
+        assert_eq!(*value, 4);
+    }
+
+    #[test]
+    fn test_refinement_try_mutate_failure() {
+        let mut value = Refinement::<u8, boundable::unsigned::LessThan<5>>(4, PhantomData);
+        let err = value.try_mutate(|x| *x += 1).unwrap_err();
+        assert_eq!(
+            format!("{}", err),
+            "refinement violated: must be less than 5"
+        );
+        assert_eq!(*value, 4);
+    }
+
+    #[test]
+    fn test_refinement_new_unchecked() {
+        let value = unsafe { Refinement::<u8, boundable::unsigned::LessThan<5>>::new_unchecked(4) };
+        assert_eq!(*value, 4);
+    }
+
+    #[cfg(debug_assertions)]
+    #[test]
+    #[should_panic(expected = "does not satisfy the predicate")]
+    fn test_refinement_new_unchecked_debug_assert() {
+        let _ = unsafe { Refinement::<u8, boundable::unsigned::LessThan<5>>::new_unchecked(5) };
+    }
+
     #[test]
     fn test_refinement_take() {
         let value = Refinement::<u8, boundable::unsigned::LessThan<5>>(4, PhantomData);
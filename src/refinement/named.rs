@@ -1,8 +1,8 @@
-use alloc::format;
 use core::{marker::PhantomData, ops::Deref};
 
 use crate::{
-    Refined, RefinementError, RefinementOps, StatefulPredicate, StatefulRefinementOps, TypeString,
+    Refined, RefinementError, RefinementOps, RejectedValue, StatefulPredicate,
+    StatefulRefinementOps, TypeString,
 };
 
 /// A named refinement over a refinement type `R`.
@@ -45,10 +45,7 @@ impl<N: TypeString, R: RefinementOps> TryFrom<Refined<R::T>> for Named<N, R> {
     type Error = RefinementError;
 
     fn try_from(value: Refined<R::T>) -> Result<Self, Self::Error> {
-        match R::refine(value.0) {
-            Ok(value) => Ok(Self(value, PhantomData)),
-            Err(err) => Err(RefinementError(format!("{} {}", N::VALUE, err.0))),
-        }
+        Self::try_refine(value.0).map_err(RejectedValue::into_error)
     }
 }
 
@@ -61,6 +58,13 @@ impl<N: TypeString, R: RefinementOps> From<Named<N, R>> for Refined<R::T> {
 impl<N: TypeString, R: RefinementOps> RefinementOps for Named<N, R> {
     type T = R::T;
 
+    fn try_refine(value: R::T) -> Result<Self, RejectedValue<R::T>> {
+        match R::try_refine(value) {
+            Ok(value) => Ok(Self(value, PhantomData)),
+            Err(rejected) => Err(rejected.named(N::VALUE)),
+        }
+    }
+
     fn take(self) -> Self::T {
         self.0.take()
     }
@@ -76,7 +80,7 @@ impl<N: TypeString, T, P: StatefulPredicate<T>, R: StatefulRefinementOps<T, P>>
     fn refine_with_state(predicate: &P, value: T) -> Result<Self, RefinementError> {
         match R::refine_with_state(predicate, value) {
             Ok(value) => Ok(Self(value, PhantomData)),
-            Err(err) => Err(RefinementError(format!("{} {}", N::VALUE, err.0))),
+            Err(err) => Err(err.named(N::VALUE)),
         }
     }
 }
@@ -148,10 +152,7 @@ mod named_serde {
         type Error = RefinementError;
 
         fn try_from(value: Refined<R::T>) -> Result<Self, Self::Error> {
-            match R::refine(value.0) {
-                Ok(value) => Ok(Self(value, PhantomData)),
-                Err(err) => Err(RefinementError(format!("{} {}", N::VALUE, err.0))),
-            }
+            Self::try_refine(value.0).map_err(RejectedValue::into_error)
         }
     }
 
@@ -170,6 +171,13 @@ mod named_serde {
     {
         type T = R::T;
 
+        fn try_refine(value: R::T) -> Result<Self, RejectedValue<R::T>> {
+            match R::try_refine(value) {
+                Ok(value) => Ok(Self(value, PhantomData)),
+                Err(rejected) => Err(rejected.named(N::VALUE)),
+            }
+        }
+
         fn take(self) -> Self::T {
             self.0.take()
         }
@@ -189,7 +197,7 @@ mod named_serde {
         fn refine_with_state(predicate: &P, value: T) -> Result<Self, RefinementError> {
             match R::refine_with_state(predicate, value) {
                 Ok(value) => Ok(Self(value, PhantomData)),
-                Err(err) => Err(RefinementError(format!("{} {}", N::VALUE, err.0))),
+                Err(err) => Err(err.named(N::VALUE)),
             }
         }
     }
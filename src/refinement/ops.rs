@@ -0,0 +1,274 @@
+use core::{
+    marker::PhantomData,
+    ops::{AddAssign, SubAssign},
+};
+
+use crate::{
+    boundable::{
+        signed, signed::SignedBoundable, unsigned, unsigned::UnsignedBoundable, SignedMax,
+        SignedMin, SignedMinMax, UnsignedMax, UnsignedMin, UnsignedMinMax,
+    },
+    Predicate, Refinement, RefinementError, RefinementOps,
+};
+
+macro_rules! checked_ops {
+    ($t:ty; $($checked:ident, $op:ident, $message:literal);+) => {
+        impl<P: Predicate<$t>> Refinement<$t, P> {
+            $(
+                #[doc = concat!(
+                    "Applies `", stringify!($op), "` to the refined value, re-refining the result with the same predicate.\n\n",
+                    "Fails if the result does not satisfy the predicate, or if the operation overflows."
+                )]
+                pub fn $checked(self, rhs: $t) -> Result<Self, RefinementError> {
+                    match self.0.$checked(rhs) {
+                        Some(value) => Self::refine(value),
+                        None => Err(RefinementError::arithmetic($message)),
+                    }
+                }
+            )+
+        }
+    };
+}
+
+macro_rules! wrapping_ops {
+    ($t:ty; $($wrapping:ident, $op:ident);+) => {
+        impl<P: Predicate<$t>> Refinement<$t, P> {
+            $(
+                #[doc = concat!(
+                    "Applies `", stringify!($op), "` to the refined value, wrapping around at the boundary of the type, ",
+                    "and re-refines the result with the same predicate.\n\n",
+                    "Fails if the result does not satisfy the predicate."
+                )]
+                pub fn $wrapping(self, rhs: $t) -> Result<Self, RefinementError> {
+                    Self::refine(self.0.$wrapping(rhs))
+                }
+            )+
+        }
+    };
+}
+
+macro_rules! saturating_ops {
+    ($t:ty, $boundable:ident, $minmax:ident, $min:ident, $max:ident; $($saturating:ident, $op:ident);+) => {
+        impl<P: $minmax<$t>> Refinement<$t, P> {
+            $(
+                #[doc = concat!(
+                    "Applies `", stringify!($op), "` to the refined value, clamping the result into the bounds of the predicate.\n\n",
+                    "The predicate must be satisfied by every value between its minimum and maximum, as is the case for the ",
+                    "interval predicates and `Equals`."
+                )]
+                pub fn $saturating(self, rhs: $t) -> Self {
+                    let value = self.0.$saturating(rhs);
+                    let clamped = <$t as $boundable>::bounding_value(&value)
+                        .clamp(<P as $min<$t>>::UMIN, <P as $max<$t>>::UMAX);
+                    Self::refine(clamped as $t)
+                        .expect("saturated value must satisfy the predicate")
+                }
+            )+
+        }
+    };
+}
+
+macro_rules! arithmetic_primitive {
+    ($boundable:ident, $minmax:ident, $min:ident, $max:ident; $($t:ty),+) => {
+        $(
+            checked_ops!(
+                $t;
+                checked_add, add, "attempt to add with overflow";
+                checked_sub, sub, "attempt to subtract with overflow";
+                checked_mul, mul, "attempt to multiply with overflow";
+                checked_div, div, "attempt to divide by zero or with overflow"
+            );
+            wrapping_ops!(
+                $t;
+                wrapping_add, add;
+                wrapping_sub, sub;
+                wrapping_mul, mul
+            );
+            saturating_ops!(
+                $t, $boundable, $minmax, $min, $max;
+                saturating_add, add;
+                saturating_sub, sub;
+                saturating_mul, mul;
+                saturating_div, div
+            );
+        )+
+    };
+}
+
+arithmetic_primitive!(
+    UnsignedBoundable, UnsignedMinMax, UnsignedMin, UnsignedMax;
+    u8, u16, u32, u64, u128, usize
+);
+arithmetic_primitive!(
+    SignedBoundable, SignedMinMax, SignedMin, SignedMax;
+    i8, i16, i32, i64, i128, isize
+);
+
+struct Sign<T, B>(PhantomData<(T, B)>);
+
+impl<T: SignedBoundable, B: SignedMin<T>> Sign<T, B> {
+    const NON_NEGATIVE: () = assert!(B::UMIN >= 0, "operand may be negative");
+}
+
+impl<T: SignedBoundable, B: SignedMax<T>> Sign<T, B> {
+    const NON_POSITIVE: () = assert!(B::UMAX <= 0, "operand may be positive");
+}
+
+macro_rules! assign_op {
+    ([$($param:tt)*] $assign:ident, $fun:ident, $t:ty, $pred:ty, $rhs:ty, $checked:ident, $message:literal; |$value:ident| $operand:expr $(, $check:expr)?) => {
+        impl<$($param)*> $assign<$rhs> for Refinement<$t, $pred> {
+            fn $fun(&mut self, $value: $rhs) {
+                $(let () = $check;)?
+                self.0 = self.0.$checked($operand).expect($message);
+            }
+        }
+    };
+}
+
+macro_rules! assign_primitive {
+    (unsigned; $($t:ty),+) => {
+        $(
+            assign_op!([const MIN: u128] AddAssign, add_assign, $t, unsigned::GreaterThan<MIN>, $t, checked_add, "attempt to add with overflow"; |rhs| rhs);
+            assign_op!([const MIN: u128] AddAssign, add_assign, $t, unsigned::GreaterThanEqual<MIN>, $t, checked_add, "attempt to add with overflow"; |rhs| rhs);
+            assign_op!([const MAX: u128] SubAssign, sub_assign, $t, unsigned::LessThan<MAX>, $t, checked_sub, "attempt to subtract with overflow"; |rhs| rhs);
+            assign_op!([const MAX: u128] SubAssign, sub_assign, $t, unsigned::LessThanEqual<MAX>, $t, checked_sub, "attempt to subtract with overflow"; |rhs| rhs);
+            assign_op!([const DIV: u128] AddAssign, add_assign, $t, unsigned::Modulo<DIV, 0>, Self, checked_add, "attempt to add with overflow"; |rhs| rhs.0);
+            assign_op!([const DIV: u128] SubAssign, sub_assign, $t, unsigned::Modulo<DIV, 0>, Self, checked_sub, "attempt to subtract with overflow"; |rhs| rhs.0);
+        )+
+    };
+    (signed; $($t:ty),+) => {
+        $(
+            assign_op!([const MIN: i128, B: SignedMin<$t>] AddAssign, add_assign, $t, signed::GreaterThan<MIN>, Refinement<$t, B>, checked_add, "attempt to add with overflow"; |rhs| rhs.0, Sign::<$t, B>::NON_NEGATIVE);
+            assign_op!([const MIN: i128, B: SignedMin<$t>] AddAssign, add_assign, $t, signed::GreaterThanEqual<MIN>, Refinement<$t, B>, checked_add, "attempt to add with overflow"; |rhs| rhs.0, Sign::<$t, B>::NON_NEGATIVE);
+            assign_op!([const MIN: i128, B: SignedMax<$t>] SubAssign, sub_assign, $t, signed::GreaterThan<MIN>, Refinement<$t, B>, checked_sub, "attempt to subtract with overflow"; |rhs| rhs.0, Sign::<$t, B>::NON_POSITIVE);
+            assign_op!([const MIN: i128, B: SignedMax<$t>] SubAssign, sub_assign, $t, signed::GreaterThanEqual<MIN>, Refinement<$t, B>, checked_sub, "attempt to subtract with overflow"; |rhs| rhs.0, Sign::<$t, B>::NON_POSITIVE);
+            assign_op!([const MAX: i128, B: SignedMax<$t>] AddAssign, add_assign, $t, signed::LessThan<MAX>, Refinement<$t, B>, checked_add, "attempt to add with overflow"; |rhs| rhs.0, Sign::<$t, B>::NON_POSITIVE);
+            assign_op!([const MAX: i128, B: SignedMax<$t>] AddAssign, add_assign, $t, signed::LessThanEqual<MAX>, Refinement<$t, B>, checked_add, "attempt to add with overflow"; |rhs| rhs.0, Sign::<$t, B>::NON_POSITIVE);
+            assign_op!([const MAX: i128, B: SignedMin<$t>] SubAssign, sub_assign, $t, signed::LessThan<MAX>, Refinement<$t, B>, checked_sub, "attempt to subtract with overflow"; |rhs| rhs.0, Sign::<$t, B>::NON_NEGATIVE);
+            assign_op!([const MAX: i128, B: SignedMin<$t>] SubAssign, sub_assign, $t, signed::LessThanEqual<MAX>, Refinement<$t, B>, checked_sub, "attempt to subtract with overflow"; |rhs| rhs.0, Sign::<$t, B>::NON_NEGATIVE);
+            assign_op!([const DIV: i128] AddAssign, add_assign, $t, signed::Modulo<DIV, 0>, Self, checked_add, "attempt to add with overflow"; |rhs| rhs.0);
+            assign_op!([const DIV: i128] SubAssign, sub_assign, $t, signed::Modulo<DIV, 0>, Self, checked_sub, "attempt to subtract with overflow"; |rhs| rhs.0);
+        )+
+    };
+}
+
+assign_primitive!(unsigned; u8, u16, u32, u64, u128, usize);
+assign_primitive!(signed; i8, i16, i32, i64, i128, isize);
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::*, *};
+
+    #[test]
+    fn test_checked_unsigned() {
+        let a = Refinement::<u8, unsigned::LessThan<100>>::refine(60).unwrap();
+        assert_eq!(*a.checked_add(30).unwrap(), 90);
+        assert!(a.checked_add(40).is_err());
+        assert!(a.checked_sub(61).is_err());
+        assert_eq!(*a.checked_mul(1).unwrap(), 60);
+        assert!(a.checked_div(0).is_err());
+        let b = Refinement::<u8, unsigned::GreaterThan<100>>::refine(200).unwrap();
+        assert!(b.checked_add(100).is_err());
+    }
+
+    #[test]
+    fn test_checked_signed() {
+        let a = Refinement::<i16, signed::ClosedInterval<-10, 10>>::refine(-5).unwrap();
+        assert_eq!(*a.checked_mul(-2).unwrap(), 10);
+        assert!(a.checked_sub(6).is_err());
+        let b = Refinement::<i8, signed::Negative>::refine(i8::MIN).unwrap();
+        assert!(b.checked_div(-1).is_err());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_checked_errors() {
+        use alloc::string::ToString;
+
+        let a = Refinement::<u8, unsigned::LessThan<100>>::refine(60).unwrap();
+        assert_eq!(
+            a.checked_add(40).unwrap_err().to_string(),
+            "refinement violated: must be less than 100"
+        );
+        assert_eq!(
+            a.checked_add(200).unwrap_err().to_string(),
+            "refinement violated: attempt to add with overflow"
+        );
+    }
+
+    #[test]
+    fn test_wrapping() {
+        let a = Refinement::<u8, unsigned::Modulo<2, 0>>::refine(250).unwrap();
+        assert_eq!(*a.wrapping_add(10).unwrap(), 4);
+        assert!(a.wrapping_add(11).is_err());
+        assert_eq!(*a.wrapping_mul(3).unwrap(), 238);
+        let b = Refinement::<u8, unsigned::LessThan<100>>::refine(60).unwrap();
+        assert!(b.wrapping_sub(61).is_err());
+        let c = Refinement::<i8, signed::Odd>::refine(i8::MIN + 1).unwrap();
+        assert_eq!(*c.wrapping_sub(2).unwrap(), 127);
+    }
+
+    #[test]
+    fn test_shadows_primitive_methods() {
+        let a = Refinement::<u8, unsigned::LessThan<100>>::refine(60).unwrap();
+        assert!(a.checked_add(200).is_err());
+        assert_eq!((*a).checked_add(200), None);
+        assert_eq!(a.take().checked_add(30), Some(90));
+    }
+
+    #[test]
+    fn test_saturating_unsigned() {
+        let a = Refinement::<u8, unsigned::ClosedInterval<10, 100>>::refine(60).unwrap();
+        assert_eq!(*a.saturating_add(50), 100);
+        assert_eq!(*a.saturating_add(250), 100);
+        assert_eq!(*a.saturating_sub(70), 10);
+        assert_eq!(*a.saturating_mul(2), 100);
+        assert_eq!(*a.saturating_div(10), 10);
+        let b = Refinement::<u32, unsigned::OpenInterval<0, 10>>::refine(5).unwrap();
+        assert_eq!(*b.saturating_sub(5), 1);
+    }
+
+    #[test]
+    fn test_saturating_signed() {
+        let a = Refinement::<i8, signed::ClosedOpenInterval<-50, 50>>::refine(-20).unwrap();
+        assert_eq!(*a.saturating_sub(100), -50);
+        assert_eq!(*a.saturating_mul(-10), 49);
+        assert_eq!(*a.saturating_add(30), 10);
+    }
+
+    #[test]
+    fn test_assign_unsigned() {
+        let mut a = Refinement::<u8, unsigned::GreaterThanEqual<10>>::refine(10).unwrap();
+        a += 5;
+        assert_eq!(*a, 15);
+        let mut b = Refinement::<u16, unsigned::LessThan<100>>::refine(60).unwrap();
+        b -= 60;
+        assert_eq!(*b, 0);
+        let mut c = Refinement::<u32, unsigned::Modulo<3, 0>>::refine(9).unwrap();
+        c -= Refinement::refine(6).unwrap();
+        c += Refinement::refine(30).unwrap();
+        assert_eq!(*c, 33);
+    }
+
+    #[test]
+    #[should_panic(expected = "attempt to add with overflow")]
+    fn test_assign_overflow() {
+        let mut a = Refinement::<u8, unsigned::GreaterThan<10>>::refine(250).unwrap();
+        a += 6;
+    }
+
+    #[test]
+    fn test_assign_signed() {
+        let mut a = Refinement::<i8, signed::Positive>::refine(5).unwrap();
+        a += Refinement::<i8, signed::ClosedInterval<0, 10>>::refine(10).unwrap();
+        a -= Refinement::<i8, signed::LessThan<0>>::refine(-5).unwrap();
+        assert_eq!(*a, 20);
+        let mut b = Refinement::<i16, signed::LessThanEqual<0>>::refine(-5).unwrap();
+        b -= Refinement::<i16, signed::GreaterThan<2>>::refine(3).unwrap();
+        assert_eq!(*b, -8);
+        let mut c = Refinement::<i32, signed::Even>::refine(-4).unwrap();
+        c += Refinement::refine(10).unwrap();
+        assert_eq!(*c, 6);
+        // b += Refinement::<i16, signed::ClosedInterval<-1, 1>>::refine(0).unwrap();
+    }
+}
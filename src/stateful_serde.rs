@@ -0,0 +1,354 @@
+//! Deserialization using [StatefulPredicate] instances.
+//!
+//! The `Deserialize` implementations of refinements always use the stateless [Predicate](crate::Predicate), because serde
+//! provides no way to pass additional state into [Deserialize]. This module provides two ways to supply
+//! that state instead:
+//!
+//! * [WithState] is a [DeserializeSeed] that refines a single value using a borrowed predicate
+//! * [with_state] (requires `std`) makes a predicate available to every field in its scope that is
+//!   annotated with `#[serde(deserialize_with = "refined::stateful_serde::deserialize")]`, allowing
+//!   entire structs to be deserialized using the supplied state. [Scoped] provides the same behavior
+//!   as a [DeserializeSeed], and [deserialize_or_default] falls back to the [Default] predicate when no
+//!   scope is active
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, stateful_serde::*};
+//! use serde::{de::DeserializeSeed, Deserialize};
+//!
+//! /// At most a maximum that is only known at runtime.
+//! #[derive(Debug)]
+//! struct AtMost(u8);
+//!
+//! impl Default for AtMost {
+//!   fn default() -> Self {
+//!     Self(u8::MAX)
+//!   }
+//! }
+//!
+//! impl Predicate<u8> for AtMost {
+//!   fn test(_: &u8) -> bool {
+//!     true
+//!   }
+//!
+//!   fn error() -> ErrorMessage {
+//!     "must be at most the configured maximum".to_string()
+//!   }
+//! }
+//!
+//! impl StatefulPredicate<u8> for AtMost {
+//!   fn test(&self, value: &u8) -> bool {
+//!     *value <= self.0
+//!   }
+//! }
+//!
+//! type Small = Refinement<u8, AtMost>;
+//! let at_most_ten = AtMost(10);
+//!
+//! let mut deserializer = serde_json::Deserializer::from_str("11");
+//! let result: Result<Small, _> = WithState::new(&at_most_ten).deserialize(&mut deserializer);
+//! assert!(result.is_err());
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Config {
+//!   #[serde(deserialize_with = "refined::stateful_serde::deserialize")]
+//!   size: Small,
+//! }
+//!
+//! let config: Config = with_state(&at_most_ten, || serde_json::from_str(r#"{"size": 9}"#)).unwrap();
+//! assert_eq!(*config.size, 9);
+//! assert!(with_state(&at_most_ten, || serde_json::from_str::<Config>(r#"{"size": 11}"#)).is_err());
+//! // Without a predicate in scope, deserialization fails.
+//! assert!(serde_json::from_str::<Config>(r#"{"size": 9}"#).is_err());
+//! ```
+
+use core::marker::PhantomData;
+
+use serde::{de::DeserializeSeed, Deserialize, Deserializer};
+
+use crate::{RefinementOps, StatefulPredicate, StatefulRefinementOps};
+
+/// A [DeserializeSeed] that refines the deserialized value using a borrowed [StatefulPredicate].
+pub struct WithState<'a, P, R> {
+    predicate: &'a P,
+    refinement: PhantomData<R>,
+}
+
+impl<'a, P, R> WithState<'a, P, R> {
+    /// Creates a seed that refines using `predicate`.
+    pub fn new(predicate: &'a P) -> Self {
+        Self {
+            predicate,
+            refinement: PhantomData,
+        }
+    }
+}
+
+impl<'de, T, P, R> DeserializeSeed<'de> for WithState<'_, P, R>
+where
+    R: RefinementOps<T = T> + StatefulRefinementOps<T, P>,
+    T: Deserialize<'de>,
+    P: StatefulPredicate<T>,
+{
+    type Value = R;
+
+    fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let value = T::deserialize(deserializer)?;
+        R::refine_with_state(self.predicate, value).map_err(serde::de::Error::custom)
+    }
+}
+
+#[cfg(feature = "std")]
+mod scope {
+    use core::{any::TypeId, cell::RefCell};
+
+    use super::*;
+
+    std::thread_local! {
+        static SCOPE: RefCell<alloc::vec::Vec<(TypeId, *const ())>> = const { RefCell::new(alloc::vec::Vec::new()) };
+    }
+
+    struct ScopeGuard;
+
+    impl Drop for ScopeGuard {
+        fn drop(&mut self) {
+            SCOPE.with(|scope| scope.borrow_mut().pop());
+        }
+    }
+
+    /// Makes `predicate` available to [deserialize] for the duration of `f` on the current thread.
+    ///
+    /// Scopes may be nested; the innermost predicate of each type is used.
+    pub fn with_state<P: 'static, R>(predicate: &P, f: impl FnOnce() -> R) -> R {
+        SCOPE.with(|scope| {
+            scope
+                .borrow_mut()
+                .push((TypeId::of::<P>(), predicate as *const P as *const ()))
+        });
+        let _guard = ScopeGuard;
+        f()
+    }
+
+    fn with_current<P: 'static, R>(f: impl FnOnce(Option<&P>) -> R) -> R {
+        let current = SCOPE.with(|scope| {
+            scope
+                .borrow()
+                .iter()
+                .rev()
+                .find(|(id, _)| *id == TypeId::of::<P>())
+                .map(|(_, predicate)| *predicate)
+        });
+        // SAFETY: a pointer is only present in the scope while the `with_state` call that pushed it is
+        // executing, and that call borrows the predicate for its entire duration. The reference handed to
+        // `f` cannot outlive this call, which itself happens within that scope.
+        f(current.map(|predicate| unsafe { &*(predicate as *const P) }))
+    }
+
+    /// Deserializes a refinement using the predicate supplied by the innermost enclosing [with_state] call.
+    ///
+    /// Intended for use with serde's `deserialize_with` attribute. Fails if no predicate of the required
+    /// type is in scope; use [deserialize_or_default] to fall back to the [Default] predicate instead.
+    pub fn deserialize<'de, D, T, R, P>(deserializer: D) -> Result<R, D::Error>
+    where
+        D: Deserializer<'de>,
+        R: RefinementOps<T = T> + StatefulRefinementOps<T, P>,
+        T: Deserialize<'de>,
+        P: StatefulPredicate<T> + 'static,
+    {
+        let value = T::deserialize(deserializer)?;
+        with_current::<P, _>(|predicate| match predicate {
+            Some(predicate) => {
+                R::refine_with_state(predicate, value).map_err(serde::de::Error::custom)
+            }
+            None => Err(serde::de::Error::custom(format_args!(
+                "no predicate of type {} in scope",
+                core::any::type_name::<P>()
+            ))),
+        })
+    }
+
+    /// Deserializes a refinement like [deserialize], using the [Default] predicate if no predicate of the
+    /// required type is in scope.
+    pub fn deserialize_or_default<'de, D, T, R, P>(deserializer: D) -> Result<R, D::Error>
+    where
+        D: Deserializer<'de>,
+        R: RefinementOps<T = T> + StatefulRefinementOps<T, P>,
+        T: Deserialize<'de>,
+        P: StatefulPredicate<T> + 'static,
+    {
+        let value = T::deserialize(deserializer)?;
+        with_current::<P, _>(|predicate| match predicate {
+            Some(predicate) => R::refine_with_state(predicate, value),
+            None => R::refine_with_state(&P::default(), value),
+        })
+        .map_err(serde::de::Error::custom)
+    }
+
+    /// A [DeserializeSeed] that deserializes `T` with `predicate` made available via [with_state].
+    pub struct Scoped<'a, P, T> {
+        predicate: &'a P,
+        value: PhantomData<T>,
+    }
+
+    impl<'a, P, T> Scoped<'a, P, T> {
+        /// Creates a seed that deserializes within the scope of `predicate`.
+        pub fn new(predicate: &'a P) -> Self {
+            Self {
+                predicate,
+                value: PhantomData,
+            }
+        }
+    }
+
+    impl<'de, P: 'static, T: Deserialize<'de>> DeserializeSeed<'de> for Scoped<'_, P, T> {
+        type Value = T;
+
+        fn deserialize<D>(self, deserializer: D) -> Result<Self::Value, D::Error>
+        where
+            D: Deserializer<'de>,
+        {
+            with_state(self.predicate, || T::deserialize(deserializer))
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg_attr(docsrs, doc(cfg(feature = "std")))]
+pub use scope::*;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::*;
+    use alloc::string::ToString;
+
+    #[derive(Debug)]
+    struct AtMost(u8);
+
+    impl Default for AtMost {
+        fn default() -> Self {
+            Self(u8::MAX)
+        }
+    }
+
+    impl Predicate<u8> for AtMost {
+        fn test(_: &u8) -> bool {
+            true
+        }
+
+        fn error() -> ErrorMessage {
+            "must be at most the configured maximum".to_string()
+        }
+    }
+
+    impl StatefulPredicate<u8> for AtMost {
+        fn test(&self, value: &u8) -> bool {
+            *value <= self.0
+        }
+    }
+
+    type Small = Refinement<u8, AtMost>;
+
+    #[test]
+    fn test_with_state_seed() {
+        let mut deserializer = serde_json::Deserializer::from_str("5");
+        let small: Small = WithState::new(&AtMost(5))
+            .deserialize(&mut deserializer)
+            .unwrap();
+        assert_eq!(*small, 5);
+
+        let mut deserializer = serde_json::Deserializer::from_str("6");
+        let err = WithState::<_, Small>::new(&AtMost(5))
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "refinement violated: must be at most the configured maximum"
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Debug, Deserialize)]
+    struct Config {
+        #[serde(deserialize_with = "crate::stateful_serde::deserialize")]
+        low: Small,
+        #[serde(deserialize_with = "crate::stateful_serde::deserialize")]
+        high: Named<TestName, Small>,
+    }
+
+    #[cfg(feature = "std")]
+    type_string!(TestName, "high");
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_scoped_state() {
+        let config: Config = with_state(&AtMost(10), || {
+            serde_json::from_str(r#"{"low": 1, "high": 10}"#)
+        })
+        .unwrap();
+        assert_eq!((*config.low, *config.high), (1, 10));
+
+        let err = with_state(&AtMost(10), || {
+            serde_json::from_str::<Config>(r#"{"low": 1, "high": 11}"#)
+        })
+        .unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("refinement violated: high must be at most the configured maximum"));
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_scoped_state_nesting() {
+        let json = r#"{"low": 200, "high": 200}"#;
+        let nested = with_state(&AtMost(10), || {
+            with_state(&AtMost(u8::MAX), || serde_json::from_str::<Config>(json))
+        });
+        assert!(nested.is_ok());
+        assert!(with_state(&AtMost(10), || serde_json::from_str::<Config>(json)).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_no_scope() {
+        let err = serde_json::from_str::<Config>(r#"{"low": 1, "high": 1}"#).unwrap_err();
+        assert!(err
+            .to_string()
+            .starts_with("no predicate of type refined::stateful_serde::tests::AtMost in scope"));
+        let err = with_state(&5u8, || {
+            serde_json::from_str::<Config>(r#"{"low": 1, "high": 1}"#)
+        })
+        .unwrap_err();
+        assert!(err.to_string().starts_with("no predicate of type"));
+    }
+
+    #[cfg(feature = "std")]
+    #[derive(Debug, Deserialize)]
+    struct DefaultConfig {
+        #[serde(deserialize_with = "crate::stateful_serde::deserialize_or_default")]
+        size: Small,
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_deserialize_or_default() {
+        let json = r#"{"size": 200}"#;
+        let config: DefaultConfig = serde_json::from_str(json).unwrap();
+        assert_eq!(*config.size, 200);
+        assert!(with_state(&AtMost(10), || serde_json::from_str::<DefaultConfig>(json)).is_err());
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn test_scoped_seed() {
+        let mut deserializer = serde_json::Deserializer::from_str(r#"{"low": 3, "high": 4}"#);
+        let err = Scoped::<_, Config>::new(&AtMost(3))
+            .deserialize(&mut deserializer)
+            .unwrap_err();
+        assert!(err.to_string().contains("high"));
+    }
+}
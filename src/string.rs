@@ -17,7 +17,10 @@
 use alloc::format;
 use core::marker::PhantomData;
 
-use crate::{ErrorMessage, Predicate, TypeString};
+use crate::{
+    Bound, ConstPredicate, ConstValue, ErrorMessage, Predicate, PredicateKind, Stateless,
+    TypeString, Violation,
+};
 
 #[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash, Default)]
 pub struct StartsWith<Prefix: TypeString>(PhantomData<Prefix>);
@@ -31,8 +34,16 @@ impl<T: AsRef<str>, Prefix: TypeString> Predicate<T> for StartsWith<Prefix> {
         format!("must start with '{}'", Prefix::VALUE)
     }
 
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::StartsWith,
+            &[Bound::Str(Prefix::VALUE)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
@@ -48,8 +59,16 @@ impl<T: AsRef<str>, Suffix: TypeString> Predicate<T> for EndsWith<Suffix> {
         format!("must end with '{}'", Suffix::VALUE)
     }
 
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::EndsWith,
+            &[Bound::Str(Suffix::VALUE)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
@@ -65,8 +84,16 @@ impl<T: AsRef<str>, Substr: TypeString> Predicate<T> for Contains<Substr> {
         format!("must contain '{}'", Substr::VALUE)
     }
 
+    fn violation() -> Violation {
+        Violation::predicate(
+            PredicateKind::Contains,
+            &[Bound::Str(Substr::VALUE)],
+            <Self as Predicate<T>>::error(),
+        )
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
@@ -82,8 +109,12 @@ impl<T: AsRef<str>> Predicate<T> for Trimmed {
         ErrorMessage::from("must not start or end with whitespace")
     }
 
+    fn violation() -> Violation {
+        Violation::predicate(PredicateKind::Trimmed, &[], <Self as Predicate<T>>::error())
+    }
+
     unsafe fn optimize(value: &T) {
-        core::hint::assert_unchecked(Self::test(value));
+        core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
     }
 }
 
@@ -93,20 +124,68 @@ mod regex_pred {
     use super::*;
     use crate::StatefulPredicate;
 
+    /// Runs `f` against the compiled form of `pattern`.
+    ///
+    /// With `std`, each pattern is compiled only once and shared by all subsequent checks.
+    #[cfg(feature = "std")]
+    fn with_compiled<R>(pattern: &'static str, f: impl FnOnce(&regex::Regex) -> R) -> R {
+        use std::{
+            collections::HashMap,
+            sync::{OnceLock, PoisonError, RwLock},
+        };
+
+        static CACHE: OnceLock<RwLock<HashMap<&'static str, regex::Regex>>> = OnceLock::new();
+
+        let cache = CACHE.get_or_init(Default::default);
+        if let Some(regex) = cache
+            .read()
+            .unwrap_or_else(PoisonError::into_inner)
+            .get(pattern)
+        {
+            return f(regex);
+        }
+
+        let compiled = regex::Regex::new(pattern).expect("Invalid regex");
+        let mut cache = cache.write().unwrap_or_else(PoisonError::into_inner);
+        f(cache.entry(pattern).or_insert(compiled))
+    }
+
+    /// Runs `f` against the compiled form of `pattern`.
+    ///
+    /// Without `std` there is no synchronization primitive to guard a shared cache, so the
+    /// pattern is compiled on every call. Use the [StatefulPredicate] implementation of
+    /// [Regex] to reuse a compiled pattern in `alloc`-only builds.
+    #[cfg(not(feature = "std"))]
+    fn with_compiled<R>(pattern: &'static str, f: impl FnOnce(&regex::Regex) -> R) -> R {
+        f(&regex::Regex::new(pattern).expect("Invalid regex"))
+    }
+
+    /// The string matches the regular expression `S`.
+    ///
+    /// With the `std` feature enabled, the regular expression is compiled once on first use and then
+    /// cached for the lifetime of the program. In `alloc`-only builds, the stateless predicate compiles
+    /// the regular expression on every test; the [StatefulPredicate] implementation should be preferred
+    /// in that case.
     #[derive(Clone, Debug)]
     pub struct Regex<S: TypeString>(regex::Regex, PhantomData<S>);
 
     impl<S: TypeString, T: AsRef<str>> Predicate<T> for Regex<S> {
         fn test(s: &T) -> bool {
-            regex::Regex::new(S::VALUE)
-                .expect("Invalid regex")
-                .is_match(s.as_ref())
+            with_compiled(S::VALUE, |regex| regex.is_match(s.as_ref()))
         }
 
         fn error() -> ErrorMessage {
             format!("must match regular expression {}", S::VALUE)
         }
 
+        fn violation() -> Violation {
+            Violation::predicate(
+                PredicateKind::Regex,
+                &[Bound::Str(S::VALUE)],
+                <Self as Predicate<T>>::error(),
+            )
+        }
+
         unsafe fn optimize(value: &T) {
             core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
         }
@@ -114,10 +193,7 @@ mod regex_pred {
 
     impl<S: TypeString> Default for Regex<S> {
         fn default() -> Self {
-            Self(
-                regex::Regex::new(S::VALUE).expect("Invalid regex"),
-                PhantomData,
-            )
+            Self(with_compiled(S::VALUE, regex::Regex::clone), PhantomData)
         }
     }
 
@@ -126,11 +202,81 @@ mod regex_pred {
             self.0.is_match(value.as_ref())
         }
 
+        fn violation(&self) -> Violation {
+            <Self as Predicate<T>>::violation()
+        }
+
         unsafe fn optimize(value: &T) {
             core::hint::assert_unchecked(<Self as Predicate<T>>::test(value));
         }
     }
 
+    /// The string matches a regular expression that is only known at runtime.
+    ///
+    /// The compiled pattern is carried by the predicate instance, so this predicate can only be
+    /// certified statefully. Stateless refinement always fails, and the [Default] instance matches
+    /// nothing.
+    #[derive(Clone, Debug)]
+    pub struct DynRegex(regex::Regex);
+
+    impl DynRegex {
+        /// Compiles `pattern` into a predicate.
+        pub fn new(pattern: &str) -> Result<Self, regex::Error> {
+            regex::Regex::new(pattern).map(Self)
+        }
+
+        /// The compiled regular expression.
+        pub fn regex(&self) -> &regex::Regex {
+            &self.0
+        }
+    }
+
+    impl From<regex::Regex> for DynRegex {
+        fn from(regex: regex::Regex) -> Self {
+            Self(regex)
+        }
+    }
+
+    impl Default for DynRegex {
+        fn default() -> Self {
+            Self(regex::Regex::new("[a&&b]").expect("Invalid regex"))
+        }
+    }
+
+    impl<T: AsRef<str>> Predicate<T> for DynRegex {
+        const RUNTIME_STATE: bool = true;
+
+        fn test(_: &T) -> bool {
+            false
+        }
+
+        fn error() -> ErrorMessage {
+            ErrorMessage::from("must match a regular expression provided at runtime")
+        }
+
+        fn violation() -> Violation {
+            Violation::predicate(PredicateKind::Regex, &[], <Self as Predicate<T>>::error())
+        }
+    }
+
+    impl<T: AsRef<str>> StatefulPredicate<T> for DynRegex {
+        fn test(&self, value: &T) -> bool {
+            self.0.is_match(value.as_ref())
+        }
+
+        fn error(&self) -> ErrorMessage {
+            format!("must match regular expression {}", self.0.as_str())
+        }
+
+        fn violation(&self) -> Violation {
+            Violation::predicate(
+                PredicateKind::Regex,
+                &[],
+                StatefulPredicate::<T>::error(self),
+            )
+        }
+    }
+
     #[cfg(test)]
     mod tests {
         use super::*;
@@ -147,6 +293,20 @@ mod regex_pred {
             assert!(Test::refine("aab".to_string()).is_err());
         }
 
+        #[cfg(feature = "std")]
+        #[test]
+        fn test_regex_cache() {
+            type_string!(Digits, "^[0-9]+$");
+            type Test = Refinement<String, Regex<Digits>>;
+            assert!(Test::refine("123".to_string()).is_ok());
+            assert!(Test::refine("12a".to_string()).is_err());
+            let pattern = with_compiled(Digits::VALUE, |regex| regex.as_str().as_ptr());
+            assert_eq!(
+                pattern,
+                with_compiled(Digits::VALUE, |regex| regex.as_str().as_ptr())
+            );
+        }
+
         #[test]
         fn test_stateful_regex() {
             let st = Regex::<AllAs>::default();
@@ -164,6 +324,55 @@ mod regex_pred {
             assert!(it.modify_with_state(&st, |s| s + "b").is_err());
         }
 
+        #[test]
+        fn test_stateful_regex_combined() {
+            type_string!(EndsB, "b$");
+            type Test =
+                Refinement<String, boolean::And<Regex<AllAs>, boundable::unsigned::LessThan<4>>>;
+            let st = Test::refine_with_state(&Default::default(), "aaa".to_string()).unwrap();
+            assert!(st
+                .modify_with_state(&Default::default(), |s| s + "a")
+                .is_err());
+
+            type Either = Refinement<String, boolean::Or<Regex<AllAs>, Regex<EndsB>>>;
+            let either = boolean::Or::<Regex<AllAs>, Regex<EndsB>>::default();
+            assert!(Either::refine_with_state(&either, "aaa".to_string()).is_ok());
+            assert!(Either::refine_with_state(&either, "cab".to_string()).is_ok());
+            assert!(Either::refine_with_state(&either, "cat".to_string()).is_err());
+        }
+
+        #[test]
+        fn test_dyn_regex() {
+            type Test = Refinement<String, DynRegex>;
+            let pattern = DynRegex::new("^b+$").unwrap();
+            assert!(Test::refine_with_state(&pattern, "bbb".to_string()).is_ok());
+            assert!(Test::refine("bbb".to_string()).is_err());
+            assert!(Test::refine_with_state(&DynRegex::default(), "".to_string()).is_err());
+            assert_eq!(
+                Test::refine_with_state(&pattern, "bab".to_string())
+                    .unwrap_err()
+                    .to_string(),
+                "refinement violated: must match regular expression ^b+$"
+            );
+        }
+
+        #[test]
+        fn test_dyn_regex_combined() {
+            type Test =
+                Refinement<String, boolean::And<DynRegex, boundable::unsigned::DynLessThan>>;
+            let predicate = boolean::And::new(
+                DynRegex::new("^b+$").unwrap(),
+                boundable::unsigned::DynLessThan::new(4),
+            );
+            assert!(Test::refine_with_state(&predicate, "bbb".to_string()).is_ok());
+            assert_eq!(
+                Test::refine_with_state(&predicate, "bbbb".to_string())
+                    .unwrap_err()
+                    .to_string(),
+                "refinement violated: must be less than 4"
+            );
+        }
+
         #[test]
         fn test_stateful_regex_replace() {
             type Test = Refinement<String, Regex<AllAs>>;
@@ -213,14 +422,118 @@ mod regex_pred {
 #[cfg(feature = "regex")]
 pub use regex_pred::*;
 
+const fn bytes_match(haystack: &[u8], start: usize, needle: &[u8]) -> bool {
+    if start + needle.len() > haystack.len() {
+        return false;
+    }
+    let mut i = 0;
+    while i < needle.len() {
+        if haystack[start + i] != needle[i] {
+            return false;
+        }
+        i += 1;
+    }
+    true
+}
+
+const fn decode_char(bytes: &[u8], start: usize) -> char {
+    let first = bytes[start] as u32;
+    let (mut code, len) = if first < 0x80 {
+        (first, 1)
+    } else if first < 0xE0 {
+        (first & 0x1F, 2)
+    } else if first < 0xF0 {
+        (first & 0x0F, 3)
+    } else {
+        (first & 0x07, 4)
+    };
+    let mut i = 1;
+    while i < len {
+        code = (code << 6) | (bytes[start + i] as u32 & 0x3F);
+        i += 1;
+    }
+    match char::from_u32(code) {
+        Some(c) => c,
+        None => panic!("invalid UTF-8"),
+    }
+}
+
+const fn is_trimmed(s: &str) -> bool {
+    let bytes = s.as_bytes();
+    if bytes.is_empty() {
+        return true;
+    }
+    let mut last = bytes.len() - 1;
+    while bytes[last] & 0xC0 == 0x80 {
+        last -= 1;
+    }
+    !decode_char(bytes, 0).is_whitespace() && !decode_char(bytes, last).is_whitespace()
+}
+
+impl<V: ConstValue<&'static str>, Prefix: TypeString> ConstPredicate<&'static str, V>
+    for StartsWith<Prefix>
+{
+    const HOLDS: bool = bytes_match(V::VALUE.as_bytes(), 0, Prefix::VALUE.as_bytes());
+}
+
+impl<V: ConstValue<&'static str>, Suffix: TypeString> ConstPredicate<&'static str, V>
+    for EndsWith<Suffix>
+{
+    const HOLDS: bool = V::VALUE.len() >= Suffix::VALUE.len()
+        && bytes_match(
+            V::VALUE.as_bytes(),
+            V::VALUE.len() - Suffix::VALUE.len(),
+            Suffix::VALUE.as_bytes(),
+        );
+}
+
+impl<V: ConstValue<&'static str>, Substr: TypeString> ConstPredicate<&'static str, V>
+    for Contains<Substr>
+{
+    const HOLDS: bool = {
+        let haystack = V::VALUE.as_bytes();
+        let needle = Substr::VALUE.as_bytes();
+        let mut start = 0;
+        let mut found = false;
+        while !found && start + needle.len() <= haystack.len() {
+            found = bytes_match(haystack, start, needle);
+            start += 1;
+        }
+        found
+    };
+}
+
+impl<V: ConstValue<&'static str>> ConstPredicate<&'static str, V> for Trimmed {
+    const HOLDS: bool = is_trimmed(V::VALUE);
+}
+
+impl<Prefix: TypeString> Stateless for StartsWith<Prefix> {}
+
+impl<Suffix: TypeString> Stateless for EndsWith<Suffix> {}
+
+impl<Substr: TypeString> Stateless for Contains<Substr> {}
+
+impl Stateless for Trimmed {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use crate::*;
+    use crate::{boolean::And, *};
     use alloc::string::{String, ToString};
 
     type_string!(Foo, "foo");
 
+    #[test]
+    fn test_refine_const() {
+        type Greeting = Refinement<&'static str, And<StartsWith<Foo>, And<Contains<Foo>, Trimmed>>>;
+        const GREETING: Greeting = refine_const!(Greeting, "foo bär");
+        assert_eq!(*GREETING, "foo bär");
+        const SUFFIXED: Refinement<&'static str, EndsWith<Foo>> =
+            refine_const!(Refinement<&'static str, EndsWith<Foo>>, "ü foo");
+        assert_eq!(*SUFFIXED, "ü foo");
+        assert!(!is_trimmed("foo\u{3000}"));
+    }
+
     #[test]
     fn test_starts_with() {
         type Test = Refinement<String, StartsWith<Foo>>;
@@ -0,0 +1,126 @@
+//! Deserialization of refinements from trusted sources.
+//!
+//! Data that was refined before it was persisted (such as rows written to your own database) does not
+//! need its predicates tested again when it is read back. The functions in this module are intended for
+//! use with serde's `deserialize_with` attribute, and skip [Predicate::test] entirely in release builds.
+//!
+//! Unlike [Refinement::new_unchecked], these functions are safe: when the `optimized` feature is enabled,
+//! invalid refinements could lead to undefined behavior, so predicates are always tested in that case.
+//! Predicates are also tested in debug builds so that untrustworthy sources are detected during development.
+//!
+//! # Example
+//!
+//! ```
+//! use refined::{prelude::*, boundable::unsigned::LessThan};
+//! use serde::Deserialize;
+//!
+//! #[derive(Debug, Deserialize)]
+//! struct Row {
+//!   #[serde(deserialize_with = "refined::trusted::deserialize")]
+//!   size: Refinement<u8, LessThan<10>>,
+//! }
+//!
+//! let row: Row = serde_json::from_str(r#"{"size": 5}"#).unwrap();
+//! assert_eq!(*row.size, 5);
+//! ```
+
+use core::marker::PhantomData;
+
+use serde::{Deserialize, Deserializer};
+
+use crate::{Predicate, Refinement, RefinementError};
+
+/// Whether predicates must be tested even when deserializing from a trusted source.
+const VERIFY: bool = cfg!(any(feature = "optimized", debug_assertions));
+
+/// Deserializes a [Refinement] from a trusted source, skipping the predicate test when it is safe to do so.
+pub fn deserialize<'de, D, T, P>(deserializer: D) -> Result<Refinement<T, P>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+    P: Predicate<T>,
+{
+    let value = T::deserialize(deserializer)?;
+    if P::RUNTIME_STATE || (VERIFY && !P::test(&value)) {
+        return Err(serde::de::Error::custom(RefinementError::of::<T, P>(
+            &value,
+        )));
+    }
+    // The refinement is built directly rather than through `Refinement::new_unchecked`, whose
+    // contract requires the predicate to hold. An untested value is only a correctness concern
+    // here: whenever `optimized` is enabled (and refinements may be relied upon for soundness),
+    // the predicate was tested above. Predicates with runtime state are always rejected, as their
+    // stateless test cannot certify any value.
+    Ok(Refinement(value, PhantomData))
+}
+
+/// Deserializes an optional [Refinement] from a trusted source, skipping the predicate test when it is
+/// safe to do so.
+pub fn deserialize_option<'de, D, T, P>(
+    deserializer: D,
+) -> Result<Option<Refinement<T, P>>, D::Error>
+where
+    D: Deserializer<'de>,
+    T: Deserialize<'de>,
+    P: Predicate<T>,
+{
+    let Some(value) = Option::<T>::deserialize(deserializer)? else {
+        return Ok(None);
+    };
+    if P::RUNTIME_STATE || (VERIFY && !P::test(&value)) {
+        return Err(serde::de::Error::custom(RefinementError::of::<T, P>(
+            &value,
+        )));
+    }
+    // See `deserialize` for why the refinement is built directly.
+    Ok(Some(Refinement(value, PhantomData)))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{boundable::unsigned::LessThan, Refinement};
+    use alloc::string::ToString;
+    use serde::Deserialize;
+
+    #[derive(Debug, Deserialize)]
+    struct Row {
+        #[serde(deserialize_with = "crate::trusted::deserialize")]
+        size: Refinement<u8, LessThan<10>>,
+        #[serde(default, deserialize_with = "crate::trusted::deserialize_option")]
+        limit: Option<Refinement<u8, LessThan<10>>>,
+    }
+
+    #[test]
+    fn test_trusted_deserialize() {
+        let row: Row = serde_json::from_str(r#"{"size": 5, "limit": 9}"#).unwrap();
+        assert_eq!(*row.size, 5);
+        assert_eq!(row.limit.map(|l| *l), Some(9));
+        let row: Row = serde_json::from_str(r#"{"size": 5}"#).unwrap();
+        assert!(row.limit.is_none());
+    }
+
+    #[cfg(any(debug_assertions, feature = "optimized"))]
+    #[test]
+    fn test_trusted_deserialize_verified() {
+        let err = serde_json::from_str::<Row>(r#"{"size": 10}"#).unwrap_err();
+        assert!(err.to_string().starts_with("refinement violated"));
+        assert!(serde_json::from_str::<Row>(r#"{"size": 1, "limit": 10}"#).is_err());
+    }
+
+    #[test]
+    fn test_trusted_deserialize_runtime_state() {
+        use crate::{boolean::Not, boundable::unsigned::DynGreaterThan};
+
+        let mut deserializer = serde_json::Deserializer::from_str("200");
+        assert!(
+            crate::trusted::deserialize::<_, u8, Not<DynGreaterThan>>(&mut deserializer).is_err()
+        );
+    }
+
+    #[cfg(not(any(debug_assertions, feature = "optimized")))]
+    #[test]
+    fn test_trusted_deserialize_unverified() {
+        let row: Row = serde_json::from_str(r#"{"size": 10}"#).unwrap();
+        assert_eq!(*row.size, 10);
+    }
+}
@@ -0,0 +1,6 @@
+#[cfg(feature = "derive")]
+#[test]
+fn test_derive_errors() {
+    let cases = trybuild::TestCases::new();
+    cases.compile_fail("tests/derive/*.rs");
+}
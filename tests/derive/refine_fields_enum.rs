@@ -0,0 +1,8 @@
+use refined::RefineFields;
+
+#[derive(RefineFields)]
+enum Frobnicator {
+    Small,
+}
+
+fn main() {}
@@ -0,0 +1,8 @@
+use refined::RefineFields;
+
+#[derive(RefineFields)]
+struct Frobnicator<T> {
+    size: T,
+}
+
+fn main() {}
@@ -0,0 +1,6 @@
+use refined::{boundable::unsigned::LessThan, Refinement, RefineFields};
+
+#[derive(RefineFields)]
+struct Frobnicator(Refinement<u8, LessThan<10>>);
+
+fn main() {}
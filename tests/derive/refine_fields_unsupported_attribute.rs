@@ -0,0 +1,9 @@
+use refined::{boundable::unsigned::LessThan, Refinement, RefineFields};
+
+#[derive(RefineFields)]
+#[refined(mirror = RawFrobnicator)]
+struct Frobnicator {
+    size: Refinement<u8, LessThan<10>>,
+}
+
+fn main() {}
@@ -0,0 +1,9 @@
+use refined::Refined;
+
+#[derive(Refined)]
+#[refined(predicate = refined::boundable::unsigned::LessThan<10>)]
+enum Rating {
+    Low(u8),
+}
+
+fn main() {}
@@ -0,0 +1,7 @@
+use refined::Refined;
+
+#[derive(Refined)]
+#[refined(predicate = refined::boundable::unsigned::LessThan<10>)]
+struct Rating<T>(T);
+
+fn main() {}
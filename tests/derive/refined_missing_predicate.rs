@@ -0,0 +1,6 @@
+use refined::Refined;
+
+#[derive(Refined)]
+struct Rating(u8);
+
+fn main() {}
@@ -0,0 +1,7 @@
+use refined::Refined;
+
+#[derive(Refined)]
+#[refined(predicate = refined::boundable::unsigned::LessThan<10>, rename = "Score")]
+struct Rating(u8);
+
+fn main() {}